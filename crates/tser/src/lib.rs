@@ -1,19 +1,51 @@
+use tser_codegen::csharp::CSharpCodeGen;
+use tser_codegen::go::GoCodeGen;
+use tser_codegen::java::JavaCodeGen;
+use tser_codegen::kotlin::KotlinCodeGen;
+use tser_codegen::python::PythonCodeGen;
 use tser_codegen::rust::RustCodeGen;
 use tser_codegen::swift::SwiftCodeGen;
 use tser_codegen::{generate, CodeGen};
-use tser_parser_ts::parse_file;
+use tser_parser_ts::{parse_file, parse_project};
+
+pub use tser_parser_ts::{DiskFileSystem, FileSystem};
 
 #[derive(Debug, Copy, Clone)]
 pub enum Language {
     Rust,
     Swift,
+    Kotlin,
+    Go,
+    Python,
+    CSharp,
+    Java,
+    JavaPojo,
 }
 
-pub fn generate_from_ts(ts_src: &str, lang: Language) -> anyhow::Result<String> {
-    let code_gen: Box<dyn CodeGen> = match lang {
+fn code_gen(lang: Language) -> Box<dyn CodeGen> {
+    match lang {
         Language::Rust => Box::new(RustCodeGen),
         Language::Swift => Box::new(SwiftCodeGen),
-    };
+        Language::Kotlin => Box::new(KotlinCodeGen),
+        Language::Go => Box::<GoCodeGen>::default(),
+        Language::Python => Box::new(PythonCodeGen),
+        Language::CSharp => Box::<CSharpCodeGen>::default(),
+        Language::Java => Box::<JavaCodeGen>::default(),
+        Language::JavaPojo => Box::new(JavaCodeGen::pojos()),
+    }
+}
+
+pub fn generate_from_ts(ts_src: &str, lang: Language) -> anyhow::Result<String> {
     let ir_file = parse_file(ts_src)?;
-    Ok(generate(&ir_file, code_gen.as_ref()))
+    Ok(generate(&ir_file, code_gen(lang).as_ref())?)
+}
+
+/// Generates a single file with the declarations of the `roots` and the files they import
+pub fn generate_from_ts_project(
+    roots: &[&str],
+    file_system: &dyn FileSystem,
+    lang: Language,
+) -> anyhow::Result<String> {
+    let project = parse_project(roots, file_system)?;
+    Ok(generate(&project.merge(), code_gen(lang).as_ref())?)
 }
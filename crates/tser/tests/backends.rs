@@ -0,0 +1,356 @@
+//! The output of every backend is checked against reviewed fixtures. Only the Rust output is
+//! compiled, in `rust_serde.rs`, so the paths that are easy to get wrong in the other languages
+//! are checked line by line.
+
+use std::path::PathBuf;
+use tser::{generate_from_ts, generate_from_ts_project, DiskFileSystem, Language};
+
+const LANGUAGES: &[(Language, &str)] = &[
+    (Language::Rust, "rs"),
+    (Language::Swift, "swift"),
+    (Language::Kotlin, "kt"),
+    (Language::Go, "go"),
+    (Language::Python, "py"),
+    (Language::CSharp, "cs"),
+    (Language::Java, "java"),
+];
+
+const FIXTURES: &[&str] = &[
+    "union",
+    "generic",
+    "inheritance",
+    "doc",
+    "map",
+    "tuple",
+    "alias",
+    "intersection",
+    "number",
+    "rename",
+    "naming",
+    "inline",
+    "service",
+];
+
+/// Classes with getters and setters only differ from records in these
+const POJO_FIXTURES: &[&str] = &["union", "doc", "tuple", "naming"];
+
+fn fixtures_dir() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures")
+}
+
+fn read_fixture(file_name: &str) -> String {
+    std::fs::read_to_string(fixtures_dir().join(file_name)).unwrap()
+}
+
+#[test]
+fn fixtures_are_up_to_date() {
+    let cases = LANGUAGES
+        .iter()
+        .flat_map(|&(lang, ext)| FIXTURES.iter().map(move |&name| (lang, name, ext)))
+        .chain(
+            POJO_FIXTURES
+                .iter()
+                .map(|&name| (Language::JavaPojo, name, "pojo.java")),
+        );
+    for (lang, name, ext) in cases {
+        let generated = generate_from_ts(&read_fixture(&format!("{name}.ts")), lang).unwrap();
+        assert_eq!(
+            generated,
+            read_fixture(&format!("{name}.{ext}")),
+            "{name}.{ext} is out of date"
+        );
+    }
+}
+
+#[test]
+fn project_fixtures_are_up_to_date() {
+    let fs = DiskFileSystem {
+        root: fixtures_dir().join("project"),
+    };
+    for &(lang, ext) in LANGUAGES {
+        assert_eq!(
+            generate_from_ts_project(&["main.ts"], &fs, lang).unwrap(),
+            read_fixture(&format!("project.{ext}")),
+            "project.{ext} is out of date"
+        );
+    }
+}
+
+#[test]
+fn generic_instances_must_not_clash() {
+    let src = "
+interface User { name: string }
+interface Page<T> { items: T[] }
+interface PageUser { count: number }
+interface Response { users: Page<User> }
+";
+    assert!(generate_from_ts(src, Language::Rust).is_ok());
+    assert_eq!(
+        generate_from_ts(src, Language::Go).unwrap_err().to_string(),
+        "The copy of a generic type named `PageUser` would clash with another type"
+    );
+}
+
+const TRICKY_PATHS: &str = r#"
+interface Profile {
+    nickname?: string;
+    avatar: string | null;
+    /** @format uint64 */
+    visits: number;
+}
+
+enum Level { Low = 1, High = 10 }
+
+type Payload =
+    | { "the-kind": "text"; "my-data": string }
+    | { "the-kind": "count"; "my-data"?: number };
+
+type Id = number | string;
+
+type Payment =
+    | { method: "bank-transfer"; iban: string }
+    | { method: "card" };
+
+type Body = { "content-type": string } | { "x-raw": number[] };
+"#;
+
+/// Asserts that the lines appear in the output in this order, ignoring indentation
+fn assert_lines_in_order(lang: Language, lines: &[&str]) {
+    let generated = generate_from_ts(TRICKY_PATHS, lang).unwrap();
+    let mut remaining = generated.lines().map(str::trim);
+    for line in lines {
+        assert!(
+            remaining.any(|generated_line| generated_line == *line),
+            "`{line}` is missing or out of order in:\n{generated}"
+        );
+    }
+}
+
+#[test]
+fn swift_tricky_paths() {
+    assert_lines_in_order(
+        Language::Swift,
+        &[
+            "public var nickname: String?",
+            "public var avatar: String?",
+            "public var visits: UInt64",
+        ],
+    );
+    assert_lines_in_order(
+        Language::Swift,
+        &[
+            "public enum Level: Int64, Codable, Equatable, Hashable {",
+            "case Low = 1",
+            "case High = 10",
+        ],
+    );
+    assert_lines_in_order(
+        Language::Swift,
+        &[
+            r#"case theKind = "the-kind""#,
+            r#"case myData = "my-data""#,
+            "let tag = try container.decode(String.self, forKey: .theKind)",
+            "self = try .count(container.decodeIfPresent(Double.self, forKey: .myData))",
+            "try container.encodeIfPresent(value, forKey: .myData)",
+        ],
+    );
+    // Tags and keys are only used as identifiers once sanitized
+    assert_lines_in_order(
+        Language::Swift,
+        &[
+            "case bankTransfer(iban: String)",
+            r#"case "bank-transfer":"#,
+            r#"try container.encode("bank-transfer", forKey: .method)"#,
+            r#"case contentType = "content-type""#,
+            r#"case xRaw = "x-raw""#,
+            "case .contentType:",
+        ],
+    );
+    assert_lines_in_order(
+        Language::Swift,
+        &[
+            "if let value = try? container.decode(Double.self) {",
+            "} else if let value = try? container.decode(String.self) {",
+        ],
+    );
+}
+
+#[test]
+fn kotlin_tricky_paths() {
+    // Optional fields are left out when null, nullable ones are sent as null
+    assert_lines_in_order(
+        Language::Kotlin,
+        &[
+            "val nickname: String? = null,",
+            "val avatar: String?,",
+            "val visits: ULong,",
+        ],
+    );
+    assert_lines_in_order(
+        Language::Kotlin,
+        &[
+            "Low(1),",
+            "High(10);",
+            "override fun serialize(encoder: Encoder, value: Level) = encoder.encodeLong(value.value)",
+        ],
+    );
+    assert_lines_in_order(
+        Language::Kotlin,
+        &[
+            r#"put("the-kind", "text")"#,
+            r#"put("my-data", output.json.encodeToJsonElement(value.value))"#,
+            r#"put("the-kind", "count")"#,
+            r#"value.value?.let { put("my-data", output.json.encodeToJsonElement(it)) }"#,
+            r#"val data = element["my-data"] ?: JsonNull"#,
+            r#"return when (val tag = element["the-kind"]?.jsonPrimitive?.contentOrNull) {"#,
+        ],
+    );
+    assert_lines_in_order(
+        Language::Kotlin,
+        &[
+            "return runCatching<Id> { IdNumber(input.json.decodeFromJsonElement(element)) }",
+            ".recoverCatching { IdString(input.json.decodeFromJsonElement(element)) }",
+        ],
+    );
+}
+
+#[test]
+fn go_tricky_paths() {
+    assert_lines_in_order(
+        Language::Go,
+        &[
+            r#"Nickname *string `json:"nickname,omitempty"`"#,
+            r#"Avatar *string `json:"avatar"`"#,
+            r#"Visits uint64 `json:"visits"`"#,
+        ],
+    );
+    assert_lines_in_order(
+        Language::Go,
+        &["LevelLow Level = 1", "LevelHigh Level = 10"],
+    );
+    assert_lines_in_order(
+        Language::Go,
+        &[
+            r#"Tag string `json:"the-kind"`"#,
+            r#"Data string `json:"my-data"`"#,
+            r#"Tag string `json:"the-kind"`"#,
+            r#"Data *float64 `json:"my-data,omitempty"`"#,
+            r#"Tag string `json:"the-kind"`"#,
+            r#"Data json.RawMessage `json:"my-data"`"#,
+            // A missing content key leaves an optional value unset
+            "if tagged.Data != nil {",
+        ],
+    );
+    assert_lines_in_order(
+        Language::Go,
+        &[
+            "if value := new(IdNumber); json.Unmarshal(data, &value.Value) == nil {",
+            "if value := new(IdString); json.Unmarshal(data, &value.Value) == nil {",
+        ],
+    );
+}
+
+#[test]
+fn python_tricky_paths() {
+    assert_lines_in_order(
+        Language::Python,
+        &[
+            "nickname: Optional[str] = Field(default=None, exclude_if=lambda value: value is None)",
+            "avatar: Optional[str]",
+            "visits: int",
+        ],
+    );
+    assert_lines_in_order(
+        Language::Python,
+        &["class Level(enum.IntEnum):", "LOW = 1", "HIGH = 10"],
+    );
+    assert_lines_in_order(
+        Language::Python,
+        &[
+            r#"the_kind: Literal["text"] = Field(default="text", alias="the-kind")"#,
+            r#"my_data: str = Field(alias="my-data")"#,
+            r#"my_data: Optional[float] = Field(default=None, alias="my-data", exclude_if=lambda value: value is None)"#,
+            r#"Payload = Annotated[Union[PayloadText, PayloadCount], Field(discriminator="the_kind")]"#,
+        ],
+    );
+    assert_lines_in_order(
+        Language::Python,
+        &[r#"Id = Annotated[Union[float, str], Field(union_mode="left_to_right")]"#],
+    );
+}
+
+#[test]
+fn csharp_tricky_paths() {
+    assert_lines_in_order(
+        Language::CSharp,
+        &[
+            "[JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]",
+            "public string? Nickname { get; init; }",
+            "public required string? Avatar { get; init; }",
+            "public required ulong Visits { get; init; }",
+        ],
+    );
+    // Serialized as numbers, without a string enum converter
+    assert_lines_in_order(
+        Language::CSharp,
+        &["}", "public enum Level", "{", "Low = 1,", "High = 10,"],
+    );
+    assert_lines_in_order(
+        Language::CSharp,
+        &[
+            r#"var tag = element.GetProperty("the-kind").GetString();"#,
+            r#"element.TryGetProperty("my-data", out var data);"#,
+            r#"writer.WriteString("the-kind", "text");"#,
+            r#"writer.WritePropertyName("my-data");"#,
+        ],
+    );
+    assert_lines_in_order(
+        Language::CSharp,
+        &[
+            "try { return new IdNumber(element.Deserialize<double>(options)!); } catch (JsonException) { }",
+            "try { return new IdString(element.Deserialize<string>(options)!); } catch (JsonException) { }",
+        ],
+    );
+}
+
+#[test]
+fn java_tricky_paths() {
+    assert_lines_in_order(
+        Language::Java,
+        &[
+            r#"@JsonProperty("nickname") @JsonInclude(JsonInclude.Include.NON_NULL) String nickname,"#,
+            r#"@JsonProperty(value = "avatar", required = true) String avatar,"#,
+            r#"@JsonProperty(value = "visits", required = true) BigInteger visits"#,
+        ],
+    );
+    // Jackson would read an integer as the position of the constant without the creator
+    assert_lines_in_order(
+        Language::Java,
+        &[
+            "LOW(1L),",
+            "HIGH(10L);",
+            "@JsonValue",
+            "@JsonCreator",
+            "public static Level of(long value) {",
+        ],
+    );
+    assert_lines_in_order(
+        Language::Java,
+        &[
+            r#"generator.writeStringField("the-kind", "text");"#,
+            r#"provider.defaultSerializeField("my-data", variant.value(), generator);"#,
+            r#"String tag = node.path("the-kind").asText();"#,
+            r#"JsonNode data = node.get("my-data");"#,
+        ],
+    );
+    // Jackson would read `"12"` as a number and `42` as a string without the checks
+    assert_lines_in_order(
+        Language::Java,
+        &[
+            "if (node.isNumber()) {",
+            "return new IdNumber(read(context, node, new TypeReference<Double>() {}));",
+            "if (node.isTextual()) {",
+            "return new IdString(read(context, node, new TypeReference<String>() {}));",
+        ],
+    );
+}
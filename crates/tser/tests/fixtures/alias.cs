@@ -0,0 +1,36 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.Linq;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace Models;
+
+/// <summary>Opaque, so it can't be mixed up with other strings.</summary>
+[JsonConverter(typeof(UserId.Converter))]
+public readonly record struct UserId(string Value)
+{
+    public class Converter : JsonConverter<UserId>
+    {
+        public override UserId Read(ref Utf8JsonReader reader, Type typeToConvert, JsonSerializerOptions options) =>
+            new(JsonSerializer.Deserialize<string>(ref reader, options)!);
+
+        public override void Write(Utf8JsonWriter writer, UserId value, JsonSerializerOptions options) =>
+            JsonSerializer.Serialize(writer, value.Value, options);
+    }
+}
+
+public record User
+{
+    [JsonPropertyName("id")]
+    public required UserId Id { get; init; }
+
+    [JsonPropertyName("tags")]
+    public required List<string> Tags { get; init; }
+
+    [JsonPropertyName("friends")]
+    public required List<UserId>? Friends { get; init; }
+}
+
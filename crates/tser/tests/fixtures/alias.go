@@ -0,0 +1,15 @@
+package models
+
+// Opaque, so it can't be mixed up with other strings.
+type UserId string
+
+type Tags = []string
+
+type PageUserId = *[]UserId
+
+type User struct {
+    Id UserId `json:"id"`
+    Tags Tags `json:"tags"`
+    Friends PageUserId `json:"friends"`
+}
+
@@ -0,0 +1,38 @@
+package models;
+
+import com.fasterxml.jackson.annotation.*;
+import com.fasterxml.jackson.core.*;
+import com.fasterxml.jackson.core.type.TypeReference;
+import com.fasterxml.jackson.databind.*;
+import com.fasterxml.jackson.databind.annotation.*;
+import com.fasterxml.jackson.databind.deser.std.StdDeserializer;
+import com.fasterxml.jackson.databind.node.NullNode;
+import com.fasterxml.jackson.databind.ser.std.StdSerializer;
+import java.io.IOException;
+import java.math.BigInteger;
+import java.util.*;
+
+public final class Models {
+    private Models() {
+    }
+
+    /** Opaque, so it can't be mixed up with other strings. */
+    public record UserId(String value) {
+        @JsonCreator(mode = JsonCreator.Mode.DELEGATING)
+        public UserId {
+        }
+
+        @JsonValue
+        @Override
+        public String value() {
+            return value;
+        }
+    }
+
+    public record User(
+        @JsonProperty(value = "id", required = true) UserId id,
+        @JsonProperty(value = "tags", required = true) List<String> tags,
+        @JsonProperty(value = "friends", required = true) List<UserId> friends
+    ) {}
+}
+
@@ -0,0 +1,23 @@
+@file:OptIn(kotlinx.serialization.ExperimentalSerializationApi::class)
+
+import kotlinx.serialization.*
+import kotlinx.serialization.descriptors.*
+import kotlinx.serialization.encoding.*
+import kotlinx.serialization.json.*
+
+/** Opaque, so it can't be mixed up with other strings. */
+@JvmInline
+@Serializable
+value class UserId(val value: String)
+
+typealias Tags = List<String>
+
+typealias PageUserId = List<UserId>?
+
+@Serializable
+data class User(
+    val id: UserId,
+    val tags: Tags,
+    val friends: PageUserId,
+)
+
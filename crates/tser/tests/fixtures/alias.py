@@ -0,0 +1,18 @@
+# Requires pydantic>=2.12
+import enum
+from typing import Annotated, Dict, List, Literal, NewType, Optional, Tuple, Union
+
+from pydantic import BaseModel, ConfigDict, Field
+
+# Opaque, so it can't be mixed up with other strings.
+UserId = NewType("UserId", str)
+
+Tags = List[str]
+
+PageUserId = Optional[List[UserId]]
+
+class User(BaseModel):
+    id: UserId
+    tags: Tags
+    friends: PageUserId
+
@@ -0,0 +1,18 @@
+use serde::{Serialize, Deserialize};
+
+/// Opaque, so it can't be mixed up with other strings.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(transparent)]
+pub struct UserId(pub String);
+
+pub type Tags = Vec<String>;
+
+pub type Page<T> = Option<Vec<T>>;
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct User {
+    pub id: UserId,
+    pub tags: Tags,
+    pub friends: Page<UserId>,
+}
+
@@ -0,0 +1,28 @@
+/// Opaque, so it can't be mixed up with other strings.
+public struct UserId: Codable, Equatable, Hashable {
+    public var rawValue: String
+
+    public init(_ rawValue: String) {
+        self.rawValue = rawValue
+    }
+
+    public init(from decoder: Decoder) throws {
+        rawValue = try decoder.singleValueContainer().decode(String.self)
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        try container.encode(rawValue)
+    }
+}
+
+public typealias Tags = [String]
+
+public typealias Page<T> = [T]?
+
+public struct User: Codable, Equatable, Hashable {
+    public var id: UserId
+    public var tags: Tags
+    public var friends: Page<UserId>
+}
+
@@ -0,0 +1,15 @@
+/**
+ * Opaque, so it can't be mixed up with other strings.
+ * @newtype
+ */
+type UserId = string;
+
+type Tags = string[];
+
+type Page<T> = T[] | null;
+
+interface User {
+    id: UserId;
+    tags: Tags;
+    friends: Page<UserId>;
+}
@@ -0,0 +1,161 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.Linq;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace Models;
+
+/// <summary>
+/// A registered user.
+///
+/// Users are created on sign-up.
+/// </summary>
+public record User
+{
+    /// <summary>The primary key</summary>
+    [JsonPropertyName("id")]
+    public required string Id { get; init; }
+
+    /// <summary>Shown to other users</summary>
+    [JsonPropertyName("name")]
+    public required string Name { get; init; }
+
+    [JsonPropertyName("email")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? Email { get; init; }
+}
+
+/// <summary>How much a user is trusted</summary>
+public enum Level
+{
+    /// <summary>Can only read</summary>
+    Reader = 1,
+    Writer = 2,
+}
+
+/// <summary>A value in the UI</summary>
+[JsonConverter(typeof(JsonStringEnumConverter<Status>))]
+public enum Status
+{
+    /// <summary>Still running</summary>
+    [JsonStringEnumMemberName("pending")]
+    Pending,
+    [JsonStringEnumMemberName("done")]
+    Done,
+}
+
+/// <summary>A drawable shape</summary>
+[JsonPolymorphic(TypeDiscriminatorPropertyName = "type")]
+[JsonDerivedType(typeof(ShapeCircle), "circle")]
+[JsonDerivedType(typeof(ShapeSquare), "square")]
+public abstract record Shape;
+
+/// <summary>A circle around the origin</summary>
+public record ShapeCircle : Shape
+{
+    [JsonPropertyName("radius")]
+    public required double Radius { get; init; }
+}
+
+public record ShapeSquare : Shape
+{
+    [JsonPropertyName("side")]
+    public required double Side { get; init; }
+
+    [JsonPropertyName("rotation")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public double? Rotation { get; init; }
+}
+
+/// <summary>A message sent to the server</summary>
+[JsonConverter(typeof(Message.Converter))]
+public abstract record Message
+{
+    public class Converter : JsonConverter<Message>
+    {
+        public override Message Read(ref Utf8JsonReader reader, Type typeToConvert, JsonSerializerOptions options)
+        {
+            var element = JsonElement.ParseValue(ref reader);
+            var tag = element.GetProperty("kind").GetString();
+            element.TryGetProperty("data", out var data);
+            return tag switch
+            {
+                "hello" => new MessageHello(data.Deserialize<string>(options)!),
+                "bye" => new MessageBye(data.Deserialize<double>(options)!),
+                _ => throw new JsonException($"Unknown tag {tag}"),
+            };
+        }
+
+        public override void Write(Utf8JsonWriter writer, Message value, JsonSerializerOptions options)
+        {
+            writer.WriteStartObject();
+            switch (value)
+            {
+                case MessageHello variant:
+                    writer.WriteString("kind", "hello");
+                    writer.WritePropertyName("data");
+                    JsonSerializer.Serialize(writer, variant.Value, options);
+                    break;
+                case MessageBye variant:
+                    writer.WriteString("kind", "bye");
+                    writer.WritePropertyName("data");
+                    JsonSerializer.Serialize(writer, variant.Value, options);
+                    break;
+            }
+            writer.WriteEndObject();
+        }
+    }
+}
+
+/// <summary>Says hello</summary>
+public record MessageHello(string Value) : Message;
+public record MessageBye(double Value) : Message;
+
+[JsonConverter(typeof(Event.Converter))]
+public abstract record Event
+{
+    public class Converter : JsonConverter<Event>
+    {
+        public override Event Read(ref Utf8JsonReader reader, Type typeToConvert, JsonSerializerOptions options)
+        {
+            var element = JsonElement.ParseValue(ref reader);
+            var properties = element.EnumerateObject().ToList();
+            if (properties.Count != 1)
+            {
+                throw new JsonException("Expected exactly one key");
+            }
+            var (tag, data) = (properties[0].Name, properties[0].Value);
+            return tag switch
+            {
+                "key" => new EventKey(data.Deserialize<string>(options)!),
+                "click" => new EventClick(data.Deserialize<double>(options)!),
+                _ => throw new JsonException($"Unknown tag {tag}"),
+            };
+        }
+
+        public override void Write(Utf8JsonWriter writer, Event value, JsonSerializerOptions options)
+        {
+            writer.WriteStartObject();
+            switch (value)
+            {
+                case EventKey variant:
+                    writer.WritePropertyName("key");
+                    JsonSerializer.Serialize(writer, variant.Value, options);
+                    break;
+                case EventClick variant:
+                    writer.WritePropertyName("click");
+                    JsonSerializer.Serialize(writer, variant.Value, options);
+                    break;
+            }
+            writer.WriteEndObject();
+        }
+    }
+}
+
+/// <summary>A key was pressed</summary>
+public record EventKey(string Value) : Event;
+public record EventClick(double Value) : Event;
+
@@ -0,0 +1,221 @@
+package models
+
+import (
+    "encoding/json"
+    "fmt"
+)
+
+// A registered user.
+//
+// Users are created on sign-up.
+type User struct {
+    // The primary key
+    Id string `json:"id"`
+    // Shown to other users
+    Name string `json:"name"`
+    Email *string `json:"email,omitempty"`
+}
+
+// How much a user is trusted
+type Level int64
+
+const (
+    // Can only read
+    LevelReader Level = 1
+    LevelWriter Level = 2
+)
+
+// A value in the UI
+type Status string
+
+const (
+    // Still running
+    StatusPending Status = "pending"
+    StatusDone Status = "done"
+)
+
+// A drawable shape
+type Shape struct {
+    Value isShape
+}
+
+type isShape interface {
+    isShape()
+}
+
+// A circle around the origin
+type ShapeCircle struct {
+    Radius float64 `json:"radius"`
+}
+
+func (ShapeCircle) isShape() {}
+
+type ShapeSquare struct {
+    Side float64 `json:"side"`
+    Rotation *float64 `json:"rotation,omitempty"`
+}
+
+func (ShapeSquare) isShape() {}
+
+func (u Shape) MarshalJSON() ([]byte, error) {
+    switch value := u.Value.(type) {
+    case ShapeCircle:
+        return json.Marshal(struct {
+            Tag string `json:"type"`
+            ShapeCircle
+        }{"circle", value})
+    case ShapeSquare:
+        return json.Marshal(struct {
+            Tag string `json:"type"`
+            ShapeSquare
+        }{"square", value})
+    default:
+        return nil, fmt.Errorf("unexpected Shape variant %T", u.Value)
+    }
+}
+
+func (u *Shape) UnmarshalJSON(data []byte) error {
+    var tagged struct {
+        Tag string `json:"type"`
+    }
+    if err := json.Unmarshal(data, &tagged); err != nil {
+        return err
+    }
+    var err error
+    switch tagged.Tag {
+    case "circle":
+        var value ShapeCircle
+        err = json.Unmarshal(data, &value)
+        u.Value = value
+    case "square":
+        var value ShapeSquare
+        err = json.Unmarshal(data, &value)
+        u.Value = value
+    default:
+        return fmt.Errorf("unknown Shape tag %q", tagged.Tag)
+    }
+    return err
+}
+
+// A message sent to the server
+type Message struct {
+    Value isMessage
+}
+
+type isMessage interface {
+    isMessage()
+}
+
+// Says hello
+type MessageHello struct {
+    Value string
+}
+
+func (MessageHello) isMessage() {}
+
+type MessageBye struct {
+    Value float64
+}
+
+func (MessageBye) isMessage() {}
+
+func (u Message) MarshalJSON() ([]byte, error) {
+    switch value := u.Value.(type) {
+    case MessageHello:
+        return json.Marshal(struct {
+            Tag string `json:"kind"`
+            Data string `json:"data"`
+        }{"hello", value.Value})
+    case MessageBye:
+        return json.Marshal(struct {
+            Tag string `json:"kind"`
+            Data float64 `json:"data"`
+        }{"bye", value.Value})
+    default:
+        return nil, fmt.Errorf("unexpected Message variant %T", u.Value)
+    }
+}
+
+func (u *Message) UnmarshalJSON(data []byte) error {
+    var tagged struct {
+        Tag string `json:"kind"`
+        Data json.RawMessage `json:"data"`
+    }
+    if err := json.Unmarshal(data, &tagged); err != nil {
+        return err
+    }
+    var err error
+    switch tagged.Tag {
+    case "hello":
+        var value MessageHello
+        err = json.Unmarshal(tagged.Data, &value.Value)
+        u.Value = value
+    case "bye":
+        var value MessageBye
+        err = json.Unmarshal(tagged.Data, &value.Value)
+        u.Value = value
+    default:
+        return fmt.Errorf("unknown Message tag %q", tagged.Tag)
+    }
+    return err
+}
+
+type Event struct {
+    Value isEvent
+}
+
+type isEvent interface {
+    isEvent()
+}
+
+// A key was pressed
+type EventKey struct {
+    Value string
+}
+
+func (EventKey) isEvent() {}
+
+type EventClick struct {
+    Value float64
+}
+
+func (EventClick) isEvent() {}
+
+func (u Event) MarshalJSON() ([]byte, error) {
+    switch value := u.Value.(type) {
+    case EventKey:
+        return json.Marshal(map[string]any{"key": value.Value})
+    case EventClick:
+        return json.Marshal(map[string]any{"click": value.Value})
+    default:
+        return nil, fmt.Errorf("unexpected Event variant %T", u.Value)
+    }
+}
+
+func (u *Event) UnmarshalJSON(data []byte) error {
+    var tagged map[string]json.RawMessage
+    if err := json.Unmarshal(data, &tagged); err != nil {
+        return err
+    }
+    if len(tagged) != 1 {
+        return fmt.Errorf("expected exactly one key in Event, got %d", len(tagged))
+    }
+    for tag, content := range tagged {
+        var err error
+        switch tag {
+        case "key":
+            var value EventKey
+            err = json.Unmarshal(content, &value.Value)
+            u.Value = value
+        case "click":
+            var value EventClick
+            err = json.Unmarshal(content, &value.Value)
+            u.Value = value
+        default:
+            return fmt.Errorf("unknown Event tag %q", tag)
+        }
+        return err
+    }
+    return nil
+}
+
@@ -0,0 +1,143 @@
+@file:OptIn(kotlinx.serialization.ExperimentalSerializationApi::class)
+
+import kotlinx.serialization.*
+import kotlinx.serialization.descriptors.*
+import kotlinx.serialization.encoding.*
+import kotlinx.serialization.json.*
+
+/**
+ * A registered user.
+ *
+ * Users are created on sign-up.
+ */
+@Serializable
+data class User(
+    /** The primary key */
+    val id: String,
+    /** Shown to other users */
+    val name: String,
+    val email: String? = null,
+)
+
+/** How much a user is trusted */
+@Serializable(with = Level.Serializer::class)
+enum class Level(val value: Long) {
+    /** Can only read */
+    Reader(1),
+    Writer(2);
+
+    object Serializer : KSerializer<Level> {
+        override val descriptor = PrimitiveSerialDescriptor("Level", PrimitiveKind.LONG)
+
+        override fun serialize(encoder: Encoder, value: Level) = encoder.encodeLong(value.value)
+
+        override fun deserialize(decoder: Decoder): Level {
+            val value = decoder.decodeLong()
+            return Level.entries.firstOrNull { it.value == value }
+                ?: throw SerializationException("Unknown Level $value")
+        }
+    }
+}
+
+/** A value in the UI */
+@Serializable
+enum class Status {
+    /** Still running */
+    @SerialName("pending") Pending,
+    @SerialName("done") Done,
+}
+
+/** A drawable shape */
+@Serializable
+@JsonClassDiscriminator("type")
+sealed interface Shape
+
+/** A circle around the origin */
+@Serializable
+@SerialName("circle")
+data class ShapeCircle(
+    val radius: Double,
+) : Shape
+
+@Serializable
+@SerialName("square")
+data class ShapeSquare(
+    val side: Double,
+    val rotation: Double? = null,
+) : Shape
+
+/** A message sent to the server */
+@Serializable(with = Message.Serializer::class)
+sealed interface Message {
+    object Serializer : KSerializer<Message> {
+        override val descriptor = buildClassSerialDescriptor("Message")
+
+        override fun serialize(encoder: Encoder, value: Message) {
+            val output = encoder as JsonEncoder
+            val element = buildJsonObject {
+                when (value) {
+                    is MessageHello -> {
+                        put("kind", "hello")
+                        put("data", output.json.encodeToJsonElement(value.value))
+                    }
+                    is MessageBye -> {
+                        put("kind", "bye")
+                        put("data", output.json.encodeToJsonElement(value.value))
+                    }
+                }
+            }
+            output.encodeJsonElement(element)
+        }
+
+        override fun deserialize(decoder: Decoder): Message {
+            val input = decoder as JsonDecoder
+            val element = input.decodeJsonElement().jsonObject
+            val data = element["data"] ?: JsonNull
+            return when (val tag = element["kind"]?.jsonPrimitive?.contentOrNull) {
+                "hello" -> MessageHello(input.json.decodeFromJsonElement(data))
+                "bye" -> MessageBye(input.json.decodeFromJsonElement(data))
+                else -> throw SerializationException("Unknown tag $tag")
+            }
+        }
+    }
+}
+
+/** Says hello */
+data class MessageHello(val value: String) : Message
+data class MessageBye(val value: Double) : Message
+
+@Serializable(with = Event.Serializer::class)
+sealed interface Event {
+    object Serializer : KSerializer<Event> {
+        override val descriptor = buildClassSerialDescriptor("Event")
+
+        override fun serialize(encoder: Encoder, value: Event) {
+            val output = encoder as JsonEncoder
+            val element = when (value) {
+                is EventKey -> buildJsonObject {
+                    put("key", output.json.encodeToJsonElement(value.value))
+                }
+                is EventClick -> buildJsonObject {
+                    put("click", output.json.encodeToJsonElement(value.value))
+                }
+            }
+            output.encodeJsonElement(element)
+        }
+
+        override fun deserialize(decoder: Decoder): Event {
+            val input = decoder as JsonDecoder
+            val (tag, element) = input.decodeJsonElement().jsonObject.entries.singleOrNull()
+                ?: throw SerializationException("Expected exactly one key")
+            return when (tag) {
+                "key" -> EventKey(input.json.decodeFromJsonElement(element))
+                "click" -> EventClick(input.json.decodeFromJsonElement(element))
+                else -> throw SerializationException("Unknown tag $tag")
+            }
+        }
+    }
+}
+
+/** A key was pressed */
+data class EventKey(val value: String) : Event
+data class EventClick(val value: Double) : Event
+
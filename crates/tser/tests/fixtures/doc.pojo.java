@@ -0,0 +1,251 @@
+package models;
+
+import com.fasterxml.jackson.annotation.*;
+import com.fasterxml.jackson.core.*;
+import com.fasterxml.jackson.core.type.TypeReference;
+import com.fasterxml.jackson.databind.*;
+import com.fasterxml.jackson.databind.annotation.*;
+import com.fasterxml.jackson.databind.deser.std.StdDeserializer;
+import com.fasterxml.jackson.databind.node.NullNode;
+import com.fasterxml.jackson.databind.ser.std.StdSerializer;
+import java.io.IOException;
+import java.math.BigInteger;
+import java.util.*;
+
+public final class Models {
+    private Models() {
+    }
+
+    private static <T> T read(DeserializationContext context, JsonNode node, TypeReference<T> type) throws IOException {
+        JavaType javaType = context.getTypeFactory().constructType(type);
+        return context.readTreeAsValue(node == null ? NullNode.getInstance() : node, javaType);
+    }
+
+    /**
+     * A registered user.
+     *
+     * Users are created on sign-up.
+     */
+    public static class User {
+        /** The primary key */
+        @JsonProperty(value = "id", required = true)
+        private String id;
+
+        /** Shown to other users */
+        @JsonProperty(value = "name", required = true)
+        private String name;
+
+        @JsonProperty("email")
+        @JsonInclude(JsonInclude.Include.NON_NULL)
+        private String email;
+
+        public String getId() {
+            return id;
+        }
+
+        public void setId(String id) {
+            this.id = id;
+        }
+
+        public String getName() {
+            return name;
+        }
+
+        public void setName(String name) {
+            this.name = name;
+        }
+
+        public String getEmail() {
+            return email;
+        }
+
+        public void setEmail(String email) {
+            this.email = email;
+        }
+    }
+
+    /** How much a user is trusted */
+    public enum Level {
+        /** Can only read */
+        READER(1L),
+        WRITER(2L);
+
+        private final long value;
+
+        Level(long value) {
+            this.value = value;
+        }
+
+        @JsonValue
+        public long value() {
+            return value;
+        }
+
+        @JsonCreator
+        public static Level of(long value) {
+            for (Level constant : values()) {
+                if (constant.value == value) {
+                    return constant;
+                }
+            }
+            throw new IllegalArgumentException("Unknown Level " + value);
+        }
+    }
+
+    /** A value in the UI */
+    public enum Status {
+        /** Still running */
+        PENDING("pending"),
+        DONE("done");
+
+        private final String value;
+
+        Status(String value) {
+            this.value = value;
+        }
+
+        @JsonValue
+        public String value() {
+            return value;
+        }
+    }
+
+    /** A drawable shape */
+    @JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = "type")
+    @JsonSubTypes({
+        @JsonSubTypes.Type(value = ShapeCircle.class, name = "circle"),
+        @JsonSubTypes.Type(value = ShapeSquare.class, name = "square"),
+    })
+    public sealed interface Shape permits ShapeCircle, ShapeSquare {}
+
+    /** A circle around the origin */
+    public static final class ShapeCircle implements Shape {
+        @JsonProperty(value = "radius", required = true)
+        private double radius;
+
+        public double getRadius() {
+            return radius;
+        }
+
+        public void setRadius(double radius) {
+            this.radius = radius;
+        }
+    }
+
+    public static final class ShapeSquare implements Shape {
+        @JsonProperty(value = "side", required = true)
+        private double side;
+
+        @JsonProperty("rotation")
+        @JsonInclude(JsonInclude.Include.NON_NULL)
+        private Double rotation;
+
+        public double getSide() {
+            return side;
+        }
+
+        public void setSide(double side) {
+            this.side = side;
+        }
+
+        public Double getRotation() {
+            return rotation;
+        }
+
+        public void setRotation(Double rotation) {
+            this.rotation = rotation;
+        }
+    }
+
+    /** A message sent to the server */
+    @JsonSerialize(using = Message.Serializer.class)
+    @JsonDeserialize(using = Message.Deserializer.class)
+    public sealed interface Message permits MessageHello, MessageBye {
+        final class Serializer extends StdSerializer<Message> {
+            public Serializer() {
+                super(Message.class);
+            }
+
+            @Override
+            public void serialize(Message value, JsonGenerator generator, SerializerProvider provider) throws IOException {
+                generator.writeStartObject();
+                if (value instanceof MessageHello variant) {
+                    generator.writeStringField("kind", "hello");
+                    provider.defaultSerializeField("data", variant.value(), generator);
+                } else if (value instanceof MessageBye variant) {
+                    generator.writeStringField("kind", "bye");
+                    provider.defaultSerializeField("data", variant.value(), generator);
+                }
+                generator.writeEndObject();
+            }
+        }
+
+        final class Deserializer extends StdDeserializer<Message> {
+            public Deserializer() {
+                super(Message.class);
+            }
+
+            @Override
+            public Message deserialize(JsonParser parser, DeserializationContext context) throws IOException {
+                JsonNode node = parser.readValueAsTree();
+                String tag = node.path("kind").asText();
+                JsonNode data = node.get("data");
+                return switch (tag) {
+                    case "hello" -> new MessageHello(read(context, data, new TypeReference<String>() {}));
+                    case "bye" -> new MessageBye(read(context, data, new TypeReference<Double>() {}));
+                    default -> throw JsonMappingException.from(parser, "Unknown tag " + tag);
+                };
+            }
+        }
+    }
+
+    /** Says hello */
+    public record MessageHello(String value) implements Message {}
+    public record MessageBye(double value) implements Message {}
+
+    @JsonSerialize(using = Event.Serializer.class)
+    @JsonDeserialize(using = Event.Deserializer.class)
+    public sealed interface Event permits EventKey, EventClick {
+        final class Serializer extends StdSerializer<Event> {
+            public Serializer() {
+                super(Event.class);
+            }
+
+            @Override
+            public void serialize(Event value, JsonGenerator generator, SerializerProvider provider) throws IOException {
+                generator.writeStartObject();
+                if (value instanceof EventKey variant) {
+                    provider.defaultSerializeField("key", variant.value(), generator);
+                } else if (value instanceof EventClick variant) {
+                    provider.defaultSerializeField("click", variant.value(), generator);
+                }
+                generator.writeEndObject();
+            }
+        }
+
+        final class Deserializer extends StdDeserializer<Event> {
+            public Deserializer() {
+                super(Event.class);
+            }
+
+            @Override
+            public Event deserialize(JsonParser parser, DeserializationContext context) throws IOException {
+                JsonNode node = parser.readValueAsTree();
+                if (!node.isObject() || node.size() != 1) {
+                    throw JsonMappingException.from(parser, "Expected exactly one key");
+                }
+                Map.Entry<String, JsonNode> entry = node.fields().next();
+                return switch (entry.getKey()) {
+                    case "key" -> new EventKey(read(context, entry.getValue(), new TypeReference<String>() {}));
+                    case "click" -> new EventClick(read(context, entry.getValue(), new TypeReference<Double>() {}));
+                    default -> throw JsonMappingException.from(parser, "Unknown tag " + entry.getKey());
+                };
+            }
+        }
+    }
+
+    /** A key was pressed */
+    public record EventKey(String value) implements Event {}
+    public record EventClick(double value) implements Event {}
+}
+
@@ -0,0 +1,65 @@
+# Requires pydantic>=2.12
+import enum
+from typing import Annotated, Dict, List, Literal, NewType, Optional, Tuple, Union
+
+from pydantic import BaseModel, ConfigDict, Field
+
+class User(BaseModel):
+    """A registered user.
+
+    Users are created on sign-up.
+    """
+    id: str
+    """The primary key"""
+    name: str
+    """Shown to other users"""
+    email: Optional[str] = Field(default=None, exclude_if=lambda value: value is None)
+
+class Level(enum.IntEnum):
+    """How much a user is trusted"""
+    READER = 1
+    """Can only read"""
+    WRITER = 2
+
+class Status(enum.Enum):
+    """A value in the UI"""
+    PENDING = "pending"
+    """Still running"""
+    DONE = "done"
+
+class ShapeCircle(BaseModel):
+    """A circle around the origin"""
+    type: Literal["circle"] = "circle"
+    radius: float
+
+class ShapeSquare(BaseModel):
+    type: Literal["square"] = "square"
+    side: float
+    rotation: Optional[float] = Field(default=None, exclude_if=lambda value: value is None)
+
+# A drawable shape
+Shape = Annotated[Union[ShapeCircle, ShapeSquare], Field(discriminator="type")]
+
+class MessageHello(BaseModel):
+    """Says hello"""
+    kind: Literal["hello"] = "hello"
+    data: str
+
+class MessageBye(BaseModel):
+    kind: Literal["bye"] = "bye"
+    data: float
+
+# A message sent to the server
+Message = Annotated[Union[MessageHello, MessageBye], Field(discriminator="kind")]
+
+class EventKey(BaseModel):
+    """A key was pressed"""
+    model_config = ConfigDict(extra="forbid")
+    key: str
+
+class EventClick(BaseModel):
+    model_config = ConfigDict(extra="forbid")
+    click: float
+
+Event = Union[EventKey, EventClick]
+
@@ -0,0 +1,65 @@
+use serde::{Serialize, Deserialize};
+
+/// A registered user.
+///
+/// Users are created on sign-up.
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct User {
+    /// The primary key
+    pub id: String,
+    /// Shown to other users
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+}
+
+/// How much a user is trusted
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[repr(i64)]
+pub enum Level {
+    /// Can only read
+    Reader = 1,
+    Writer = 2,
+}
+
+/// A value in the UI
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub enum Status {
+    /// Still running
+    #[serde(rename = "pending")] Pending,
+    #[serde(rename = "done")] Done,
+}
+
+/// A drawable shape
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(tag = "type")]
+pub enum Shape {
+    /// A circle around the origin
+    #[serde(rename = "circle")]
+    Circle {
+        radius: f64,
+    },
+    #[serde(rename = "square")]
+    Square {
+        side: f64,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        rotation: Option<f64>,
+    },
+}
+
+/// A message sent to the server
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(tag = "kind", content = "data")]
+pub enum Message {
+    /// Says hello
+    #[serde(rename = "hello")] Hello(String),
+    #[serde(rename = "bye")] Bye(f64),
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub enum Event {
+    /// A key was pressed
+    #[serde(rename = "key")] Key(String),
+    #[serde(rename = "click")] Click(f64),
+}
+
@@ -0,0 +1,136 @@
+/// A registered user.
+///
+/// Users are created on sign-up.
+public struct User: Codable, Equatable, Hashable {
+    /// The primary key
+    public var id: String
+    /// Shown to other users
+    public var name: String
+    public var email: String?
+}
+
+/// How much a user is trusted
+public enum Level: Int64, Codable, Equatable, Hashable {
+    /// Can only read
+    case Reader = 1
+    case Writer = 2
+}
+
+/// A value in the UI
+public enum Status: String, Codable, Equatable, Hashable {
+    /// Still running
+    case pending = "pending"
+    case done = "done"
+}
+
+/// A drawable shape
+public enum Shape: Codable, Equatable, Hashable {
+    /// A circle around the origin
+    case circle(radius: Double)
+    case square(side: Double, rotation: Double?)
+
+    private enum CodingKeys: String, CodingKey {
+        case type = "type"
+        case radius = "radius"
+        case side = "side"
+        case rotation = "rotation"
+    }
+
+    public init(from decoder: Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        let tag = try container.decode(String.self, forKey: .type)
+        switch tag {
+        case "circle":
+            self = try .circle(radius: container.decode(Double.self, forKey: .radius))
+        case "square":
+            self = try .square(side: container.decode(Double.self, forKey: .side), rotation: container.decodeIfPresent(Double.self, forKey: .rotation))
+        default:
+            throw DecodingError.dataCorruptedError(forKey: .type, in: container, debugDescription: "Unknown tag \(tag)")
+        }
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.container(keyedBy: CodingKeys.self)
+        switch self {
+        case let .circle(radius):
+            try container.encode("circle", forKey: .type)
+            try container.encode(radius, forKey: .radius)
+        case let .square(side, rotation):
+            try container.encode("square", forKey: .type)
+            try container.encode(side, forKey: .side)
+            try container.encodeIfPresent(rotation, forKey: .rotation)
+        }
+    }
+}
+
+/// A message sent to the server
+public enum Message: Codable, Equatable, Hashable {
+    /// Says hello
+    case hello(String)
+    case bye(Double)
+
+    private enum CodingKeys: String, CodingKey {
+        case kind = "kind"
+        case data = "data"
+    }
+
+    public init(from decoder: Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        let tag = try container.decode(String.self, forKey: .kind)
+        switch tag {
+        case "hello":
+            self = try .hello(container.decode(String.self, forKey: .data))
+        case "bye":
+            self = try .bye(container.decode(Double.self, forKey: .data))
+        default:
+            throw DecodingError.dataCorruptedError(forKey: .kind, in: container, debugDescription: "Unknown tag \(tag)")
+        }
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.container(keyedBy: CodingKeys.self)
+        switch self {
+        case let .hello(value):
+            try container.encode("hello", forKey: .kind)
+            try container.encode(value, forKey: .data)
+        case let .bye(value):
+            try container.encode("bye", forKey: .kind)
+            try container.encode(value, forKey: .data)
+        }
+    }
+}
+
+public enum Event: Codable, Equatable, Hashable {
+    /// A key was pressed
+    case key(String)
+    case click(Double)
+
+    private enum CodingKeys: String, CodingKey {
+        case key = "key"
+        case click = "click"
+    }
+
+    public init(from decoder: Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        guard container.allKeys.count == 1 else {
+            throw DecodingError.dataCorrupted(DecodingError.Context(codingPath: container.codingPath, debugDescription: "Expected exactly one key"))
+        }
+        switch container.allKeys[0] {
+        case .key:
+            self = try .key(container.decode(String.self, forKey: .key))
+        case .click:
+            self = try .click(container.decode(Double.self, forKey: .click))
+        }
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.container(keyedBy: CodingKeys.self)
+        switch self {
+        case let .key(value):
+            try container.encode(value, forKey: .key)
+        case let .click(value):
+            try container.encode(value, forKey: .click)
+        }
+    }
+}
+
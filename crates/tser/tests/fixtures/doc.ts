@@ -0,0 +1,42 @@
+/**
+ * A registered user.
+ *
+ * Users are created on sign-up.
+ */
+export interface User {
+    /** The primary key */
+    id: string;
+    // Shown to other users
+    name: string;
+    email?: string;
+}
+
+/** How much a user is trusted */
+enum Level {
+    /** Can only read */
+    Reader = 1,
+    Writer,
+}
+
+/** A value in the UI */
+type Status =
+    /** Still running */
+    | 'pending'
+    | 'done';
+
+/** A drawable shape */
+type Shape =
+    /** A circle around the origin */
+    | { type: 'circle', radius: number }
+    | { type: 'square', side: number, rotation?: number };
+
+/** A message sent to the server */
+type Message =
+    /** Says hello */
+    | { kind: 'hello', data: string }
+    | { kind: 'bye', data: number };
+
+type Event =
+    /** A key was pressed */
+    | { key: string }
+    | { click: number };
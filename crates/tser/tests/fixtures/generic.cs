@@ -0,0 +1,79 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.Linq;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace Models;
+
+public record User
+{
+    [JsonPropertyName("name")]
+    public required string Name { get; init; }
+}
+
+public record PageUser
+{
+    [JsonPropertyName("items")]
+    public required List<User> Items { get; init; }
+
+    [JsonPropertyName("next")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? Next { get; init; }
+}
+
+[JsonConverter(typeof(OutcomeNumberArray.Converter))]
+public abstract record OutcomeNumberArray
+{
+    public class Converter : JsonConverter<OutcomeNumberArray>
+    {
+        public override OutcomeNumberArray Read(ref Utf8JsonReader reader, Type typeToConvert, JsonSerializerOptions options)
+        {
+            var element = JsonElement.ParseValue(ref reader);
+            var properties = element.EnumerateObject().ToList();
+            if (properties.Count != 1)
+            {
+                throw new JsonException("Expected exactly one key");
+            }
+            var (tag, data) = (properties[0].Name, properties[0].Value);
+            return tag switch
+            {
+                "ok" => new OutcomeNumberArrayOk(data.Deserialize<List<double>>(options)!),
+                "err" => new OutcomeNumberArrayErr(data.Deserialize<string>(options)!),
+                _ => throw new JsonException($"Unknown tag {tag}"),
+            };
+        }
+
+        public override void Write(Utf8JsonWriter writer, OutcomeNumberArray value, JsonSerializerOptions options)
+        {
+            writer.WriteStartObject();
+            switch (value)
+            {
+                case OutcomeNumberArrayOk variant:
+                    writer.WritePropertyName("ok");
+                    JsonSerializer.Serialize(writer, variant.Value, options);
+                    break;
+                case OutcomeNumberArrayErr variant:
+                    writer.WritePropertyName("err");
+                    JsonSerializer.Serialize(writer, variant.Value, options);
+                    break;
+            }
+            writer.WriteEndObject();
+        }
+    }
+}
+
+public record OutcomeNumberArrayOk(List<double> Value) : OutcomeNumberArray;
+public record OutcomeNumberArrayErr(string Value) : OutcomeNumberArray;
+
+public record Response
+{
+    [JsonPropertyName("users")]
+    public required PageUser Users { get; init; }
+
+    [JsonPropertyName("outcome")]
+    public required OutcomeNumberArray Outcome { get; init; }
+}
+
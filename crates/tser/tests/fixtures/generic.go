@@ -0,0 +1,79 @@
+package models
+
+import (
+    "encoding/json"
+    "fmt"
+)
+
+type User struct {
+    Name string `json:"name"`
+}
+
+type PageUser struct {
+    Items []User `json:"items"`
+    Next *string `json:"next,omitempty"`
+}
+
+type OutcomeNumberArray struct {
+    Value isOutcomeNumberArray
+}
+
+type isOutcomeNumberArray interface {
+    isOutcomeNumberArray()
+}
+
+type OutcomeNumberArrayOk struct {
+    Value []float64
+}
+
+func (OutcomeNumberArrayOk) isOutcomeNumberArray() {}
+
+type OutcomeNumberArrayErr struct {
+    Value string
+}
+
+func (OutcomeNumberArrayErr) isOutcomeNumberArray() {}
+
+func (u OutcomeNumberArray) MarshalJSON() ([]byte, error) {
+    switch value := u.Value.(type) {
+    case OutcomeNumberArrayOk:
+        return json.Marshal(map[string]any{"ok": value.Value})
+    case OutcomeNumberArrayErr:
+        return json.Marshal(map[string]any{"err": value.Value})
+    default:
+        return nil, fmt.Errorf("unexpected OutcomeNumberArray variant %T", u.Value)
+    }
+}
+
+func (u *OutcomeNumberArray) UnmarshalJSON(data []byte) error {
+    var tagged map[string]json.RawMessage
+    if err := json.Unmarshal(data, &tagged); err != nil {
+        return err
+    }
+    if len(tagged) != 1 {
+        return fmt.Errorf("expected exactly one key in OutcomeNumberArray, got %d", len(tagged))
+    }
+    for tag, content := range tagged {
+        var err error
+        switch tag {
+        case "ok":
+            var value OutcomeNumberArrayOk
+            err = json.Unmarshal(content, &value.Value)
+            u.Value = value
+        case "err":
+            var value OutcomeNumberArrayErr
+            err = json.Unmarshal(content, &value.Value)
+            u.Value = value
+        default:
+            return fmt.Errorf("unknown OutcomeNumberArray tag %q", tag)
+        }
+        return err
+    }
+    return nil
+}
+
+type Response struct {
+    Users PageUser `json:"users"`
+    Outcome OutcomeNumberArray `json:"outcome"`
+}
+
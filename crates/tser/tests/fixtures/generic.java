@@ -0,0 +1,82 @@
+package models;
+
+import com.fasterxml.jackson.annotation.*;
+import com.fasterxml.jackson.core.*;
+import com.fasterxml.jackson.core.type.TypeReference;
+import com.fasterxml.jackson.databind.*;
+import com.fasterxml.jackson.databind.annotation.*;
+import com.fasterxml.jackson.databind.deser.std.StdDeserializer;
+import com.fasterxml.jackson.databind.node.NullNode;
+import com.fasterxml.jackson.databind.ser.std.StdSerializer;
+import java.io.IOException;
+import java.math.BigInteger;
+import java.util.*;
+
+public final class Models {
+    private Models() {
+    }
+
+    private static <T> T read(DeserializationContext context, JsonNode node, TypeReference<T> type) throws IOException {
+        JavaType javaType = context.getTypeFactory().constructType(type);
+        return context.readTreeAsValue(node == null ? NullNode.getInstance() : node, javaType);
+    }
+
+    public record User(
+        @JsonProperty(value = "name", required = true) String name
+    ) {}
+
+    public record PageUser(
+        @JsonProperty(value = "items", required = true) List<User> items,
+        @JsonProperty("next") @JsonInclude(JsonInclude.Include.NON_NULL) String next
+    ) {}
+
+    @JsonSerialize(using = OutcomeNumberArray.Serializer.class)
+    @JsonDeserialize(using = OutcomeNumberArray.Deserializer.class)
+    public sealed interface OutcomeNumberArray permits OutcomeNumberArrayOk, OutcomeNumberArrayErr {
+        final class Serializer extends StdSerializer<OutcomeNumberArray> {
+            public Serializer() {
+                super(OutcomeNumberArray.class);
+            }
+
+            @Override
+            public void serialize(OutcomeNumberArray value, JsonGenerator generator, SerializerProvider provider) throws IOException {
+                generator.writeStartObject();
+                if (value instanceof OutcomeNumberArrayOk variant) {
+                    provider.defaultSerializeField("ok", variant.value(), generator);
+                } else if (value instanceof OutcomeNumberArrayErr variant) {
+                    provider.defaultSerializeField("err", variant.value(), generator);
+                }
+                generator.writeEndObject();
+            }
+        }
+
+        final class Deserializer extends StdDeserializer<OutcomeNumberArray> {
+            public Deserializer() {
+                super(OutcomeNumberArray.class);
+            }
+
+            @Override
+            public OutcomeNumberArray deserialize(JsonParser parser, DeserializationContext context) throws IOException {
+                JsonNode node = parser.readValueAsTree();
+                if (!node.isObject() || node.size() != 1) {
+                    throw JsonMappingException.from(parser, "Expected exactly one key");
+                }
+                Map.Entry<String, JsonNode> entry = node.fields().next();
+                return switch (entry.getKey()) {
+                    case "ok" -> new OutcomeNumberArrayOk(read(context, entry.getValue(), new TypeReference<List<Double>>() {}));
+                    case "err" -> new OutcomeNumberArrayErr(read(context, entry.getValue(), new TypeReference<String>() {}));
+                    default -> throw JsonMappingException.from(parser, "Unknown tag " + entry.getKey());
+                };
+            }
+        }
+    }
+
+    public record OutcomeNumberArrayOk(List<Double> value) implements OutcomeNumberArray {}
+    public record OutcomeNumberArrayErr(String value) implements OutcomeNumberArray {}
+
+    public record Response(
+        @JsonProperty(value = "users", required = true) PageUser users,
+        @JsonProperty(value = "outcome", required = true) OutcomeNumberArray outcome
+    ) {}
+}
+
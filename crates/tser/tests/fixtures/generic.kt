@@ -0,0 +1,58 @@
+@file:OptIn(kotlinx.serialization.ExperimentalSerializationApi::class)
+
+import kotlinx.serialization.*
+import kotlinx.serialization.descriptors.*
+import kotlinx.serialization.encoding.*
+import kotlinx.serialization.json.*
+
+@Serializable
+data class User(
+    val name: String,
+)
+
+@Serializable
+data class PageUser(
+    val items: List<User>,
+    val next: String? = null,
+)
+
+@Serializable(with = OutcomeNumberArray.Serializer::class)
+sealed interface OutcomeNumberArray {
+    object Serializer : KSerializer<OutcomeNumberArray> {
+        override val descriptor = buildClassSerialDescriptor("OutcomeNumberArray")
+
+        override fun serialize(encoder: Encoder, value: OutcomeNumberArray) {
+            val output = encoder as JsonEncoder
+            val element = when (value) {
+                is OutcomeNumberArrayOk -> buildJsonObject {
+                    put("ok", output.json.encodeToJsonElement(value.value))
+                }
+                is OutcomeNumberArrayErr -> buildJsonObject {
+                    put("err", output.json.encodeToJsonElement(value.value))
+                }
+            }
+            output.encodeJsonElement(element)
+        }
+
+        override fun deserialize(decoder: Decoder): OutcomeNumberArray {
+            val input = decoder as JsonDecoder
+            val (tag, element) = input.decodeJsonElement().jsonObject.entries.singleOrNull()
+                ?: throw SerializationException("Expected exactly one key")
+            return when (tag) {
+                "ok" -> OutcomeNumberArrayOk(input.json.decodeFromJsonElement(element))
+                "err" -> OutcomeNumberArrayErr(input.json.decodeFromJsonElement(element))
+                else -> throw SerializationException("Unknown tag $tag")
+            }
+        }
+    }
+}
+
+data class OutcomeNumberArrayOk(val value: List<Double>) : OutcomeNumberArray
+data class OutcomeNumberArrayErr(val value: String) : OutcomeNumberArray
+
+@Serializable
+data class Response(
+    val users: PageUser,
+    val outcome: OutcomeNumberArray,
+)
+
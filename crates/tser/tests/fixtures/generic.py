@@ -0,0 +1,27 @@
+# Requires pydantic>=2.12
+import enum
+from typing import Annotated, Dict, List, Literal, NewType, Optional, Tuple, Union
+
+from pydantic import BaseModel, ConfigDict, Field
+
+class User(BaseModel):
+    name: str
+
+class PageUser(BaseModel):
+    items: List[User]
+    next: Optional[str] = Field(default=None, exclude_if=lambda value: value is None)
+
+class OutcomeNumberArrayOk(BaseModel):
+    model_config = ConfigDict(extra="forbid")
+    ok: List[float]
+
+class OutcomeNumberArrayErr(BaseModel):
+    model_config = ConfigDict(extra="forbid")
+    err: str
+
+OutcomeNumberArray = Union[OutcomeNumberArrayOk, OutcomeNumberArrayErr]
+
+class Response(BaseModel):
+    users: PageUser
+    outcome: OutcomeNumberArray
+
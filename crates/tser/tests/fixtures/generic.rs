@@ -0,0 +1,26 @@
+use serde::{Serialize, Deserialize};
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct User {
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct Page<T> {
+    pub items: Vec<T>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub next: Option<String>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub enum Outcome<T> {
+    #[serde(rename = "ok")] Ok(T),
+    #[serde(rename = "err")] Err(String),
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct Response {
+    pub users: Page<User>,
+    pub outcome: Outcome<Vec<f64>>,
+}
+
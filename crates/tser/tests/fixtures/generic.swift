@@ -0,0 +1,47 @@
+public struct User: Codable, Equatable, Hashable {
+    public var name: String
+}
+
+public struct Page<T: Codable & Equatable & Hashable>: Codable, Equatable, Hashable {
+    public var items: [T]
+    public var next: String?
+}
+
+public enum Outcome<T: Codable & Equatable & Hashable>: Codable, Equatable, Hashable {
+    case ok(T)
+    case err(String)
+
+    private enum CodingKeys: String, CodingKey {
+        case ok = "ok"
+        case err = "err"
+    }
+
+    public init(from decoder: Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        guard container.allKeys.count == 1 else {
+            throw DecodingError.dataCorrupted(DecodingError.Context(codingPath: container.codingPath, debugDescription: "Expected exactly one key"))
+        }
+        switch container.allKeys[0] {
+        case .ok:
+            self = try .ok(container.decode(T.self, forKey: .ok))
+        case .err:
+            self = try .err(container.decode(String.self, forKey: .err))
+        }
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.container(keyedBy: CodingKeys.self)
+        switch self {
+        case let .ok(value):
+            try container.encode(value, forKey: .ok)
+        case let .err(value):
+            try container.encode(value, forKey: .err)
+        }
+    }
+}
+
+public struct Response: Codable, Equatable, Hashable {
+    public var users: Page<User>
+    public var outcome: Outcome<[Double]>
+}
+
@@ -0,0 +1,4 @@
+interface User { name: string }
+interface Page<T> { items: T[]; next?: string }
+type Outcome<T> = { ok: T } | { err: string }
+interface Response { users: Page<User>, outcome: Outcome<Array<number>> }
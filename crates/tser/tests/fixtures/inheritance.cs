@@ -0,0 +1,59 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.Linq;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace Models;
+
+public record Entity
+{
+    [JsonPropertyName("id")]
+    public required string Id { get; init; }
+}
+
+public record Named
+{
+    [JsonPropertyName("id")]
+    public required string Id { get; init; }
+
+    [JsonPropertyName("name")]
+    public required string Name { get; init; }
+}
+
+public record Dated
+{
+    [JsonPropertyName("id")]
+    public required string Id { get; init; }
+
+    [JsonPropertyName("created")]
+    public required double Created { get; init; }
+}
+
+public record PageString
+{
+    [JsonPropertyName("items")]
+    public required List<string> Items { get; init; }
+}
+
+public record User
+{
+    [JsonPropertyName("id")]
+    public required string Id { get; init; }
+
+    [JsonPropertyName("name")]
+    public required string Name { get; init; }
+
+    [JsonPropertyName("created")]
+    public required double Created { get; init; }
+
+    [JsonPropertyName("items")]
+    public required List<string> Items { get; init; }
+
+    [JsonPropertyName("email")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? Email { get; init; }
+}
+
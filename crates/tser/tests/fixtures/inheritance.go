@@ -0,0 +1,28 @@
+package models
+
+type Entity struct {
+    Id string `json:"id"`
+}
+
+type Named struct {
+    Id string `json:"id"`
+    Name string `json:"name"`
+}
+
+type Dated struct {
+    Id string `json:"id"`
+    Created float64 `json:"created"`
+}
+
+type PageString struct {
+    Items []string `json:"items"`
+}
+
+type User struct {
+    Id string `json:"id"`
+    Name string `json:"name"`
+    Created float64 `json:"created"`
+    Items []string `json:"items"`
+    Email *string `json:"email,omitempty"`
+}
+
@@ -0,0 +1,45 @@
+package models;
+
+import com.fasterxml.jackson.annotation.*;
+import com.fasterxml.jackson.core.*;
+import com.fasterxml.jackson.core.type.TypeReference;
+import com.fasterxml.jackson.databind.*;
+import com.fasterxml.jackson.databind.annotation.*;
+import com.fasterxml.jackson.databind.deser.std.StdDeserializer;
+import com.fasterxml.jackson.databind.node.NullNode;
+import com.fasterxml.jackson.databind.ser.std.StdSerializer;
+import java.io.IOException;
+import java.math.BigInteger;
+import java.util.*;
+
+public final class Models {
+    private Models() {
+    }
+
+    public record Entity(
+        @JsonProperty(value = "id", required = true) String id
+    ) {}
+
+    public record Named(
+        @JsonProperty(value = "id", required = true) String id,
+        @JsonProperty(value = "name", required = true) String name
+    ) {}
+
+    public record Dated(
+        @JsonProperty(value = "id", required = true) String id,
+        @JsonProperty(value = "created", required = true) double created
+    ) {}
+
+    public record PageString(
+        @JsonProperty(value = "items", required = true) List<String> items
+    ) {}
+
+    public record User(
+        @JsonProperty(value = "id", required = true) String id,
+        @JsonProperty(value = "name", required = true) String name,
+        @JsonProperty(value = "created", required = true) double created,
+        @JsonProperty(value = "items", required = true) List<String> items,
+        @JsonProperty("email") @JsonInclude(JsonInclude.Include.NON_NULL) String email
+    ) {}
+}
+
@@ -0,0 +1,38 @@
+@file:OptIn(kotlinx.serialization.ExperimentalSerializationApi::class)
+
+import kotlinx.serialization.*
+import kotlinx.serialization.descriptors.*
+import kotlinx.serialization.encoding.*
+import kotlinx.serialization.json.*
+
+@Serializable
+data class Entity(
+    val id: String,
+)
+
+@Serializable
+data class Named(
+    val id: String,
+    val name: String,
+)
+
+@Serializable
+data class Dated(
+    val id: String,
+    val created: Double,
+)
+
+@Serializable
+data class PageString(
+    val items: List<String>,
+)
+
+@Serializable
+data class User(
+    val id: String,
+    val name: String,
+    val created: Double,
+    val items: List<String>,
+    val email: String? = null,
+)
+
@@ -0,0 +1,27 @@
+# Requires pydantic>=2.12
+import enum
+from typing import Annotated, Dict, List, Literal, NewType, Optional, Tuple, Union
+
+from pydantic import BaseModel, ConfigDict, Field
+
+class Entity(BaseModel):
+    id: str
+
+class Named(BaseModel):
+    id: str
+    name: str
+
+class Dated(BaseModel):
+    id: str
+    created: float
+
+class PageString(BaseModel):
+    items: List[str]
+
+class User(BaseModel):
+    id: str
+    name: str
+    created: float
+    items: List[str]
+    email: Optional[str] = Field(default=None, exclude_if=lambda value: value is None)
+
@@ -0,0 +1,26 @@
+public struct Entity: Codable, Equatable, Hashable {
+    public var id: String
+}
+
+public struct Named: Codable, Equatable, Hashable {
+    public var id: String
+    public var name: String
+}
+
+public struct Dated: Codable, Equatable, Hashable {
+    public var id: String
+    public var created: Double
+}
+
+public struct Page<T: Codable & Equatable & Hashable>: Codable, Equatable, Hashable {
+    public var items: [T]
+}
+
+public struct User: Codable, Equatable, Hashable {
+    public var id: String
+    public var name: String
+    public var created: Double
+    public var items: [String]
+    public var email: String?
+}
+
@@ -0,0 +1,5 @@
+interface Entity { id: string }
+interface Named extends Entity { name: string }
+interface Dated extends Entity { created: number }
+interface Page<T> { items: T[] }
+interface User extends Named, Dated, Page<string> { email?: string }
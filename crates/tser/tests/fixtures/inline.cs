@@ -0,0 +1,80 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.Linq;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace Models;
+
+public record Order
+{
+    [JsonPropertyName("id")]
+    public required string Id { get; init; }
+
+    [JsonPropertyName("shippingAddress")]
+    public required OrderShippingAddress ShippingAddress { get; init; }
+
+    [JsonPropertyName("lines")]
+    public required List<OrderLines> Lines { get; init; }
+
+    [JsonPropertyName("total")]
+    public required Money Total { get; init; }
+}
+
+public record OrderShippingAddress
+{
+    [JsonPropertyName("street")]
+    public required string Street { get; init; }
+
+    [JsonPropertyName("geo")]
+    public required OrderShippingAddressGeo? Geo { get; init; }
+}
+
+public record OrderShippingAddressGeo
+{
+    [JsonPropertyName("lat")]
+    public required double Lat { get; init; }
+
+    [JsonPropertyName("lng")]
+    public required double Lng { get; init; }
+}
+
+public record OrderLines
+{
+    [JsonPropertyName("sku")]
+    public required string Sku { get; init; }
+
+    [JsonPropertyName("quantity")]
+    public required double Quantity { get; init; }
+}
+
+public record Money
+{
+    [JsonPropertyName("amount")]
+    public required double Amount { get; init; }
+
+    [JsonPropertyName("currency")]
+    public required string Currency { get; init; }
+}
+
+[JsonPolymorphic(TypeDiscriminatorPropertyName = "method")]
+[JsonDerivedType(typeof(PaymentCard), "card")]
+[JsonDerivedType(typeof(PaymentCash), "cash")]
+public abstract record Payment;
+
+public record PaymentCard : Payment
+{
+    [JsonPropertyName("card")]
+    public required PaymentCardCard Card { get; init; }
+}
+
+public record PaymentCash : Payment;
+
+public record PaymentCardCard
+{
+    [JsonPropertyName("last4")]
+    public required string Last4 { get; init; }
+}
+
@@ -0,0 +1,96 @@
+package models
+
+import (
+    "encoding/json"
+    "fmt"
+)
+
+type Order struct {
+    Id string `json:"id"`
+    ShippingAddress OrderShippingAddress `json:"shippingAddress"`
+    Lines []OrderLines `json:"lines"`
+    Total Money `json:"total"`
+}
+
+type OrderShippingAddress struct {
+    Street string `json:"street"`
+    Geo *OrderShippingAddressGeo `json:"geo"`
+}
+
+type OrderShippingAddressGeo struct {
+    Lat float64 `json:"lat"`
+    Lng float64 `json:"lng"`
+}
+
+type OrderLines struct {
+    Sku string `json:"sku"`
+    Quantity float64 `json:"quantity"`
+}
+
+type Money struct {
+    Amount float64 `json:"amount"`
+    Currency string `json:"currency"`
+}
+
+type Payment struct {
+    Value isPayment
+}
+
+type isPayment interface {
+    isPayment()
+}
+
+type PaymentCard struct {
+    Card PaymentCardCard `json:"card"`
+}
+
+func (PaymentCard) isPayment() {}
+
+type PaymentCash struct{}
+
+func (PaymentCash) isPayment() {}
+
+func (u Payment) MarshalJSON() ([]byte, error) {
+    switch value := u.Value.(type) {
+    case PaymentCard:
+        return json.Marshal(struct {
+            Tag string `json:"method"`
+            PaymentCard
+        }{"card", value})
+    case PaymentCash:
+        return json.Marshal(struct {
+            Tag string `json:"method"`
+            PaymentCash
+        }{"cash", value})
+    default:
+        return nil, fmt.Errorf("unexpected Payment variant %T", u.Value)
+    }
+}
+
+func (u *Payment) UnmarshalJSON(data []byte) error {
+    var tagged struct {
+        Tag string `json:"method"`
+    }
+    if err := json.Unmarshal(data, &tagged); err != nil {
+        return err
+    }
+    var err error
+    switch tagged.Tag {
+    case "card":
+        var value PaymentCard
+        err = json.Unmarshal(data, &value)
+        u.Value = value
+    case "cash":
+        var value PaymentCash
+        err = json.Unmarshal(data, &value)
+        u.Value = value
+    default:
+        return fmt.Errorf("unknown Payment tag %q", tagged.Tag)
+    }
+    return err
+}
+
+type PaymentCardCard struct {
+    Last4 string `json:"last4"`
+}
+
@@ -0,0 +1,63 @@
+package models;
+
+import com.fasterxml.jackson.annotation.*;
+import com.fasterxml.jackson.core.*;
+import com.fasterxml.jackson.core.type.TypeReference;
+import com.fasterxml.jackson.databind.*;
+import com.fasterxml.jackson.databind.annotation.*;
+import com.fasterxml.jackson.databind.deser.std.StdDeserializer;
+import com.fasterxml.jackson.databind.node.NullNode;
+import com.fasterxml.jackson.databind.ser.std.StdSerializer;
+import java.io.IOException;
+import java.math.BigInteger;
+import java.util.*;
+
+public final class Models {
+    private Models() {
+    }
+
+    public record Order(
+        @JsonProperty(value = "id", required = true) String id,
+        @JsonProperty(value = "shippingAddress", required = true) OrderShippingAddress shippingAddress,
+        @JsonProperty(value = "lines", required = true) List<OrderLines> lines,
+        @JsonProperty(value = "total", required = true) Money total
+    ) {}
+
+    public record OrderShippingAddress(
+        @JsonProperty(value = "street", required = true) String street,
+        @JsonProperty(value = "geo", required = true) OrderShippingAddressGeo geo
+    ) {}
+
+    public record OrderShippingAddressGeo(
+        @JsonProperty(value = "lat", required = true) double lat,
+        @JsonProperty(value = "lng", required = true) double lng
+    ) {}
+
+    public record OrderLines(
+        @JsonProperty(value = "sku", required = true) String sku,
+        @JsonProperty(value = "quantity", required = true) double quantity
+    ) {}
+
+    public record Money(
+        @JsonProperty(value = "amount", required = true) double amount,
+        @JsonProperty(value = "currency", required = true) String currency
+    ) {}
+
+    @JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = "method")
+    @JsonSubTypes({
+        @JsonSubTypes.Type(value = PaymentCard.class, name = "card"),
+        @JsonSubTypes.Type(value = PaymentCash.class, name = "cash"),
+    })
+    public sealed interface Payment permits PaymentCard, PaymentCash {}
+
+    public record PaymentCard(
+        @JsonProperty(value = "card", required = true) PaymentCardCard card
+    ) implements Payment {}
+
+    public record PaymentCash() implements Payment {}
+
+    public record PaymentCardCard(
+        @JsonProperty(value = "last4", required = true) String last4
+    ) {}
+}
+
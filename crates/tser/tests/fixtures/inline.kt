@@ -0,0 +1,58 @@
+@file:OptIn(kotlinx.serialization.ExperimentalSerializationApi::class)
+
+import kotlinx.serialization.*
+import kotlinx.serialization.descriptors.*
+import kotlinx.serialization.encoding.*
+import kotlinx.serialization.json.*
+
+@Serializable
+data class Order(
+    val id: String,
+    val shippingAddress: OrderShippingAddress,
+    val lines: List<OrderLines>,
+    val total: Money,
+)
+
+@Serializable
+data class OrderShippingAddress(
+    val street: String,
+    val geo: OrderShippingAddressGeo?,
+)
+
+@Serializable
+data class OrderShippingAddressGeo(
+    val lat: Double,
+    val lng: Double,
+)
+
+@Serializable
+data class OrderLines(
+    val sku: String,
+    val quantity: Double,
+)
+
+@Serializable
+data class Money(
+    val amount: Double,
+    val currency: String,
+)
+
+@Serializable
+@JsonClassDiscriminator("method")
+sealed interface Payment
+
+@Serializable
+@SerialName("card")
+data class PaymentCard(
+    val card: PaymentCardCard,
+) : Payment
+
+@Serializable
+@SerialName("cash")
+data object PaymentCash : Payment
+
+@Serializable
+data class PaymentCardCard(
+    val last4: String,
+)
+
@@ -0,0 +1,41 @@
+# Requires pydantic>=2.12
+import enum
+from typing import Annotated, Dict, List, Literal, NewType, Optional, Tuple, Union
+
+from pydantic import BaseModel, ConfigDict, Field
+
+class Order(BaseModel):
+    model_config = ConfigDict(validate_by_name=True, serialize_by_alias=True)
+    id: str
+    shipping_address: "OrderShippingAddress" = Field(alias="shippingAddress")
+    lines: List["OrderLines"]
+    total: "Money"
+
+class OrderShippingAddress(BaseModel):
+    street: str
+    geo: Optional["OrderShippingAddressGeo"]
+
+class OrderShippingAddressGeo(BaseModel):
+    lat: float
+    lng: float
+
+class OrderLines(BaseModel):
+    sku: str
+    quantity: float
+
+class Money(BaseModel):
+    amount: float
+    currency: str
+
+class PaymentCard(BaseModel):
+    method: Literal["card"] = "card"
+    card: "PaymentCardCard"
+
+class PaymentCash(BaseModel):
+    method: Literal["cash"] = "cash"
+
+Payment = Annotated[Union[PaymentCard, PaymentCash], Field(discriminator="method")]
+
+class PaymentCardCard(BaseModel):
+    last4: str
+
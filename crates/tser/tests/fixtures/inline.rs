@@ -0,0 +1,51 @@
+use serde::{Serialize, Deserialize};
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Order {
+    pub id: String,
+    pub shipping_address: OrderShippingAddress,
+    pub lines: Vec<OrderLines>,
+    pub total: Money,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct OrderShippingAddress {
+    pub street: String,
+    pub geo: Option<OrderShippingAddressGeo>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct OrderShippingAddressGeo {
+    pub lat: f64,
+    pub lng: f64,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct OrderLines {
+    pub sku: String,
+    pub quantity: f64,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct Money {
+    pub amount: f64,
+    pub currency: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(tag = "method")]
+pub enum Payment {
+    #[serde(rename = "card")]
+    Card {
+        card: PaymentCardCard,
+    },
+    #[serde(rename = "cash")]
+    Cash,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct PaymentCardCard {
+    pub last4: String,
+}
+
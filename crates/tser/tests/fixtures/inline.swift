@@ -0,0 +1,65 @@
+public struct Order: Codable, Equatable, Hashable {
+    public var id: String
+    public var shippingAddress: OrderShippingAddress
+    public var lines: [OrderLines]
+    public var total: Money
+}
+
+public struct OrderShippingAddress: Codable, Equatable, Hashable {
+    public var street: String
+    public var geo: OrderShippingAddressGeo?
+}
+
+public struct OrderShippingAddressGeo: Codable, Equatable, Hashable {
+    public var lat: Double
+    public var lng: Double
+}
+
+public struct OrderLines: Codable, Equatable, Hashable {
+    public var sku: String
+    public var quantity: Double
+}
+
+public struct Money: Codable, Equatable, Hashable {
+    public var amount: Double
+    public var currency: String
+}
+
+public enum Payment: Codable, Equatable, Hashable {
+    case card(card: PaymentCardCard)
+    case cash
+
+    private enum CodingKeys: String, CodingKey {
+        case method = "method"
+        case card = "card"
+    }
+
+    public init(from decoder: Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        let tag = try container.decode(String.self, forKey: .method)
+        switch tag {
+        case "card":
+            self = try .card(card: container.decode(PaymentCardCard.self, forKey: .card))
+        case "cash":
+            self = .cash
+        default:
+            throw DecodingError.dataCorruptedError(forKey: .method, in: container, debugDescription: "Unknown tag \(tag)")
+        }
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.container(keyedBy: CodingKeys.self)
+        switch self {
+        case let .card(card):
+            try container.encode("card", forKey: .method)
+            try container.encode(card, forKey: .card)
+        case .cash:
+            try container.encode("cash", forKey: .method)
+        }
+    }
+}
+
+public struct PaymentCardCard: Codable, Equatable, Hashable {
+    public var last4: String
+}
+
@@ -0,0 +1,14 @@
+interface Order {
+    id: string;
+    shippingAddress: {
+        street: string;
+        geo: { lat: number; lng: number } | null;
+    };
+    lines: { sku: string; quantity: number }[];
+    /** @name Money */
+    total: { amount: number; currency: string };
+}
+
+type Payment =
+    | { method: 'card'; card: { last4: string } }
+    | { method: 'cash' };
@@ -0,0 +1,44 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.Linq;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace Models;
+
+public record Timestamps
+{
+    [JsonPropertyName("createdAt")]
+    public required string CreatedAt { get; init; }
+
+    [JsonPropertyName("updatedAt")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? UpdatedAt { get; init; }
+}
+
+public record OwnedString
+{
+    [JsonPropertyName("owner")]
+    public required string Owner { get; init; }
+}
+
+/// <summary>A post with its bookkeeping fields mixed in.</summary>
+public record Post
+{
+    [JsonPropertyName("createdAt")]
+    public required string CreatedAt { get; init; }
+
+    [JsonPropertyName("updatedAt")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? UpdatedAt { get; init; }
+
+    [JsonPropertyName("owner")]
+    public required string Owner { get; init; }
+
+    /// <summary>The markdown body.</summary>
+    [JsonPropertyName("body")]
+    public required string Body { get; init; }
+}
+
@@ -0,0 +1,20 @@
+package models
+
+type Timestamps struct {
+    CreatedAt string `json:"createdAt"`
+    UpdatedAt *string `json:"updatedAt,omitempty"`
+}
+
+type OwnedString struct {
+    Owner string `json:"owner"`
+}
+
+// A post with its bookkeeping fields mixed in.
+type Post struct {
+    CreatedAt string `json:"createdAt"`
+    UpdatedAt *string `json:"updatedAt,omitempty"`
+    Owner string `json:"owner"`
+    // The markdown body.
+    Body string `json:"body"`
+}
+
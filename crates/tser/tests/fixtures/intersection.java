@@ -0,0 +1,37 @@
+package models;
+
+import com.fasterxml.jackson.annotation.*;
+import com.fasterxml.jackson.core.*;
+import com.fasterxml.jackson.core.type.TypeReference;
+import com.fasterxml.jackson.databind.*;
+import com.fasterxml.jackson.databind.annotation.*;
+import com.fasterxml.jackson.databind.deser.std.StdDeserializer;
+import com.fasterxml.jackson.databind.node.NullNode;
+import com.fasterxml.jackson.databind.ser.std.StdSerializer;
+import java.io.IOException;
+import java.math.BigInteger;
+import java.util.*;
+
+public final class Models {
+    private Models() {
+    }
+
+    public record Timestamps(
+        @JsonProperty(value = "createdAt", required = true) String createdAt,
+        @JsonProperty("updatedAt") @JsonInclude(JsonInclude.Include.NON_NULL) String updatedAt
+    ) {}
+
+    public record OwnedString(
+        @JsonProperty(value = "owner", required = true) String owner
+    ) {}
+
+    /** A post with its bookkeeping fields mixed in. */
+    public record Post(
+        @JsonProperty(value = "createdAt", required = true) String createdAt,
+        @JsonProperty("updatedAt") @JsonInclude(JsonInclude.Include.NON_NULL) String updatedAt,
+        @JsonProperty(value = "owner", required = true) String owner,
+        /** The markdown body. */
+        @JsonProperty(value = "body", required = true) String body
+    ) {}
+}
+
@@ -0,0 +1,28 @@
+@file:OptIn(kotlinx.serialization.ExperimentalSerializationApi::class)
+
+import kotlinx.serialization.*
+import kotlinx.serialization.descriptors.*
+import kotlinx.serialization.encoding.*
+import kotlinx.serialization.json.*
+
+@Serializable
+data class Timestamps(
+    val createdAt: String,
+    val updatedAt: String? = null,
+)
+
+@Serializable
+data class OwnedString(
+    val owner: String,
+)
+
+/** A post with its bookkeeping fields mixed in. */
+@Serializable
+data class Post(
+    val createdAt: String,
+    val updatedAt: String? = null,
+    val owner: String,
+    /** The markdown body. */
+    val body: String,
+)
+
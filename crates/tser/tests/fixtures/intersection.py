@@ -0,0 +1,23 @@
+# Requires pydantic>=2.12
+import enum
+from typing import Annotated, Dict, List, Literal, NewType, Optional, Tuple, Union
+
+from pydantic import BaseModel, ConfigDict, Field
+
+class Timestamps(BaseModel):
+    model_config = ConfigDict(validate_by_name=True, serialize_by_alias=True)
+    created_at: str = Field(alias="createdAt")
+    updated_at: Optional[str] = Field(default=None, alias="updatedAt", exclude_if=lambda value: value is None)
+
+class OwnedString(BaseModel):
+    owner: str
+
+class Post(BaseModel):
+    """A post with its bookkeeping fields mixed in."""
+    model_config = ConfigDict(validate_by_name=True, serialize_by_alias=True)
+    created_at: str = Field(alias="createdAt")
+    updated_at: Optional[str] = Field(default=None, alias="updatedAt", exclude_if=lambda value: value is None)
+    owner: str
+    body: str
+    """The markdown body."""
+
@@ -0,0 +1,18 @@
+public struct Timestamps: Codable, Equatable, Hashable {
+    public var createdAt: String
+    public var updatedAt: String?
+}
+
+public struct Owned<T: Codable & Equatable & Hashable>: Codable, Equatable, Hashable {
+    public var owner: T
+}
+
+/// A post with its bookkeeping fields mixed in.
+public struct Post: Codable, Equatable, Hashable {
+    public var createdAt: String
+    public var updatedAt: String?
+    public var owner: String
+    /// The markdown body.
+    public var body: String
+}
+
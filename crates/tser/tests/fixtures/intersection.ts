@@ -0,0 +1,14 @@
+interface Timestamps {
+    createdAt: string;
+    updatedAt?: string;
+}
+
+interface Owned<T> {
+    owner: T;
+}
+
+/** A post with its bookkeeping fields mixed in. */
+type Post = Timestamps & Owned<string> & {
+    /** The markdown body. */
+    body: string;
+};
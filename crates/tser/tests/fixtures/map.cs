@@ -0,0 +1,29 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.Linq;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace Models;
+
+public record Inventory
+{
+    [JsonPropertyName("counts")]
+    public required Dictionary<string, double> Counts { get; init; }
+
+    [JsonPropertyName("tags")]
+    public required Dictionary<string, List<string>> Tags { get; init; }
+
+    [JsonPropertyName("owners")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public Dictionary<string, User?>? Owners { get; init; }
+}
+
+public record User
+{
+    [JsonPropertyName("name")]
+    public required string Name { get; init; }
+}
+
@@ -0,0 +1,12 @@
+package models
+
+type Inventory struct {
+    Counts map[string]float64 `json:"counts"`
+    Tags map[string][]string `json:"tags"`
+    Owners *map[string]*User `json:"owners,omitempty"`
+}
+
+type User struct {
+    Name string `json:"name"`
+}
+
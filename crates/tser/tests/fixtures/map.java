@@ -0,0 +1,29 @@
+package models;
+
+import com.fasterxml.jackson.annotation.*;
+import com.fasterxml.jackson.core.*;
+import com.fasterxml.jackson.core.type.TypeReference;
+import com.fasterxml.jackson.databind.*;
+import com.fasterxml.jackson.databind.annotation.*;
+import com.fasterxml.jackson.databind.deser.std.StdDeserializer;
+import com.fasterxml.jackson.databind.node.NullNode;
+import com.fasterxml.jackson.databind.ser.std.StdSerializer;
+import java.io.IOException;
+import java.math.BigInteger;
+import java.util.*;
+
+public final class Models {
+    private Models() {
+    }
+
+    public record Inventory(
+        @JsonProperty(value = "counts", required = true) Map<String, Double> counts,
+        @JsonProperty(value = "tags", required = true) Map<String, List<String>> tags,
+        @JsonProperty("owners") @JsonInclude(JsonInclude.Include.NON_NULL) Map<String, User> owners
+    ) {}
+
+    public record User(
+        @JsonProperty(value = "name", required = true) String name
+    ) {}
+}
+
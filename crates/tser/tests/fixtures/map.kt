@@ -0,0 +1,19 @@
+@file:OptIn(kotlinx.serialization.ExperimentalSerializationApi::class)
+
+import kotlinx.serialization.*
+import kotlinx.serialization.descriptors.*
+import kotlinx.serialization.encoding.*
+import kotlinx.serialization.json.*
+
+@Serializable
+data class Inventory(
+    val counts: Map<String, Double>,
+    val tags: Map<String, List<String>>,
+    val owners: Map<String, User?>? = null,
+)
+
+@Serializable
+data class User(
+    val name: String,
+)
+
@@ -0,0 +1,14 @@
+# Requires pydantic>=2.12
+import enum
+from typing import Annotated, Dict, List, Literal, NewType, Optional, Tuple, Union
+
+from pydantic import BaseModel, ConfigDict, Field
+
+class Inventory(BaseModel):
+    counts: Dict[str, float]
+    tags: Dict[str, List[str]]
+    owners: Optional[Dict[str, Optional["User"]]] = Field(default=None, exclude_if=lambda value: value is None)
+
+class User(BaseModel):
+    name: str
+
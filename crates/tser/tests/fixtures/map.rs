@@ -0,0 +1,15 @@
+use serde::{Serialize, Deserialize};
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct Inventory {
+    pub counts: std::collections::HashMap<String, f64>,
+    pub tags: std::collections::HashMap<String, Vec<String>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub owners: Option<std::collections::HashMap<String, Option<User>>>,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct User {
+    pub name: String,
+}
+
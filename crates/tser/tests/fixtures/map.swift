@@ -0,0 +1,10 @@
+public struct Inventory: Codable, Equatable, Hashable {
+    public var counts: [String: Double]
+    public var tags: [String: [String]]
+    public var owners: [String: User?]?
+}
+
+public struct User: Codable, Equatable, Hashable {
+    public var name: String
+}
+
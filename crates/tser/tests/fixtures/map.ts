@@ -0,0 +1,9 @@
+interface Inventory {
+    counts: Record<string, number>;
+    tags: { [name: string]: string[] };
+    owners?: Record<string, User | null>;
+}
+
+interface User {
+    name: string;
+}
@@ -0,0 +1,38 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.Linq;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace Models;
+
+public record Account
+{
+    [JsonPropertyName("accountId")]
+    public required string AccountId { get; init; }
+
+    [JsonPropertyName("userID")]
+    public required string UserId { get; init; }
+
+    [JsonPropertyName("type")]
+    public required string Type { get; init; }
+
+    [JsonPropertyName("self")]
+    public required bool Self { get; init; }
+
+    [JsonPropertyName("oauth2Token")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? Oauth2Token { get; init; }
+
+    [JsonPropertyName("display_name")]
+    public required string DisplayName { get; init; }
+}
+
+public enum Role
+{
+    Admin = 1,
+    ReadOnly = 2,
+}
+
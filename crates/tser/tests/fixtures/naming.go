@@ -0,0 +1,18 @@
+package models
+
+type Account struct {
+    AccountId string `json:"accountId"`
+    UserId string `json:"userID"`
+    Type string `json:"type"`
+    Self bool `json:"self"`
+    Oauth2Token *string `json:"oauth2Token,omitempty"`
+    DisplayName string `json:"display_name"`
+}
+
+type Role int64
+
+const (
+    RoleAdmin Role = 1
+    RoleReadOnly Role = 2
+)
+
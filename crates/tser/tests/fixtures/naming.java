@@ -0,0 +1,54 @@
+package models;
+
+import com.fasterxml.jackson.annotation.*;
+import com.fasterxml.jackson.core.*;
+import com.fasterxml.jackson.core.type.TypeReference;
+import com.fasterxml.jackson.databind.*;
+import com.fasterxml.jackson.databind.annotation.*;
+import com.fasterxml.jackson.databind.deser.std.StdDeserializer;
+import com.fasterxml.jackson.databind.node.NullNode;
+import com.fasterxml.jackson.databind.ser.std.StdSerializer;
+import java.io.IOException;
+import java.math.BigInteger;
+import java.util.*;
+
+public final class Models {
+    private Models() {
+    }
+
+    public record Account(
+        @JsonProperty(value = "accountId", required = true) String accountId,
+        @JsonProperty(value = "userID", required = true) String userId,
+        @JsonProperty(value = "type", required = true) String type,
+        @JsonProperty(value = "self", required = true) boolean self,
+        @JsonProperty("oauth2Token") @JsonInclude(JsonInclude.Include.NON_NULL) String oauth2Token,
+        @JsonProperty(value = "display_name", required = true) String displayName
+    ) {}
+
+    public enum Role {
+        ADMIN(1L),
+        READ_ONLY(2L);
+
+        private final long value;
+
+        Role(long value) {
+            this.value = value;
+        }
+
+        @JsonValue
+        public long value() {
+            return value;
+        }
+
+        @JsonCreator
+        public static Role of(long value) {
+            for (Role constant : values()) {
+                if (constant.value == value) {
+                    return constant;
+                }
+            }
+            throw new IllegalArgumentException("Unknown Role " + value);
+        }
+    }
+}
+
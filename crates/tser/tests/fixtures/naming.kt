@@ -0,0 +1,35 @@
+@file:OptIn(kotlinx.serialization.ExperimentalSerializationApi::class)
+
+import kotlinx.serialization.*
+import kotlinx.serialization.descriptors.*
+import kotlinx.serialization.encoding.*
+import kotlinx.serialization.json.*
+
+@Serializable
+data class Account(
+    val accountId: String,
+    @SerialName("userID") val userId: String,
+    val type: String,
+    val self: Boolean,
+    val oauth2Token: String? = null,
+    @SerialName("display_name") val displayName: String,
+)
+
+@Serializable(with = Role.Serializer::class)
+enum class Role(val value: Long) {
+    Admin(1),
+    ReadOnly(2);
+
+    object Serializer : KSerializer<Role> {
+        override val descriptor = PrimitiveSerialDescriptor("Role", PrimitiveKind.LONG)
+
+        override fun serialize(encoder: Encoder, value: Role) = encoder.encodeLong(value.value)
+
+        override fun deserialize(decoder: Decoder): Role {
+            val value = decoder.decodeLong()
+            return Role.entries.firstOrNull { it.value == value }
+                ?: throw SerializationException("Unknown Role $value")
+        }
+    }
+}
+
@@ -0,0 +1,114 @@
+package models;
+
+import com.fasterxml.jackson.annotation.*;
+import com.fasterxml.jackson.core.*;
+import com.fasterxml.jackson.core.type.TypeReference;
+import com.fasterxml.jackson.databind.*;
+import com.fasterxml.jackson.databind.annotation.*;
+import com.fasterxml.jackson.databind.deser.std.StdDeserializer;
+import com.fasterxml.jackson.databind.node.NullNode;
+import com.fasterxml.jackson.databind.ser.std.StdSerializer;
+import java.io.IOException;
+import java.math.BigInteger;
+import java.util.*;
+
+public final class Models {
+    private Models() {
+    }
+
+    public static class Account {
+        @JsonProperty(value = "accountId", required = true)
+        private String accountId;
+
+        @JsonProperty(value = "userID", required = true)
+        private String userId;
+
+        @JsonProperty(value = "type", required = true)
+        private String type;
+
+        @JsonProperty(value = "self", required = true)
+        private boolean self;
+
+        @JsonProperty("oauth2Token")
+        @JsonInclude(JsonInclude.Include.NON_NULL)
+        private String oauth2Token;
+
+        @JsonProperty(value = "display_name", required = true)
+        private String displayName;
+
+        public String getAccountId() {
+            return accountId;
+        }
+
+        public void setAccountId(String accountId) {
+            this.accountId = accountId;
+        }
+
+        public String getUserId() {
+            return userId;
+        }
+
+        public void setUserId(String userId) {
+            this.userId = userId;
+        }
+
+        public String getType() {
+            return type;
+        }
+
+        public void setType(String type) {
+            this.type = type;
+        }
+
+        public boolean getSelf() {
+            return self;
+        }
+
+        public void setSelf(boolean self) {
+            this.self = self;
+        }
+
+        public String getOauth2Token() {
+            return oauth2Token;
+        }
+
+        public void setOauth2Token(String oauth2Token) {
+            this.oauth2Token = oauth2Token;
+        }
+
+        public String getDisplayName() {
+            return displayName;
+        }
+
+        public void setDisplayName(String displayName) {
+            this.displayName = displayName;
+        }
+    }
+
+    public enum Role {
+        ADMIN(1L),
+        READ_ONLY(2L);
+
+        private final long value;
+
+        Role(long value) {
+            this.value = value;
+        }
+
+        @JsonValue
+        public long value() {
+            return value;
+        }
+
+        @JsonCreator
+        public static Role of(long value) {
+            for (Role constant : values()) {
+                if (constant.value == value) {
+                    return constant;
+                }
+            }
+            throw new IllegalArgumentException("Unknown Role " + value);
+        }
+    }
+}
+
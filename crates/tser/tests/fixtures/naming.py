@@ -0,0 +1,19 @@
+# Requires pydantic>=2.12
+import enum
+from typing import Annotated, Dict, List, Literal, NewType, Optional, Tuple, Union
+
+from pydantic import BaseModel, ConfigDict, Field
+
+class Account(BaseModel):
+    model_config = ConfigDict(validate_by_name=True, serialize_by_alias=True)
+    account_id: str = Field(alias="accountId")
+    user_id: str = Field(alias="userID")
+    type: str
+    self: bool
+    oauth2_token: Optional[str] = Field(default=None, alias="oauth2Token", exclude_if=lambda value: value is None)
+    display_name: str
+
+class Role(enum.IntEnum):
+    ADMIN = 1
+    READ_ONLY = 2
+
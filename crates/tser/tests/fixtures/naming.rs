@@ -0,0 +1,23 @@
+use serde::{Serialize, Deserialize};
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct Account {
+    pub account_id: String,
+    #[serde(rename = "userID")]
+    pub user_id: String,
+    pub r#type: String,
+    pub self_: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oauth2_token: Option<String>,
+    #[serde(rename = "display_name")]
+    pub display_name: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[repr(i64)]
+pub enum Role {
+    #[serde(rename = "admin")] Admin = 1,
+    #[serde(rename = "readOnly")] ReadOnly = 2,
+}
+
@@ -0,0 +1,23 @@
+public struct Account: Codable, Equatable, Hashable {
+    public var accountId: String
+    public var userId: String
+    public var type: String
+    public var `self`: Bool
+    public var oauth2Token: String?
+    public var displayName: String
+
+    private enum CodingKeys: String, CodingKey {
+        case accountId = "accountId"
+        case userId = "userID"
+        case type = "type"
+        case `self` = "self"
+        case oauth2Token = "oauth2Token"
+        case displayName = "display_name"
+    }
+}
+
+public enum Role: Int64, Codable, Equatable, Hashable {
+    case admin = 1
+    case readOnly = 2
+}
+
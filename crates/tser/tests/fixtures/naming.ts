@@ -0,0 +1,13 @@
+interface Account {
+    accountId: string;
+    userID: string;
+    type: string;
+    self: boolean;
+    oauth2Token?: string;
+    display_name: string;
+}
+
+enum Role {
+    admin = 1,
+    readOnly = 2,
+}
@@ -0,0 +1,35 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.Linq;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace Models;
+
+public record Stats
+{
+    [JsonPropertyName("id")]
+    public required long Id { get; init; }
+
+    [JsonPropertyName("count")]
+    public required long Count { get; init; }
+
+    [JsonPropertyName("level")]
+    public required byte Level { get; init; }
+
+    [JsonPropertyName("ratio")]
+    public required float Ratio { get; init; }
+
+    /// <summary>Milliseconds since the epoch.</summary>
+    [JsonPropertyName("timestamps")]
+    public required List<ulong> Timestamps { get; init; }
+
+    [JsonPropertyName("port")]
+    public required int Port { get; init; }
+
+    [JsonPropertyName("average")]
+    public required double Average { get; init; }
+}
+
@@ -0,0 +1,15 @@
+package models
+
+type Port = int32
+
+type Stats struct {
+    Id int64 `json:"id"`
+    Count int64 `json:"count"`
+    Level uint8 `json:"level"`
+    Ratio float32 `json:"ratio"`
+    // Milliseconds since the epoch.
+    Timestamps []uint64 `json:"timestamps"`
+    Port Port `json:"port"`
+    Average float64 `json:"average"`
+}
+
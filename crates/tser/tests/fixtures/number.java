@@ -0,0 +1,30 @@
+package models;
+
+import com.fasterxml.jackson.annotation.*;
+import com.fasterxml.jackson.core.*;
+import com.fasterxml.jackson.core.type.TypeReference;
+import com.fasterxml.jackson.databind.*;
+import com.fasterxml.jackson.databind.annotation.*;
+import com.fasterxml.jackson.databind.deser.std.StdDeserializer;
+import com.fasterxml.jackson.databind.node.NullNode;
+import com.fasterxml.jackson.databind.ser.std.StdSerializer;
+import java.io.IOException;
+import java.math.BigInteger;
+import java.util.*;
+
+public final class Models {
+    private Models() {
+    }
+
+    public record Stats(
+        @JsonProperty(value = "id", required = true) long id,
+        @JsonProperty(value = "count", required = true) long count,
+        @JsonProperty(value = "level", required = true) short level,
+        @JsonProperty(value = "ratio", required = true) float ratio,
+        /** Milliseconds since the epoch. */
+        @JsonProperty(value = "timestamps", required = true) List<BigInteger> timestamps,
+        @JsonProperty(value = "port", required = true) int port,
+        @JsonProperty(value = "average", required = true) double average
+    ) {}
+}
+
@@ -0,0 +1,21 @@
+@file:OptIn(kotlinx.serialization.ExperimentalSerializationApi::class)
+
+import kotlinx.serialization.*
+import kotlinx.serialization.descriptors.*
+import kotlinx.serialization.encoding.*
+import kotlinx.serialization.json.*
+
+typealias Port = Int
+
+@Serializable
+data class Stats(
+    val id: Long,
+    val count: Long,
+    val level: UByte,
+    val ratio: Float,
+    /** Milliseconds since the epoch. */
+    val timestamps: List<ULong>,
+    val port: Port,
+    val average: Double,
+)
+
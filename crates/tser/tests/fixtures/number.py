@@ -0,0 +1,18 @@
+# Requires pydantic>=2.12
+import enum
+from typing import Annotated, Dict, List, Literal, NewType, Optional, Tuple, Union
+
+from pydantic import BaseModel, ConfigDict, Field
+
+Port = int
+
+class Stats(BaseModel):
+    id: int
+    count: int
+    level: int
+    ratio: float
+    timestamps: List[int]
+    """Milliseconds since the epoch."""
+    port: Port
+    average: float
+
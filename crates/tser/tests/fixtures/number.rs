@@ -0,0 +1,16 @@
+use serde::{Serialize, Deserialize};
+
+pub type Port = i32;
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct Stats {
+    pub id: i64,
+    pub count: i64,
+    pub level: u8,
+    pub ratio: f32,
+    /// Milliseconds since the epoch.
+    pub timestamps: Vec<u64>,
+    pub port: Port,
+    pub average: f64,
+}
+
@@ -0,0 +1,13 @@
+public typealias Port = Int32
+
+public struct Stats: Codable, Equatable, Hashable {
+    public var id: Int64
+    public var count: Int64
+    public var level: UInt8
+    public var ratio: Float
+    /// Milliseconds since the epoch.
+    public var timestamps: [UInt64]
+    public var port: Port
+    public var average: Double
+}
+
@@ -0,0 +1,20 @@
+type i64 = number & { readonly __brand: "i64" };
+type u8 = number;
+
+/** @format int32 */
+type Port = number;
+
+interface Stats {
+    id: bigint;
+    count: i64;
+    level: u8;
+    /** @format float */
+    ratio: number;
+    /**
+     * Milliseconds since the epoch.
+     * @format uint64
+     */
+    timestamps: number[];
+    port: Port;
+    average: number;
+}
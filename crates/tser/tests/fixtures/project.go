@@ -0,0 +1,21 @@
+package models
+
+type User struct {
+    Id string `json:"id"`
+    Name string `json:"name"`
+}
+
+type Role string
+
+const (
+    RoleOwner Role = "owner"
+    RoleModerator Role = "moderator"
+)
+
+// A user who can manage others
+type Admin struct {
+    Id string `json:"id"`
+    Name string `json:"name"`
+    Role Role `json:"role"`
+}
+
@@ -0,0 +1,27 @@
+@file:OptIn(kotlinx.serialization.ExperimentalSerializationApi::class)
+
+import kotlinx.serialization.*
+import kotlinx.serialization.descriptors.*
+import kotlinx.serialization.encoding.*
+import kotlinx.serialization.json.*
+
+@Serializable
+data class User(
+    val id: String,
+    val name: String,
+)
+
+@Serializable
+enum class Role {
+    @SerialName("owner") Owner,
+    @SerialName("moderator") Moderator,
+}
+
+/** A user who can manage others */
+@Serializable
+data class Admin(
+    val id: String,
+    val name: String,
+    val role: Role,
+)
+
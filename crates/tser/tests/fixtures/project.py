@@ -0,0 +1,20 @@
+# Requires pydantic>=2.12
+import enum
+from typing import Annotated, Dict, List, Literal, NewType, Optional, Tuple, Union
+
+from pydantic import BaseModel, ConfigDict, Field
+
+class User(BaseModel):
+    id: str
+    name: str
+
+class Role(enum.Enum):
+    OWNER = "owner"
+    MODERATOR = "moderator"
+
+class Admin(BaseModel):
+    """A user who can manage others"""
+    id: str
+    name: str
+    role: Role
+
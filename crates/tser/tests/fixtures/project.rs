@@ -0,0 +1,22 @@
+use serde::{Serialize, Deserialize};
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct User {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub enum Role {
+    #[serde(rename = "owner")] Owner,
+    #[serde(rename = "moderator")] Moderator,
+}
+
+/// A user who can manage others
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct Admin {
+    pub id: String,
+    pub name: String,
+    pub role: Role,
+}
+
@@ -0,0 +1,17 @@
+public struct User: Codable, Equatable, Hashable {
+    public var id: String
+    public var name: String
+}
+
+public enum Role: String, Codable, Equatable, Hashable {
+    case Owner = "owner"
+    case Moderator = "moderator"
+}
+
+/// A user who can manage others
+public struct Admin: Codable, Equatable, Hashable {
+    public var id: String
+    public var name: String
+    public var role: Role
+}
+
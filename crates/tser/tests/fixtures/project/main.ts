@@ -0,0 +1,7 @@
+import { User } from './models/user';
+import { Role } from './models';
+
+/** A user who can manage others */
+export interface Admin extends User {
+    role: Role;
+}
@@ -0,0 +1,4 @@
+export enum Role {
+    Owner = 'owner',
+    Moderator = 'moderator',
+}
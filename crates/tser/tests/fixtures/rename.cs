@@ -0,0 +1,165 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.Linq;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace Models;
+
+public record Headers
+{
+    [JsonPropertyName("content-type")]
+    public required string ContentType { get; init; }
+
+    [JsonPropertyName("x-request-id")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? XRequestId { get; init; }
+
+    [JsonPropertyName("2fa_enabled")]
+    public required bool _2faEnabled { get; init; }
+
+    [JsonPropertyName("accept")]
+    public required string Accept { get; init; }
+}
+
+[JsonPolymorphic(TypeDiscriminatorPropertyName = "kind")]
+[JsonDerivedType(typeof(EventClick), "click")]
+[JsonDerivedType(typeof(EventClose), "close")]
+public abstract record Event;
+
+public record EventClick : Event
+{
+    [JsonPropertyName("client-x")]
+    public required double ClientX { get; init; }
+}
+
+public record EventClose : Event;
+
+[JsonPolymorphic(TypeDiscriminatorPropertyName = "the-kind")]
+[JsonDerivedType(typeof(ShapeCircle), "circle")]
+[JsonDerivedType(typeof(ShapeSquare), "square")]
+public abstract record Shape;
+
+public record ShapeCircle : Shape
+{
+    [JsonPropertyName("radius")]
+    public required double Radius { get; init; }
+}
+
+public record ShapeSquare : Shape
+{
+    [JsonPropertyName("side-length")]
+    public required double SideLength { get; init; }
+}
+
+[JsonConverter(typeof(Payload.Converter))]
+public abstract record Payload
+{
+    public class Converter : JsonConverter<Payload>
+    {
+        public override Payload Read(ref Utf8JsonReader reader, Type typeToConvert, JsonSerializerOptions options)
+        {
+            var element = JsonElement.ParseValue(ref reader);
+            var tag = element.GetProperty("the-kind").GetString();
+            element.TryGetProperty("my-data", out var data);
+            return tag switch
+            {
+                "text" => new PayloadText(data.Deserialize<string>(options)!),
+                "count" => new PayloadCount(data.ValueKind == JsonValueKind.Undefined ? null : data.Deserialize<double?>(options)),
+                _ => throw new JsonException($"Unknown tag {tag}"),
+            };
+        }
+
+        public override void Write(Utf8JsonWriter writer, Payload value, JsonSerializerOptions options)
+        {
+            writer.WriteStartObject();
+            switch (value)
+            {
+                case PayloadText variant:
+                    writer.WriteString("the-kind", "text");
+                    writer.WritePropertyName("my-data");
+                    JsonSerializer.Serialize(writer, variant.Value, options);
+                    break;
+                case PayloadCount variant:
+                    writer.WriteString("the-kind", "count");
+                    if (variant.Value is not null)
+                    {
+                        writer.WritePropertyName("my-data");
+                        JsonSerializer.Serialize(writer, variant.Value, options);
+                    }
+                    break;
+            }
+            writer.WriteEndObject();
+        }
+    }
+}
+
+public record PayloadText(string Value) : Payload;
+public record PayloadCount(double? Value) : Payload;
+
+[JsonPolymorphic(TypeDiscriminatorPropertyName = "method")]
+[JsonDerivedType(typeof(PaymentBankTransfer), "bank-transfer")]
+[JsonDerivedType(typeof(PaymentCard), "card")]
+public abstract record Payment;
+
+public record PaymentBankTransfer : Payment
+{
+    [JsonPropertyName("iban")]
+    public required string Iban { get; init; }
+
+    [JsonPropertyName("x")]
+    public required double X { get; init; }
+}
+
+public record PaymentCard : Payment
+{
+    [JsonPropertyName("last4")]
+    public required string Last4 { get; init; }
+}
+
+[JsonConverter(typeof(Body.Converter))]
+public abstract record Body
+{
+    public class Converter : JsonConverter<Body>
+    {
+        public override Body Read(ref Utf8JsonReader reader, Type typeToConvert, JsonSerializerOptions options)
+        {
+            var element = JsonElement.ParseValue(ref reader);
+            var properties = element.EnumerateObject().ToList();
+            if (properties.Count != 1)
+            {
+                throw new JsonException("Expected exactly one key");
+            }
+            var (tag, data) = (properties[0].Name, properties[0].Value);
+            return tag switch
+            {
+                "content-type" => new BodyContentType(data.Deserialize<string>(options)!),
+                "x-raw" => new BodyXRaw(data.Deserialize<List<double>>(options)!),
+                _ => throw new JsonException($"Unknown tag {tag}"),
+            };
+        }
+
+        public override void Write(Utf8JsonWriter writer, Body value, JsonSerializerOptions options)
+        {
+            writer.WriteStartObject();
+            switch (value)
+            {
+                case BodyContentType variant:
+                    writer.WritePropertyName("content-type");
+                    JsonSerializer.Serialize(writer, variant.Value, options);
+                    break;
+                case BodyXRaw variant:
+                    writer.WritePropertyName("x-raw");
+                    JsonSerializer.Serialize(writer, variant.Value, options);
+                    break;
+            }
+            writer.WriteEndObject();
+        }
+    }
+}
+
+public record BodyContentType(string Value) : Body;
+public record BodyXRaw(List<double> Value) : Body;
+
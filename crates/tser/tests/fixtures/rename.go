@@ -0,0 +1,314 @@
+package models
+
+import (
+    "encoding/json"
+    "fmt"
+)
+
+type Headers struct {
+    ContentType string `json:"content-type"`
+    XRequestId *string `json:"x-request-id,omitempty"`
+    X2faEnabled bool `json:"2fa_enabled"`
+    Accept string `json:"accept"`
+}
+
+type Event struct {
+    Value isEvent
+}
+
+type isEvent interface {
+    isEvent()
+}
+
+type EventClick struct {
+    ClientX float64 `json:"client-x"`
+}
+
+func (EventClick) isEvent() {}
+
+type EventClose struct{}
+
+func (EventClose) isEvent() {}
+
+func (u Event) MarshalJSON() ([]byte, error) {
+    switch value := u.Value.(type) {
+    case EventClick:
+        return json.Marshal(struct {
+            Tag string `json:"kind"`
+            EventClick
+        }{"click", value})
+    case EventClose:
+        return json.Marshal(struct {
+            Tag string `json:"kind"`
+            EventClose
+        }{"close", value})
+    default:
+        return nil, fmt.Errorf("unexpected Event variant %T", u.Value)
+    }
+}
+
+func (u *Event) UnmarshalJSON(data []byte) error {
+    var tagged struct {
+        Tag string `json:"kind"`
+    }
+    if err := json.Unmarshal(data, &tagged); err != nil {
+        return err
+    }
+    var err error
+    switch tagged.Tag {
+    case "click":
+        var value EventClick
+        err = json.Unmarshal(data, &value)
+        u.Value = value
+    case "close":
+        var value EventClose
+        err = json.Unmarshal(data, &value)
+        u.Value = value
+    default:
+        return fmt.Errorf("unknown Event tag %q", tagged.Tag)
+    }
+    return err
+}
+
+type Shape struct {
+    Value isShape
+}
+
+type isShape interface {
+    isShape()
+}
+
+type ShapeCircle struct {
+    Radius float64 `json:"radius"`
+}
+
+func (ShapeCircle) isShape() {}
+
+type ShapeSquare struct {
+    SideLength float64 `json:"side-length"`
+}
+
+func (ShapeSquare) isShape() {}
+
+func (u Shape) MarshalJSON() ([]byte, error) {
+    switch value := u.Value.(type) {
+    case ShapeCircle:
+        return json.Marshal(struct {
+            Tag string `json:"the-kind"`
+            ShapeCircle
+        }{"circle", value})
+    case ShapeSquare:
+        return json.Marshal(struct {
+            Tag string `json:"the-kind"`
+            ShapeSquare
+        }{"square", value})
+    default:
+        return nil, fmt.Errorf("unexpected Shape variant %T", u.Value)
+    }
+}
+
+func (u *Shape) UnmarshalJSON(data []byte) error {
+    var tagged struct {
+        Tag string `json:"the-kind"`
+    }
+    if err := json.Unmarshal(data, &tagged); err != nil {
+        return err
+    }
+    var err error
+    switch tagged.Tag {
+    case "circle":
+        var value ShapeCircle
+        err = json.Unmarshal(data, &value)
+        u.Value = value
+    case "square":
+        var value ShapeSquare
+        err = json.Unmarshal(data, &value)
+        u.Value = value
+    default:
+        return fmt.Errorf("unknown Shape tag %q", tagged.Tag)
+    }
+    return err
+}
+
+type Payload struct {
+    Value isPayload
+}
+
+type isPayload interface {
+    isPayload()
+}
+
+type PayloadText struct {
+    Value string
+}
+
+func (PayloadText) isPayload() {}
+
+type PayloadCount struct {
+    Value *float64
+}
+
+func (PayloadCount) isPayload() {}
+
+func (u Payload) MarshalJSON() ([]byte, error) {
+    switch value := u.Value.(type) {
+    case PayloadText:
+        return json.Marshal(struct {
+            Tag string `json:"the-kind"`
+            Data string `json:"my-data"`
+        }{"text", value.Value})
+    case PayloadCount:
+        return json.Marshal(struct {
+            Tag string `json:"the-kind"`
+            Data *float64 `json:"my-data,omitempty"`
+        }{"count", value.Value})
+    default:
+        return nil, fmt.Errorf("unexpected Payload variant %T", u.Value)
+    }
+}
+
+func (u *Payload) UnmarshalJSON(data []byte) error {
+    var tagged struct {
+        Tag string `json:"the-kind"`
+        Data json.RawMessage `json:"my-data"`
+    }
+    if err := json.Unmarshal(data, &tagged); err != nil {
+        return err
+    }
+    var err error
+    switch tagged.Tag {
+    case "text":
+        var value PayloadText
+        err = json.Unmarshal(tagged.Data, &value.Value)
+        u.Value = value
+    case "count":
+        var value PayloadCount
+        if tagged.Data != nil {
+            err = json.Unmarshal(tagged.Data, &value.Value)
+        }
+        u.Value = value
+    default:
+        return fmt.Errorf("unknown Payload tag %q", tagged.Tag)
+    }
+    return err
+}
+
+type Payment struct {
+    Value isPayment
+}
+
+type isPayment interface {
+    isPayment()
+}
+
+type PaymentBankTransfer struct {
+    Iban string `json:"iban"`
+    X float64 `json:"x"`
+}
+
+func (PaymentBankTransfer) isPayment() {}
+
+type PaymentCard struct {
+    Last4 string `json:"last4"`
+}
+
+func (PaymentCard) isPayment() {}
+
+func (u Payment) MarshalJSON() ([]byte, error) {
+    switch value := u.Value.(type) {
+    case PaymentBankTransfer:
+        return json.Marshal(struct {
+            Tag string `json:"method"`
+            PaymentBankTransfer
+        }{"bank-transfer", value})
+    case PaymentCard:
+        return json.Marshal(struct {
+            Tag string `json:"method"`
+            PaymentCard
+        }{"card", value})
+    default:
+        return nil, fmt.Errorf("unexpected Payment variant %T", u.Value)
+    }
+}
+
+func (u *Payment) UnmarshalJSON(data []byte) error {
+    var tagged struct {
+        Tag string `json:"method"`
+    }
+    if err := json.Unmarshal(data, &tagged); err != nil {
+        return err
+    }
+    var err error
+    switch tagged.Tag {
+    case "bank-transfer":
+        var value PaymentBankTransfer
+        err = json.Unmarshal(data, &value)
+        u.Value = value
+    case "card":
+        var value PaymentCard
+        err = json.Unmarshal(data, &value)
+        u.Value = value
+    default:
+        return fmt.Errorf("unknown Payment tag %q", tagged.Tag)
+    }
+    return err
+}
+
+type Body struct {
+    Value isBody
+}
+
+type isBody interface {
+    isBody()
+}
+
+type BodyContentType struct {
+    Value string
+}
+
+func (BodyContentType) isBody() {}
+
+type BodyXRaw struct {
+    Value []float64
+}
+
+func (BodyXRaw) isBody() {}
+
+func (u Body) MarshalJSON() ([]byte, error) {
+    switch value := u.Value.(type) {
+    case BodyContentType:
+        return json.Marshal(map[string]any{"content-type": value.Value})
+    case BodyXRaw:
+        return json.Marshal(map[string]any{"x-raw": value.Value})
+    default:
+        return nil, fmt.Errorf("unexpected Body variant %T", u.Value)
+    }
+}
+
+func (u *Body) UnmarshalJSON(data []byte) error {
+    var tagged map[string]json.RawMessage
+    if err := json.Unmarshal(data, &tagged); err != nil {
+        return err
+    }
+    if len(tagged) != 1 {
+        return fmt.Errorf("expected exactly one key in Body, got %d", len(tagged))
+    }
+    for tag, content := range tagged {
+        var err error
+        switch tag {
+        case "content-type":
+            var value BodyContentType
+            err = json.Unmarshal(content, &value.Value)
+            u.Value = value
+        case "x-raw":
+            var value BodyXRaw
+            err = json.Unmarshal(content, &value.Value)
+            u.Value = value
+        default:
+            return fmt.Errorf("unknown Body tag %q", tag)
+        }
+        return err
+    }
+    return nil
+}
+
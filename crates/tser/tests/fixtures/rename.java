@@ -0,0 +1,165 @@
+package models;
+
+import com.fasterxml.jackson.annotation.*;
+import com.fasterxml.jackson.core.*;
+import com.fasterxml.jackson.core.type.TypeReference;
+import com.fasterxml.jackson.databind.*;
+import com.fasterxml.jackson.databind.annotation.*;
+import com.fasterxml.jackson.databind.deser.std.StdDeserializer;
+import com.fasterxml.jackson.databind.node.NullNode;
+import com.fasterxml.jackson.databind.ser.std.StdSerializer;
+import java.io.IOException;
+import java.math.BigInteger;
+import java.util.*;
+
+public final class Models {
+    private Models() {
+    }
+
+    private static <T> T read(DeserializationContext context, JsonNode node, TypeReference<T> type) throws IOException {
+        JavaType javaType = context.getTypeFactory().constructType(type);
+        return context.readTreeAsValue(node == null ? NullNode.getInstance() : node, javaType);
+    }
+
+    public record Headers(
+        @JsonProperty(value = "content-type", required = true) String contentType,
+        @JsonProperty("x-request-id") @JsonInclude(JsonInclude.Include.NON_NULL) String xRequestId,
+        @JsonProperty(value = "2fa_enabled", required = true) boolean _2faEnabled,
+        @JsonProperty(value = "accept", required = true) String accept
+    ) {}
+
+    @JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = "kind")
+    @JsonSubTypes({
+        @JsonSubTypes.Type(value = EventClick.class, name = "click"),
+        @JsonSubTypes.Type(value = EventClose.class, name = "close"),
+    })
+    public sealed interface Event permits EventClick, EventClose {}
+
+    public record EventClick(
+        @JsonProperty(value = "client-x", required = true) double clientX
+    ) implements Event {}
+
+    public record EventClose() implements Event {}
+
+    @JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = "the-kind")
+    @JsonSubTypes({
+        @JsonSubTypes.Type(value = ShapeCircle.class, name = "circle"),
+        @JsonSubTypes.Type(value = ShapeSquare.class, name = "square"),
+    })
+    public sealed interface Shape permits ShapeCircle, ShapeSquare {}
+
+    public record ShapeCircle(
+        @JsonProperty(value = "radius", required = true) double radius
+    ) implements Shape {}
+
+    public record ShapeSquare(
+        @JsonProperty(value = "side-length", required = true) double sideLength
+    ) implements Shape {}
+
+    @JsonSerialize(using = Payload.Serializer.class)
+    @JsonDeserialize(using = Payload.Deserializer.class)
+    public sealed interface Payload permits PayloadText, PayloadCount {
+        final class Serializer extends StdSerializer<Payload> {
+            public Serializer() {
+                super(Payload.class);
+            }
+
+            @Override
+            public void serialize(Payload value, JsonGenerator generator, SerializerProvider provider) throws IOException {
+                generator.writeStartObject();
+                if (value instanceof PayloadText variant) {
+                    generator.writeStringField("the-kind", "text");
+                    provider.defaultSerializeField("my-data", variant.value(), generator);
+                } else if (value instanceof PayloadCount variant) {
+                    generator.writeStringField("the-kind", "count");
+                    if (variant.value() != null) {
+                        provider.defaultSerializeField("my-data", variant.value(), generator);
+                    }
+                }
+                generator.writeEndObject();
+            }
+        }
+
+        final class Deserializer extends StdDeserializer<Payload> {
+            public Deserializer() {
+                super(Payload.class);
+            }
+
+            @Override
+            public Payload deserialize(JsonParser parser, DeserializationContext context) throws IOException {
+                JsonNode node = parser.readValueAsTree();
+                String tag = node.path("the-kind").asText();
+                JsonNode data = node.get("my-data");
+                return switch (tag) {
+                    case "text" -> new PayloadText(read(context, data, new TypeReference<String>() {}));
+                    case "count" -> new PayloadCount(read(context, data, new TypeReference<Double>() {}));
+                    default -> throw JsonMappingException.from(parser, "Unknown tag " + tag);
+                };
+            }
+        }
+    }
+
+    public record PayloadText(String value) implements Payload {}
+    public record PayloadCount(Double value) implements Payload {}
+
+    @JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = "method")
+    @JsonSubTypes({
+        @JsonSubTypes.Type(value = PaymentBankTransfer.class, name = "bank-transfer"),
+        @JsonSubTypes.Type(value = PaymentCard.class, name = "card"),
+    })
+    public sealed interface Payment permits PaymentBankTransfer, PaymentCard {}
+
+    public record PaymentBankTransfer(
+        @JsonProperty(value = "iban", required = true) String iban,
+        @JsonProperty(value = "x", required = true) double x
+    ) implements Payment {}
+
+    public record PaymentCard(
+        @JsonProperty(value = "last4", required = true) String last4
+    ) implements Payment {}
+
+    @JsonSerialize(using = Body.Serializer.class)
+    @JsonDeserialize(using = Body.Deserializer.class)
+    public sealed interface Body permits BodyContentType, BodyXRaw {
+        final class Serializer extends StdSerializer<Body> {
+            public Serializer() {
+                super(Body.class);
+            }
+
+            @Override
+            public void serialize(Body value, JsonGenerator generator, SerializerProvider provider) throws IOException {
+                generator.writeStartObject();
+                if (value instanceof BodyContentType variant) {
+                    provider.defaultSerializeField("content-type", variant.value(), generator);
+                } else if (value instanceof BodyXRaw variant) {
+                    provider.defaultSerializeField("x-raw", variant.value(), generator);
+                }
+                generator.writeEndObject();
+            }
+        }
+
+        final class Deserializer extends StdDeserializer<Body> {
+            public Deserializer() {
+                super(Body.class);
+            }
+
+            @Override
+            public Body deserialize(JsonParser parser, DeserializationContext context) throws IOException {
+                JsonNode node = parser.readValueAsTree();
+                if (!node.isObject() || node.size() != 1) {
+                    throw JsonMappingException.from(parser, "Expected exactly one key");
+                }
+                Map.Entry<String, JsonNode> entry = node.fields().next();
+                return switch (entry.getKey()) {
+                    case "content-type" -> new BodyContentType(read(context, entry.getValue(), new TypeReference<String>() {}));
+                    case "x-raw" -> new BodyXRaw(read(context, entry.getValue(), new TypeReference<List<Double>>() {}));
+                    default -> throw JsonMappingException.from(parser, "Unknown tag " + entry.getKey());
+                };
+            }
+        }
+    }
+
+    public record BodyContentType(String value) implements Body {}
+    public record BodyXRaw(List<Double> value) implements Body {}
+}
+
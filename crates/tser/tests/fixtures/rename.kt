@@ -0,0 +1,134 @@
+@file:OptIn(kotlinx.serialization.ExperimentalSerializationApi::class)
+
+import kotlinx.serialization.*
+import kotlinx.serialization.descriptors.*
+import kotlinx.serialization.encoding.*
+import kotlinx.serialization.json.*
+
+@Serializable
+data class Headers(
+    @SerialName("content-type") val contentType: String,
+    @SerialName("x-request-id") val xRequestId: String? = null,
+    @SerialName("2fa_enabled") val _2faEnabled: Boolean,
+    val accept: String,
+)
+
+@Serializable
+@JsonClassDiscriminator("kind")
+sealed interface Event
+
+@Serializable
+@SerialName("click")
+data class EventClick(
+    @SerialName("client-x") val clientX: Double,
+) : Event
+
+@Serializable
+@SerialName("close")
+data object EventClose : Event
+
+@Serializable
+@JsonClassDiscriminator("the-kind")
+sealed interface Shape
+
+@Serializable
+@SerialName("circle")
+data class ShapeCircle(
+    val radius: Double,
+) : Shape
+
+@Serializable
+@SerialName("square")
+data class ShapeSquare(
+    @SerialName("side-length") val sideLength: Double,
+) : Shape
+
+@Serializable(with = Payload.Serializer::class)
+sealed interface Payload {
+    object Serializer : KSerializer<Payload> {
+        override val descriptor = buildClassSerialDescriptor("Payload")
+
+        override fun serialize(encoder: Encoder, value: Payload) {
+            val output = encoder as JsonEncoder
+            val element = buildJsonObject {
+                when (value) {
+                    is PayloadText -> {
+                        put("the-kind", "text")
+                        put("my-data", output.json.encodeToJsonElement(value.value))
+                    }
+                    is PayloadCount -> {
+                        put("the-kind", "count")
+                        value.value?.let { put("my-data", output.json.encodeToJsonElement(it)) }
+                    }
+                }
+            }
+            output.encodeJsonElement(element)
+        }
+
+        override fun deserialize(decoder: Decoder): Payload {
+            val input = decoder as JsonDecoder
+            val element = input.decodeJsonElement().jsonObject
+            val data = element["my-data"] ?: JsonNull
+            return when (val tag = element["the-kind"]?.jsonPrimitive?.contentOrNull) {
+                "text" -> PayloadText(input.json.decodeFromJsonElement(data))
+                "count" -> PayloadCount(input.json.decodeFromJsonElement(data))
+                else -> throw SerializationException("Unknown tag $tag")
+            }
+        }
+    }
+}
+
+data class PayloadText(val value: String) : Payload
+data class PayloadCount(val value: Double?) : Payload
+
+@Serializable
+@JsonClassDiscriminator("method")
+sealed interface Payment
+
+@Serializable
+@SerialName("bank-transfer")
+data class PaymentBankTransfer(
+    val iban: String,
+    val x: Double,
+) : Payment
+
+@Serializable
+@SerialName("card")
+data class PaymentCard(
+    val last4: String,
+) : Payment
+
+@Serializable(with = Body.Serializer::class)
+sealed interface Body {
+    object Serializer : KSerializer<Body> {
+        override val descriptor = buildClassSerialDescriptor("Body")
+
+        override fun serialize(encoder: Encoder, value: Body) {
+            val output = encoder as JsonEncoder
+            val element = when (value) {
+                is BodyContentType -> buildJsonObject {
+                    put("content-type", output.json.encodeToJsonElement(value.value))
+                }
+                is BodyXRaw -> buildJsonObject {
+                    put("x-raw", output.json.encodeToJsonElement(value.value))
+                }
+            }
+            output.encodeJsonElement(element)
+        }
+
+        override fun deserialize(decoder: Decoder): Body {
+            val input = decoder as JsonDecoder
+            val (tag, element) = input.decodeJsonElement().jsonObject.entries.singleOrNull()
+                ?: throw SerializationException("Expected exactly one key")
+            return when (tag) {
+                "content-type" -> BodyContentType(input.json.decodeFromJsonElement(element))
+                "x-raw" -> BodyXRaw(input.json.decodeFromJsonElement(element))
+                else -> throw SerializationException("Unknown tag $tag")
+            }
+        }
+    }
+}
+
+data class BodyContentType(val value: String) : Body
+data class BodyXRaw(val value: List<Double>) : Body
+
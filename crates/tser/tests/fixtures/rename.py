@@ -0,0 +1,68 @@
+# Requires pydantic>=2.12
+import enum
+from typing import Annotated, Dict, List, Literal, NewType, Optional, Tuple, Union
+
+from pydantic import BaseModel, ConfigDict, Field
+
+class Headers(BaseModel):
+    model_config = ConfigDict(validate_by_name=True, serialize_by_alias=True)
+    content_type: str = Field(alias="content-type")
+    x_request_id: Optional[str] = Field(default=None, alias="x-request-id", exclude_if=lambda value: value is None)
+    field_2fa_enabled: bool = Field(alias="2fa_enabled")
+    accept: str
+
+class EventClick(BaseModel):
+    model_config = ConfigDict(validate_by_name=True, serialize_by_alias=True)
+    kind: Literal["click"] = "click"
+    client_x: float = Field(alias="client-x")
+
+class EventClose(BaseModel):
+    kind: Literal["close"] = "close"
+
+Event = Annotated[Union[EventClick, EventClose], Field(discriminator="kind")]
+
+class ShapeCircle(BaseModel):
+    model_config = ConfigDict(validate_by_name=True, serialize_by_alias=True)
+    the_kind: Literal["circle"] = Field(default="circle", alias="the-kind")
+    radius: float
+
+class ShapeSquare(BaseModel):
+    model_config = ConfigDict(validate_by_name=True, serialize_by_alias=True)
+    the_kind: Literal["square"] = Field(default="square", alias="the-kind")
+    side_length: float = Field(alias="side-length")
+
+Shape = Annotated[Union[ShapeCircle, ShapeSquare], Field(discriminator="the_kind")]
+
+class PayloadText(BaseModel):
+    model_config = ConfigDict(validate_by_name=True, serialize_by_alias=True)
+    the_kind: Literal["text"] = Field(default="text", alias="the-kind")
+    my_data: str = Field(alias="my-data")
+
+class PayloadCount(BaseModel):
+    model_config = ConfigDict(validate_by_name=True, serialize_by_alias=True)
+    the_kind: Literal["count"] = Field(default="count", alias="the-kind")
+    my_data: Optional[float] = Field(default=None, alias="my-data", exclude_if=lambda value: value is None)
+
+Payload = Annotated[Union[PayloadText, PayloadCount], Field(discriminator="the_kind")]
+
+class PaymentBankTransfer(BaseModel):
+    method: Literal["bank-transfer"] = "bank-transfer"
+    iban: str
+    x: float
+
+class PaymentCard(BaseModel):
+    method: Literal["card"] = "card"
+    last4: str
+
+Payment = Annotated[Union[PaymentBankTransfer, PaymentCard], Field(discriminator="method")]
+
+class BodyContentType(BaseModel):
+    model_config = ConfigDict(extra="forbid", validate_by_name=True, serialize_by_alias=True)
+    content_type: str = Field(alias="content-type")
+
+class BodyXRaw(BaseModel):
+    model_config = ConfigDict(extra="forbid", validate_by_name=True, serialize_by_alias=True)
+    x_raw: List[float] = Field(alias="x-raw")
+
+Body = Union[BodyContentType, BodyXRaw]
+
@@ -0,0 +1,67 @@
+use serde::{Serialize, Deserialize};
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct Headers {
+    #[serde(rename = "content-type")]
+    pub content_type: String,
+    #[serde(rename = "x-request-id")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub x_request_id: Option<String>,
+    #[serde(rename = "2fa_enabled")]
+    pub _2fa_enabled: bool,
+    pub accept: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(tag = "kind")]
+pub enum Event {
+    #[serde(rename = "click")]
+    Click {
+        #[serde(rename = "client-x")]
+        client_x: f64,
+    },
+    #[serde(rename = "close")]
+    Close,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(tag = "the-kind")]
+pub enum Shape {
+    #[serde(rename = "circle")]
+    Circle {
+        radius: f64,
+    },
+    #[serde(rename = "square")]
+    Square {
+        #[serde(rename = "side-length")]
+        side_length: f64,
+    },
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(tag = "the-kind", content = "my-data")]
+pub enum Payload {
+    #[serde(rename = "text")] Text(String),
+    #[serde(rename = "count")] Count(Option<f64>),
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(tag = "method")]
+pub enum Payment {
+    #[serde(rename = "bank-transfer")]
+    BankTransfer {
+        iban: String,
+        x: f64,
+    },
+    #[serde(rename = "card")]
+    Card {
+        last4: String,
+    },
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub enum Body {
+    #[serde(rename = "content-type")] ContentType(String),
+    #[serde(rename = "x-raw")] XRaw(Vec<f64>),
+}
+
@@ -0,0 +1,190 @@
+public struct Headers: Codable, Equatable, Hashable {
+    public var contentType: String
+    public var xRequestId: String?
+    public var _2faEnabled: Bool
+    public var accept: String
+
+    private enum CodingKeys: String, CodingKey {
+        case contentType = "content-type"
+        case xRequestId = "x-request-id"
+        case _2faEnabled = "2fa_enabled"
+        case accept = "accept"
+    }
+}
+
+public enum Event: Codable, Equatable, Hashable {
+    case click(clientX: Double)
+    case close
+
+    private enum CodingKeys: String, CodingKey {
+        case kind = "kind"
+        case clientX = "client-x"
+    }
+
+    public init(from decoder: Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        let tag = try container.decode(String.self, forKey: .kind)
+        switch tag {
+        case "click":
+            self = try .click(clientX: container.decode(Double.self, forKey: .clientX))
+        case "close":
+            self = .close
+        default:
+            throw DecodingError.dataCorruptedError(forKey: .kind, in: container, debugDescription: "Unknown tag \(tag)")
+        }
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.container(keyedBy: CodingKeys.self)
+        switch self {
+        case let .click(clientX):
+            try container.encode("click", forKey: .kind)
+            try container.encode(clientX, forKey: .clientX)
+        case .close:
+            try container.encode("close", forKey: .kind)
+        }
+    }
+}
+
+public enum Shape: Codable, Equatable, Hashable {
+    case circle(radius: Double)
+    case square(sideLength: Double)
+
+    private enum CodingKeys: String, CodingKey {
+        case theKind = "the-kind"
+        case radius = "radius"
+        case sideLength = "side-length"
+    }
+
+    public init(from decoder: Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        let tag = try container.decode(String.self, forKey: .theKind)
+        switch tag {
+        case "circle":
+            self = try .circle(radius: container.decode(Double.self, forKey: .radius))
+        case "square":
+            self = try .square(sideLength: container.decode(Double.self, forKey: .sideLength))
+        default:
+            throw DecodingError.dataCorruptedError(forKey: .theKind, in: container, debugDescription: "Unknown tag \(tag)")
+        }
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.container(keyedBy: CodingKeys.self)
+        switch self {
+        case let .circle(radius):
+            try container.encode("circle", forKey: .theKind)
+            try container.encode(radius, forKey: .radius)
+        case let .square(sideLength):
+            try container.encode("square", forKey: .theKind)
+            try container.encode(sideLength, forKey: .sideLength)
+        }
+    }
+}
+
+public enum Payload: Codable, Equatable, Hashable {
+    case text(String)
+    case count(Double?)
+
+    private enum CodingKeys: String, CodingKey {
+        case theKind = "the-kind"
+        case myData = "my-data"
+    }
+
+    public init(from decoder: Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        let tag = try container.decode(String.self, forKey: .theKind)
+        switch tag {
+        case "text":
+            self = try .text(container.decode(String.self, forKey: .myData))
+        case "count":
+            self = try .count(container.decodeIfPresent(Double.self, forKey: .myData))
+        default:
+            throw DecodingError.dataCorruptedError(forKey: .theKind, in: container, debugDescription: "Unknown tag \(tag)")
+        }
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.container(keyedBy: CodingKeys.self)
+        switch self {
+        case let .text(value):
+            try container.encode("text", forKey: .theKind)
+            try container.encode(value, forKey: .myData)
+        case let .count(value):
+            try container.encode("count", forKey: .theKind)
+            try container.encodeIfPresent(value, forKey: .myData)
+        }
+    }
+}
+
+public enum Payment: Codable, Equatable, Hashable {
+    case bankTransfer(iban: String, x: Double)
+    case card(last4: String)
+
+    private enum CodingKeys: String, CodingKey {
+        case method = "method"
+        case iban = "iban"
+        case x = "x"
+        case last4 = "last4"
+    }
+
+    public init(from decoder: Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        let tag = try container.decode(String.self, forKey: .method)
+        switch tag {
+        case "bank-transfer":
+            self = try .bankTransfer(iban: container.decode(String.self, forKey: .iban), x: container.decode(Double.self, forKey: .x))
+        case "card":
+            self = try .card(last4: container.decode(String.self, forKey: .last4))
+        default:
+            throw DecodingError.dataCorruptedError(forKey: .method, in: container, debugDescription: "Unknown tag \(tag)")
+        }
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.container(keyedBy: CodingKeys.self)
+        switch self {
+        case let .bankTransfer(iban, x):
+            try container.encode("bank-transfer", forKey: .method)
+            try container.encode(iban, forKey: .iban)
+            try container.encode(x, forKey: .x)
+        case let .card(last4):
+            try container.encode("card", forKey: .method)
+            try container.encode(last4, forKey: .last4)
+        }
+    }
+}
+
+public enum Body: Codable, Equatable, Hashable {
+    case contentType(String)
+    case xRaw([Double])
+
+    private enum CodingKeys: String, CodingKey {
+        case contentType = "content-type"
+        case xRaw = "x-raw"
+    }
+
+    public init(from decoder: Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        guard container.allKeys.count == 1 else {
+            throw DecodingError.dataCorrupted(DecodingError.Context(codingPath: container.codingPath, debugDescription: "Expected exactly one key"))
+        }
+        switch container.allKeys[0] {
+        case .contentType:
+            self = try .contentType(container.decode(String.self, forKey: .contentType))
+        case .xRaw:
+            self = try .xRaw(container.decode([Double].self, forKey: .xRaw))
+        }
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.container(keyedBy: CodingKeys.self)
+        switch self {
+        case let .contentType(value):
+            try container.encode(value, forKey: .contentType)
+        case let .xRaw(value):
+            try container.encode(value, forKey: .xRaw)
+        }
+    }
+}
+
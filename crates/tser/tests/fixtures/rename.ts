@@ -0,0 +1,26 @@
+interface Headers {
+    "content-type": string;
+    'x-request-id'?: string;
+    "2fa_enabled": boolean;
+    accept: string;
+}
+
+type Event =
+    | { kind: "click"; "client-x": number }
+    | { kind: "close" };
+
+type Shape =
+    | { "the-kind": "circle"; radius: number }
+    | { "the-kind": "square"; "side-length": number };
+
+type Payload =
+    | { "the-kind": "text"; "my-data": string }
+    | { "the-kind": "count"; "my-data"?: number };
+
+type Payment =
+    | { method: "bank-transfer"; iban: string; x: number }
+    | { method: "card"; last4: string };
+
+type Body =
+    | { "content-type": string }
+    | { "x-raw": number[] };
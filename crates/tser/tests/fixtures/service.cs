@@ -0,0 +1,121 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.Linq;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace Models;
+
+public record User
+{
+    [JsonPropertyName("id")]
+    public required string Id { get; init; }
+
+    [JsonPropertyName("name")]
+    public required string Name { get; init; }
+}
+
+[JsonPolymorphic(TypeDiscriminatorPropertyName = "type")]
+[JsonDerivedType(typeof(EventJoined), "joined")]
+[JsonDerivedType(typeof(EventLeft), "left")]
+public abstract record Event;
+
+public record EventJoined : Event
+{
+    [JsonPropertyName("user")]
+    public required User User { get; init; }
+}
+
+public record EventLeft : Event
+{
+    [JsonPropertyName("userId")]
+    public required string UserId { get; init; }
+}
+
+[JsonConverter(typeof(UserServiceGetUserRequest.Converter))]
+public readonly record struct UserServiceGetUserRequest(string Value)
+{
+    public class Converter : JsonConverter<UserServiceGetUserRequest>
+    {
+        public override UserServiceGetUserRequest Read(ref Utf8JsonReader reader, Type typeToConvert, JsonSerializerOptions options) =>
+            new(JsonSerializer.Deserialize<string>(ref reader, options)!);
+
+        public override void Write(Utf8JsonWriter writer, UserServiceGetUserRequest value, JsonSerializerOptions options) =>
+            JsonSerializer.Serialize(writer, value.Value, options);
+    }
+}
+
+[JsonConverter(typeof(UserServiceGetUserResponse.Converter))]
+public readonly record struct UserServiceGetUserResponse(User? Value)
+{
+    public class Converter : JsonConverter<UserServiceGetUserResponse>
+    {
+        public override UserServiceGetUserResponse Read(ref Utf8JsonReader reader, Type typeToConvert, JsonSerializerOptions options) =>
+            new(JsonSerializer.Deserialize<User?>(ref reader, options));
+
+        public override void Write(Utf8JsonWriter writer, UserServiceGetUserResponse value, JsonSerializerOptions options) =>
+            JsonSerializer.Serialize(writer, value.Value, options);
+    }
+}
+
+public record UserServiceSearchRequest
+{
+    [JsonPropertyName("query")]
+    public required string Query { get; init; }
+
+    [JsonPropertyName("limit")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public double? Limit { get; init; }
+}
+
+[JsonConverter(typeof(UserServiceSearchResponse.Converter))]
+public readonly record struct UserServiceSearchResponse(List<User> Value)
+{
+    public class Converter : JsonConverter<UserServiceSearchResponse>
+    {
+        public override UserServiceSearchResponse Read(ref Utf8JsonReader reader, Type typeToConvert, JsonSerializerOptions options) =>
+            new(JsonSerializer.Deserialize<List<User>>(ref reader, options)!);
+
+        public override void Write(Utf8JsonWriter writer, UserServiceSearchResponse value, JsonSerializerOptions options) =>
+            JsonSerializer.Serialize(writer, value.Value, options);
+    }
+}
+
+[JsonConverter(typeof(UserServiceWatchRequest.Converter))]
+public readonly record struct UserServiceWatchRequest(string Value)
+{
+    public class Converter : JsonConverter<UserServiceWatchRequest>
+    {
+        public override UserServiceWatchRequest Read(ref Utf8JsonReader reader, Type typeToConvert, JsonSerializerOptions options) =>
+            new(JsonSerializer.Deserialize<string>(ref reader, options)!);
+
+        public override void Write(Utf8JsonWriter writer, UserServiceWatchRequest value, JsonSerializerOptions options) =>
+            JsonSerializer.Serialize(writer, value.Value, options);
+    }
+}
+
+[JsonConverter(typeof(UserServiceWatchResponse.Converter))]
+public readonly record struct UserServiceWatchResponse(Event Value)
+{
+    public class Converter : JsonConverter<UserServiceWatchResponse>
+    {
+        public override UserServiceWatchResponse Read(ref Utf8JsonReader reader, Type typeToConvert, JsonSerializerOptions options) =>
+            new(JsonSerializer.Deserialize<Event>(ref reader, options)!);
+
+        public override void Write(Utf8JsonWriter writer, UserServiceWatchResponse value, JsonSerializerOptions options) =>
+            JsonSerializer.Serialize(writer, value.Value, options);
+    }
+}
+
+/// <summary>Manages users</summary>
+public static class UserServiceMethods
+{
+    /// <summary>Looks a user up by ID</summary>
+    public const string GetUser = "getUser";
+    public const string Search = "search";
+    public const string Watch = "watch";
+    public const string Ping = "ping";
+}
+
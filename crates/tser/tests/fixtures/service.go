@@ -0,0 +1,105 @@
+package models
+
+import (
+    "encoding/json"
+    "fmt"
+)
+
+type User struct {
+    Id string `json:"id"`
+    Name string `json:"name"`
+}
+
+type Event struct {
+    Value isEvent
+}
+
+type isEvent interface {
+    isEvent()
+}
+
+type EventJoined struct {
+    User User `json:"user"`
+}
+
+func (EventJoined) isEvent() {}
+
+type EventLeft struct {
+    UserId string `json:"userId"`
+}
+
+func (EventLeft) isEvent() {}
+
+func (u Event) MarshalJSON() ([]byte, error) {
+    switch value := u.Value.(type) {
+    case EventJoined:
+        return json.Marshal(struct {
+            Tag string `json:"type"`
+            EventJoined
+        }{"joined", value})
+    case EventLeft:
+        return json.Marshal(struct {
+            Tag string `json:"type"`
+            EventLeft
+        }{"left", value})
+    default:
+        return nil, fmt.Errorf("unexpected Event variant %T", u.Value)
+    }
+}
+
+func (u *Event) UnmarshalJSON(data []byte) error {
+    var tagged struct {
+        Tag string `json:"type"`
+    }
+    if err := json.Unmarshal(data, &tagged); err != nil {
+        return err
+    }
+    var err error
+    switch tagged.Tag {
+    case "joined":
+        var value EventJoined
+        err = json.Unmarshal(data, &value)
+        u.Value = value
+    case "left":
+        var value EventLeft
+        err = json.Unmarshal(data, &value)
+        u.Value = value
+    default:
+        return fmt.Errorf("unknown Event tag %q", tagged.Tag)
+    }
+    return err
+}
+
+type UserServiceGetUserRequest = string
+
+type UserServiceGetUserResponse = *User
+
+type UserServiceSearchRequest struct {
+    Query string `json:"query"`
+    Limit *float64 `json:"limit,omitempty"`
+}
+
+type UserServiceSearchResponse = []User
+
+type UserServiceWatchRequest = string
+
+type UserServiceWatchResponse = Event
+
+// Manages users
+type UserServiceMethod string
+
+const (
+    // Looks a user up by ID
+    UserServiceMethodGetUser UserServiceMethod = "getUser"
+    UserServiceMethodSearch UserServiceMethod = "search"
+    UserServiceMethodWatch UserServiceMethod = "watch"
+    UserServiceMethodPing UserServiceMethod = "ping"
+)
+
+var UserServiceMethods = []UserServiceMethod{
+    UserServiceMethodGetUser,
+    UserServiceMethodSearch,
+    UserServiceMethodWatch,
+    UserServiceMethodPing,
+}
+
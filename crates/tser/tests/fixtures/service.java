@@ -0,0 +1,124 @@
+package models;
+
+import com.fasterxml.jackson.annotation.*;
+import com.fasterxml.jackson.core.*;
+import com.fasterxml.jackson.core.type.TypeReference;
+import com.fasterxml.jackson.databind.*;
+import com.fasterxml.jackson.databind.annotation.*;
+import com.fasterxml.jackson.databind.deser.std.StdDeserializer;
+import com.fasterxml.jackson.databind.node.NullNode;
+import com.fasterxml.jackson.databind.ser.std.StdSerializer;
+import java.io.IOException;
+import java.math.BigInteger;
+import java.util.*;
+
+public final class Models {
+    private Models() {
+    }
+
+    public record User(
+        @JsonProperty(value = "id", required = true) String id,
+        @JsonProperty(value = "name", required = true) String name
+    ) {}
+
+    @JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = "type")
+    @JsonSubTypes({
+        @JsonSubTypes.Type(value = EventJoined.class, name = "joined"),
+        @JsonSubTypes.Type(value = EventLeft.class, name = "left"),
+    })
+    public sealed interface Event permits EventJoined, EventLeft {}
+
+    public record EventJoined(
+        @JsonProperty(value = "user", required = true) User user
+    ) implements Event {}
+
+    public record EventLeft(
+        @JsonProperty(value = "userId", required = true) String userId
+    ) implements Event {}
+
+    public record UserServiceGetUserRequest(String value) {
+        @JsonCreator(mode = JsonCreator.Mode.DELEGATING)
+        public UserServiceGetUserRequest {
+        }
+
+        @JsonValue
+        @Override
+        public String value() {
+            return value;
+        }
+    }
+
+    public record UserServiceGetUserResponse(User value) {
+        @JsonCreator(mode = JsonCreator.Mode.DELEGATING)
+        public UserServiceGetUserResponse {
+        }
+
+        @JsonValue
+        @Override
+        public User value() {
+            return value;
+        }
+    }
+
+    public record UserServiceSearchRequest(
+        @JsonProperty(value = "query", required = true) String query,
+        @JsonProperty("limit") @JsonInclude(JsonInclude.Include.NON_NULL) Double limit
+    ) {}
+
+    public record UserServiceSearchResponse(List<User> value) {
+        @JsonCreator(mode = JsonCreator.Mode.DELEGATING)
+        public UserServiceSearchResponse {
+        }
+
+        @JsonValue
+        @Override
+        public List<User> value() {
+            return value;
+        }
+    }
+
+    public record UserServiceWatchRequest(String value) {
+        @JsonCreator(mode = JsonCreator.Mode.DELEGATING)
+        public UserServiceWatchRequest {
+        }
+
+        @JsonValue
+        @Override
+        public String value() {
+            return value;
+        }
+    }
+
+    public record UserServiceWatchResponse(Event value) {
+        @JsonCreator(mode = JsonCreator.Mode.DELEGATING)
+        public UserServiceWatchResponse {
+        }
+
+        @JsonValue
+        @Override
+        public Event value() {
+            return value;
+        }
+    }
+
+    /** Manages users */
+    public enum UserServiceMethod {
+        /** Looks a user up by ID */
+        GET_USER("getUser"),
+        SEARCH("search"),
+        WATCH("watch"),
+        PING("ping");
+
+        private final String value;
+
+        UserServiceMethod(String value) {
+            this.value = value;
+        }
+
+        @JsonValue
+        public String value() {
+            return value;
+        }
+    }
+}
+
@@ -0,0 +1,55 @@
+@file:OptIn(kotlinx.serialization.ExperimentalSerializationApi::class)
+
+import kotlinx.serialization.*
+import kotlinx.serialization.descriptors.*
+import kotlinx.serialization.encoding.*
+import kotlinx.serialization.json.*
+
+@Serializable
+data class User(
+    val id: String,
+    val name: String,
+)
+
+@Serializable
+@JsonClassDiscriminator("type")
+sealed interface Event
+
+@Serializable
+@SerialName("joined")
+data class EventJoined(
+    val user: User,
+) : Event
+
+@Serializable
+@SerialName("left")
+data class EventLeft(
+    val userId: String,
+) : Event
+
+typealias UserServiceGetUserRequest = String
+
+typealias UserServiceGetUserResponse = User?
+
+@Serializable
+data class UserServiceSearchRequest(
+    val query: String,
+    val limit: Double? = null,
+)
+
+typealias UserServiceSearchResponse = List<User>
+
+typealias UserServiceWatchRequest = String
+
+typealias UserServiceWatchResponse = Event
+
+/** Manages users */
+@Serializable
+enum class UserServiceMethod(val methodName: String) {
+    /** Looks a user up by ID */
+    @SerialName("getUser") GetUser("getUser"),
+    @SerialName("search") Search("search"),
+    @SerialName("watch") Watch("watch"),
+    @SerialName("ping") Ping("ping"),
+}
+
@@ -0,0 +1,43 @@
+# Requires pydantic>=2.12
+import enum
+from typing import Annotated, Dict, List, Literal, NewType, Optional, Tuple, Union
+
+from pydantic import BaseModel, ConfigDict, Field
+
+class User(BaseModel):
+    id: str
+    name: str
+
+class EventJoined(BaseModel):
+    type: Literal["joined"] = "joined"
+    user: User
+
+class EventLeft(BaseModel):
+    model_config = ConfigDict(validate_by_name=True, serialize_by_alias=True)
+    type: Literal["left"] = "left"
+    user_id: str = Field(alias="userId")
+
+Event = Annotated[Union[EventJoined, EventLeft], Field(discriminator="type")]
+
+UserServiceGetUserRequest = str
+
+UserServiceGetUserResponse = Optional[User]
+
+class UserServiceSearchRequest(BaseModel):
+    query: str
+    limit: Optional[float] = Field(default=None, exclude_if=lambda value: value is None)
+
+UserServiceSearchResponse = List[User]
+
+UserServiceWatchRequest = str
+
+UserServiceWatchResponse = Event
+
+class UserServiceMethod(enum.Enum):
+    """Manages users"""
+    GET_USER = "getUser"
+    """Looks a user up by ID"""
+    SEARCH = "search"
+    WATCH = "watch"
+    PING = "ping"
+
@@ -0,0 +1,63 @@
+use serde::{Serialize, Deserialize};
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct User {
+    pub id: String,
+    pub name: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(tag = "type")]
+pub enum Event {
+    #[serde(rename = "joined")]
+    Joined {
+        user: User,
+    },
+    #[serde(rename = "left")]
+    #[serde(rename_all = "camelCase")]
+    Left {
+        user_id: String,
+    },
+}
+
+pub type UserServiceGetUserRequest = String;
+
+pub type UserServiceGetUserResponse = Option<User>;
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct UserServiceSearchRequest {
+    pub query: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub limit: Option<f64>,
+}
+
+pub type UserServiceSearchResponse = Vec<User>;
+
+pub type UserServiceWatchRequest = String;
+
+pub type UserServiceWatchResponse = Event;
+
+/// Manages users
+#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]
+pub enum UserServiceMethod {
+    /// Looks a user up by ID
+    GetUser,
+    Search,
+    Watch,
+    Ping,
+}
+
+impl UserServiceMethod {
+    pub const ALL: [Self; 4] = [Self::GetUser, Self::Search, Self::Watch, Self::Ping];
+
+    /// The name of the method as declared
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::GetUser => "getUser",
+            Self::Search => "search",
+            Self::Watch => "watch",
+            Self::Ping => "ping",
+        }
+    }
+}
+
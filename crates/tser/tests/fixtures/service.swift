@@ -0,0 +1,65 @@
+public struct User: Codable, Equatable, Hashable {
+    public var id: String
+    public var name: String
+}
+
+public enum Event: Codable, Equatable, Hashable {
+    case joined(user: User)
+    case left(userId: String)
+
+    private enum CodingKeys: String, CodingKey {
+        case type = "type"
+        case user = "user"
+        case userId = "userId"
+    }
+
+    public init(from decoder: Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        let tag = try container.decode(String.self, forKey: .type)
+        switch tag {
+        case "joined":
+            self = try .joined(user: container.decode(User.self, forKey: .user))
+        case "left":
+            self = try .left(userId: container.decode(String.self, forKey: .userId))
+        default:
+            throw DecodingError.dataCorruptedError(forKey: .type, in: container, debugDescription: "Unknown tag \(tag)")
+        }
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.container(keyedBy: CodingKeys.self)
+        switch self {
+        case let .joined(user):
+            try container.encode("joined", forKey: .type)
+            try container.encode(user, forKey: .user)
+        case let .left(userId):
+            try container.encode("left", forKey: .type)
+            try container.encode(userId, forKey: .userId)
+        }
+    }
+}
+
+public typealias UserServiceGetUserRequest = String
+
+public typealias UserServiceGetUserResponse = User?
+
+public struct UserServiceSearchRequest: Codable, Equatable, Hashable {
+    public var query: String
+    public var limit: Double?
+}
+
+public typealias UserServiceSearchResponse = [User]
+
+public typealias UserServiceWatchRequest = String
+
+public typealias UserServiceWatchResponse = Event
+
+/// Manages users
+public enum UserServiceMethod: String, CaseIterable, Codable, Equatable, Hashable {
+    /// Looks a user up by ID
+    case getUser = "getUser"
+    case search = "search"
+    case watch = "watch"
+    case ping = "ping"
+}
+
@@ -0,0 +1,15 @@
+interface User {
+    id: string;
+    name: string;
+}
+
+type Event = { type: 'joined'; user: User } | { type: 'left'; userId: string };
+
+/** Manages users */
+interface UserService {
+    /** Looks a user up by ID */
+    getUser(id: string): Promise<User | null>;
+    search(query: string, limit?: number): Promise<User[]>;
+    watch(roomId: string): AsyncIterable<Event>;
+    ping(): Promise<void>;
+}
@@ -0,0 +1,68 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.Linq;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace Models;
+
+public record Polygon
+{
+    [JsonPropertyName("points")]
+    public required List<NumberNumberTuple> Points { get; init; }
+
+    [JsonPropertyName("label")]
+    public required StringNullableBoolTuple Label { get; init; }
+
+    [JsonPropertyName("anchor")]
+    public required NumberNumberTuple? Anchor { get; init; }
+}
+
+[JsonConverter(typeof(NumberNumberTuple.Converter))]
+public record NumberNumberTuple(double Item1, double Item2)
+{
+    public class Converter : JsonConverter<NumberNumberTuple>
+    {
+        public override NumberNumberTuple Read(ref Utf8JsonReader reader, Type typeToConvert, JsonSerializerOptions options)
+        {
+            var element = JsonElement.ParseValue(ref reader);
+            return new NumberNumberTuple(
+                element[0].Deserialize<double>(options)!,
+                element[1].Deserialize<double>(options)!);
+        }
+
+        public override void Write(Utf8JsonWriter writer, NumberNumberTuple value, JsonSerializerOptions options)
+        {
+            writer.WriteStartArray();
+            JsonSerializer.Serialize(writer, value.Item1, options);
+            JsonSerializer.Serialize(writer, value.Item2, options);
+            writer.WriteEndArray();
+        }
+    }
+}
+
+[JsonConverter(typeof(StringNullableBoolTuple.Converter))]
+public record StringNullableBoolTuple(string Item1, bool? Item2)
+{
+    public class Converter : JsonConverter<StringNullableBoolTuple>
+    {
+        public override StringNullableBoolTuple Read(ref Utf8JsonReader reader, Type typeToConvert, JsonSerializerOptions options)
+        {
+            var element = JsonElement.ParseValue(ref reader);
+            return new StringNullableBoolTuple(
+                element[0].Deserialize<string>(options)!,
+                element.GetArrayLength() > 1 ? element[1].Deserialize<bool?>(options) : null);
+        }
+
+        public override void Write(Utf8JsonWriter writer, StringNullableBoolTuple value, JsonSerializerOptions options)
+        {
+            writer.WriteStartArray();
+            JsonSerializer.Serialize(writer, value.Item1, options);
+            JsonSerializer.Serialize(writer, value.Item2, options);
+            writer.WriteEndArray();
+        }
+    }
+}
+
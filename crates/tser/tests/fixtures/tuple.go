@@ -0,0 +1,38 @@
+package models
+
+import (
+    "encoding/json"
+)
+
+type Polygon struct {
+    Points []NumberNumberTuple `json:"points"`
+    Label StringNullableBoolTuple `json:"label"`
+    Anchor *NumberNumberTuple `json:"anchor"`
+}
+
+type NumberNumberTuple struct {
+    V0 float64
+    V1 float64
+}
+
+func (t NumberNumberTuple) MarshalJSON() ([]byte, error) {
+    return json.Marshal([]any{t.V0, t.V1})
+}
+
+func (t *NumberNumberTuple) UnmarshalJSON(data []byte) error {
+    return json.Unmarshal(data, &[]any{&t.V0, &t.V1})
+}
+
+type StringNullableBoolTuple struct {
+    V0 string
+    V1 *bool
+}
+
+func (t StringNullableBoolTuple) MarshalJSON() ([]byte, error) {
+    return json.Marshal([]any{t.V0, t.V1})
+}
+
+func (t *StringNullableBoolTuple) UnmarshalJSON(data []byte) error {
+    return json.Unmarshal(data, &[]any{&t.V0, &t.V1})
+}
+
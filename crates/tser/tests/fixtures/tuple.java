@@ -0,0 +1,39 @@
+package models;
+
+import com.fasterxml.jackson.annotation.*;
+import com.fasterxml.jackson.core.*;
+import com.fasterxml.jackson.core.type.TypeReference;
+import com.fasterxml.jackson.databind.*;
+import com.fasterxml.jackson.databind.annotation.*;
+import com.fasterxml.jackson.databind.deser.std.StdDeserializer;
+import com.fasterxml.jackson.databind.node.NullNode;
+import com.fasterxml.jackson.databind.ser.std.StdSerializer;
+import java.io.IOException;
+import java.math.BigInteger;
+import java.util.*;
+
+public final class Models {
+    private Models() {
+    }
+
+    public record Polygon(
+        @JsonProperty(value = "points", required = true) List<NumberNumberTuple> points,
+        @JsonProperty(value = "label", required = true) StringNullableBoolTuple label,
+        @JsonProperty(value = "anchor", required = true) NumberNumberTuple anchor
+    ) {}
+
+    @JsonFormat(shape = JsonFormat.Shape.ARRAY)
+    @JsonPropertyOrder({"item1", "item2"})
+    public record NumberNumberTuple(
+        double item1,
+        double item2
+    ) {}
+
+    @JsonFormat(shape = JsonFormat.Shape.ARRAY)
+    @JsonPropertyOrder({"item1", "item2"})
+    public record StringNullableBoolTuple(
+        String item1,
+        Boolean item2
+    ) {}
+}
+
@@ -0,0 +1,70 @@
+@file:OptIn(kotlinx.serialization.ExperimentalSerializationApi::class)
+
+import kotlinx.serialization.*
+import kotlinx.serialization.descriptors.*
+import kotlinx.serialization.encoding.*
+import kotlinx.serialization.json.*
+
+@Serializable
+data class Polygon(
+    val points: List<NumberNumberTuple>,
+    val label: StringNullableBoolTuple,
+    val anchor: NumberNumberTuple?,
+)
+
+@Serializable(with = NumberNumberTuple.Serializer::class)
+data class NumberNumberTuple(
+    val _0: Double,
+    val _1: Double,
+) {
+    object Serializer : KSerializer<NumberNumberTuple> {
+        override val descriptor = buildClassSerialDescriptor("NumberNumberTuple")
+
+        override fun serialize(encoder: Encoder, value: NumberNumberTuple) {
+            val output = encoder as JsonEncoder
+            val element = buildJsonArray {
+                add(output.json.encodeToJsonElement(value._0))
+                add(output.json.encodeToJsonElement(value._1))
+            }
+            output.encodeJsonElement(element)
+        }
+
+        override fun deserialize(decoder: Decoder): NumberNumberTuple {
+            val input = decoder as JsonDecoder
+            val array = input.decodeJsonElement().jsonArray
+            return NumberNumberTuple(
+                input.json.decodeFromJsonElement(array[0]),
+                input.json.decodeFromJsonElement(array[1]),
+            )
+        }
+    }
+}
+
+@Serializable(with = StringNullableBoolTuple.Serializer::class)
+data class StringNullableBoolTuple(
+    val _0: String,
+    val _1: Boolean?,
+) {
+    object Serializer : KSerializer<StringNullableBoolTuple> {
+        override val descriptor = buildClassSerialDescriptor("StringNullableBoolTuple")
+
+        override fun serialize(encoder: Encoder, value: StringNullableBoolTuple) {
+            val output = encoder as JsonEncoder
+            val element = buildJsonArray {
+                add(output.json.encodeToJsonElement(value._0))
+                add(output.json.encodeToJsonElement(value._1))
+            }
+            output.encodeJsonElement(element)
+        }
+
+        override fun deserialize(decoder: Decoder): StringNullableBoolTuple {
+            val input = decoder as JsonDecoder
+            val array = input.decodeJsonElement().jsonArray
+            return StringNullableBoolTuple(
+                input.json.decodeFromJsonElement(array[0]),
+                input.json.decodeFromJsonElement(array.getOrElse(1) { JsonNull }),
+            )
+        }
+    }
+}
+
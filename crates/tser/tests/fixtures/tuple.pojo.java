@@ -0,0 +1,102 @@
+package models;
+
+import com.fasterxml.jackson.annotation.*;
+import com.fasterxml.jackson.core.*;
+import com.fasterxml.jackson.core.type.TypeReference;
+import com.fasterxml.jackson.databind.*;
+import com.fasterxml.jackson.databind.annotation.*;
+import com.fasterxml.jackson.databind.deser.std.StdDeserializer;
+import com.fasterxml.jackson.databind.node.NullNode;
+import com.fasterxml.jackson.databind.ser.std.StdSerializer;
+import java.io.IOException;
+import java.math.BigInteger;
+import java.util.*;
+
+public final class Models {
+    private Models() {
+    }
+
+    public static class Polygon {
+        @JsonProperty(value = "points", required = true)
+        private List<NumberNumberTuple> points;
+
+        @JsonProperty(value = "label", required = true)
+        private StringNullableBoolTuple label;
+
+        @JsonProperty(value = "anchor", required = true)
+        private NumberNumberTuple anchor;
+
+        public List<NumberNumberTuple> getPoints() {
+            return points;
+        }
+
+        public void setPoints(List<NumberNumberTuple> points) {
+            this.points = points;
+        }
+
+        public StringNullableBoolTuple getLabel() {
+            return label;
+        }
+
+        public void setLabel(StringNullableBoolTuple label) {
+            this.label = label;
+        }
+
+        public NumberNumberTuple getAnchor() {
+            return anchor;
+        }
+
+        public void setAnchor(NumberNumberTuple anchor) {
+            this.anchor = anchor;
+        }
+    }
+
+    @JsonFormat(shape = JsonFormat.Shape.ARRAY)
+    @JsonPropertyOrder({"item1", "item2"})
+    public static class NumberNumberTuple {
+        private double item1;
+
+        private double item2;
+
+        public double getItem1() {
+            return item1;
+        }
+
+        public void setItem1(double item1) {
+            this.item1 = item1;
+        }
+
+        public double getItem2() {
+            return item2;
+        }
+
+        public void setItem2(double item2) {
+            this.item2 = item2;
+        }
+    }
+
+    @JsonFormat(shape = JsonFormat.Shape.ARRAY)
+    @JsonPropertyOrder({"item1", "item2"})
+    public static class StringNullableBoolTuple {
+        private String item1;
+
+        private Boolean item2;
+
+        public String getItem1() {
+            return item1;
+        }
+
+        public void setItem1(String item1) {
+            this.item1 = item1;
+        }
+
+        public Boolean getItem2() {
+            return item2;
+        }
+
+        public void setItem2(Boolean item2) {
+            this.item2 = item2;
+        }
+    }
+}
+
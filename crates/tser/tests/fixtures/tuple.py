@@ -0,0 +1,11 @@
+# Requires pydantic>=2.12
+import enum
+from typing import Annotated, Dict, List, Literal, NewType, Optional, Tuple, Union
+
+from pydantic import BaseModel, ConfigDict, Field
+
+class Polygon(BaseModel):
+    points: List[Tuple[float, float]]
+    label: Tuple[str, Optional[bool]]
+    anchor: Optional[Tuple[float, float]]
+
@@ -0,0 +1,9 @@
+use serde::{Serialize, Deserialize};
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct Polygon {
+    pub points: Vec<(f64, f64)>,
+    pub label: (String, Option<bool>),
+    pub anchor: Option<(f64, f64)>,
+}
+
@@ -0,0 +1,50 @@
+public struct Polygon: Codable, Equatable, Hashable {
+    public var points: [NumberNumberTuple]
+    public var label: StringNullableBoolTuple
+    public var anchor: NumberNumberTuple?
+}
+
+public struct NumberNumberTuple: Codable, Equatable, Hashable {
+    public var _0: Double
+    public var _1: Double
+
+    public init(_ _0: Double, _ _1: Double) {
+        self._0 = _0
+        self._1 = _1
+    }
+
+    public init(from decoder: Decoder) throws {
+        var container = try decoder.unkeyedContainer()
+        _0 = try container.decode(Double.self)
+        _1 = try container.decode(Double.self)
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.unkeyedContainer()
+        try container.encode(_0)
+        try container.encode(_1)
+    }
+}
+
+public struct StringNullableBoolTuple: Codable, Equatable, Hashable {
+    public var _0: String
+    public var _1: Bool?
+
+    public init(_ _0: String, _ _1: Bool?) {
+        self._0 = _0
+        self._1 = _1
+    }
+
+    public init(from decoder: Decoder) throws {
+        var container = try decoder.unkeyedContainer()
+        _0 = try container.decode(String.self)
+        _1 = try container.decodeIfPresent(Bool.self)
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.unkeyedContainer()
+        try container.encode(_0)
+        try container.encode(_1)
+    }
+}
+
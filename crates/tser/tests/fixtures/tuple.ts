@@ -0,0 +1,5 @@
+interface Polygon {
+    points: [number, number][];
+    label: [string, boolean?];
+    anchor: [x: number, y: number] | null;
+}
@@ -0,0 +1,174 @@
+#nullable enable
+
+using System;
+using System.Collections.Generic;
+using System.Linq;
+using System.Text.Json;
+using System.Text.Json.Serialization;
+
+namespace Models;
+
+[JsonPolymorphic(TypeDiscriminatorPropertyName = "type")]
+[JsonDerivedType(typeof(InternalHello), "hello")]
+[JsonDerivedType(typeof(InternalEmpty), "empty")]
+[JsonDerivedType(typeof(InternalMaybeAString), "maybe_a_string")]
+public abstract record Internal;
+
+public record InternalHello : Internal
+{
+    [JsonPropertyName("val")]
+    public required double Val { get; init; }
+}
+
+public record InternalEmpty : Internal;
+
+public record InternalMaybeAString : Internal
+{
+    [JsonPropertyName("val")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? Val { get; init; }
+}
+
+[JsonConverter(typeof(Adjacent.Converter))]
+public abstract record Adjacent
+{
+    public class Converter : JsonConverter<Adjacent>
+    {
+        public override Adjacent Read(ref Utf8JsonReader reader, Type typeToConvert, JsonSerializerOptions options)
+        {
+            var element = JsonElement.ParseValue(ref reader);
+            var tag = element.GetProperty("kind").GetString();
+            element.TryGetProperty("data", out var data);
+            return tag switch
+            {
+                "num" => new AdjacentNum(data.Deserialize<double>(options)!),
+                "str" => new AdjacentStr(data.ValueKind == JsonValueKind.Undefined ? null : data.Deserialize<string?>(options)),
+                _ => throw new JsonException($"Unknown tag {tag}"),
+            };
+        }
+
+        public override void Write(Utf8JsonWriter writer, Adjacent value, JsonSerializerOptions options)
+        {
+            writer.WriteStartObject();
+            switch (value)
+            {
+                case AdjacentNum variant:
+                    writer.WriteString("kind", "num");
+                    writer.WritePropertyName("data");
+                    JsonSerializer.Serialize(writer, variant.Value, options);
+                    break;
+                case AdjacentStr variant:
+                    writer.WriteString("kind", "str");
+                    if (variant.Value is not null)
+                    {
+                        writer.WritePropertyName("data");
+                        JsonSerializer.Serialize(writer, variant.Value, options);
+                    }
+                    break;
+            }
+            writer.WriteEndObject();
+        }
+    }
+}
+
+public record AdjacentNum(double Value) : Adjacent;
+public record AdjacentStr(string? Value) : Adjacent;
+
+[JsonConverter(typeof(External.Converter))]
+public abstract record External
+{
+    public class Converter : JsonConverter<External>
+    {
+        public override External Read(ref Utf8JsonReader reader, Type typeToConvert, JsonSerializerOptions options)
+        {
+            var element = JsonElement.ParseValue(ref reader);
+            var properties = element.EnumerateObject().ToList();
+            if (properties.Count != 1)
+            {
+                throw new JsonException("Expected exactly one key");
+            }
+            var (tag, data) = (properties[0].Name, properties[0].Value);
+            return tag switch
+            {
+                "string" => new ExternalString(data.Deserialize<string>(options)!),
+                "number" => new ExternalNumber(data.Deserialize<double>(options)!),
+                _ => throw new JsonException($"Unknown tag {tag}"),
+            };
+        }
+
+        public override void Write(Utf8JsonWriter writer, External value, JsonSerializerOptions options)
+        {
+            writer.WriteStartObject();
+            switch (value)
+            {
+                case ExternalString variant:
+                    writer.WritePropertyName("string");
+                    JsonSerializer.Serialize(writer, variant.Value, options);
+                    break;
+                case ExternalNumber variant:
+                    writer.WritePropertyName("number");
+                    JsonSerializer.Serialize(writer, variant.Value, options);
+                    break;
+            }
+            writer.WriteEndObject();
+        }
+    }
+}
+
+public record ExternalString(string Value) : External;
+public record ExternalNumber(double Value) : External;
+
+public record Point
+{
+    [JsonPropertyName("x")]
+    public required double X { get; init; }
+
+    [JsonPropertyName("y")]
+    public required double Y { get; init; }
+}
+
+[JsonConverter(typeof(Untagged.Converter))]
+public abstract record Untagged
+{
+    public class Converter : JsonConverter<Untagged>
+    {
+        public override Untagged Read(ref Utf8JsonReader reader, Type typeToConvert, JsonSerializerOptions options)
+        {
+            var element = JsonElement.ParseValue(ref reader);
+            try { return new UntaggedPoint(element.Deserialize<Point>(options)!); } catch (JsonException) { }
+            try { return new UntaggedStringArray(element.Deserialize<List<string>>(options)!); } catch (JsonException) { }
+            try { return new UntaggedNumber(element.Deserialize<double>(options)!); } catch (JsonException) { }
+            throw new JsonException("Data doesn't match any variant");
+        }
+
+        public override void Write(Utf8JsonWriter writer, Untagged value, JsonSerializerOptions options)
+        {
+            switch (value)
+            {
+                case UntaggedPoint variant:
+                    JsonSerializer.Serialize(writer, variant.Value, options);
+                    break;
+                case UntaggedStringArray variant:
+                    JsonSerializer.Serialize(writer, variant.Value, options);
+                    break;
+                case UntaggedNumber variant:
+                    JsonSerializer.Serialize(writer, variant.Value, options);
+                    break;
+            }
+        }
+    }
+}
+
+public record UntaggedPoint(Point Value) : Untagged;
+public record UntaggedStringArray(List<string> Value) : Untagged;
+public record UntaggedNumber(double Value) : Untagged;
+
+[JsonConverter(typeof(JsonStringEnumConverter<Status>))]
+public enum Status
+{
+    [JsonStringEnumMemberName("active")]
+    Active,
+    [JsonStringEnumMemberName("in-progress")]
+    InProgress,
+}
+
@@ -0,0 +1,268 @@
+package models
+
+import (
+    "encoding/json"
+    "fmt"
+)
+
+type Internal struct {
+    Value isInternal
+}
+
+type isInternal interface {
+    isInternal()
+}
+
+type InternalHello struct {
+    Val float64 `json:"val"`
+}
+
+func (InternalHello) isInternal() {}
+
+type InternalEmpty struct{}
+
+func (InternalEmpty) isInternal() {}
+
+type InternalMaybeAString struct {
+    Val *string `json:"val,omitempty"`
+}
+
+func (InternalMaybeAString) isInternal() {}
+
+func (u Internal) MarshalJSON() ([]byte, error) {
+    switch value := u.Value.(type) {
+    case InternalHello:
+        return json.Marshal(struct {
+            Tag string `json:"type"`
+            InternalHello
+        }{"hello", value})
+    case InternalEmpty:
+        return json.Marshal(struct {
+            Tag string `json:"type"`
+            InternalEmpty
+        }{"empty", value})
+    case InternalMaybeAString:
+        return json.Marshal(struct {
+            Tag string `json:"type"`
+            InternalMaybeAString
+        }{"maybe_a_string", value})
+    default:
+        return nil, fmt.Errorf("unexpected Internal variant %T", u.Value)
+    }
+}
+
+func (u *Internal) UnmarshalJSON(data []byte) error {
+    var tagged struct {
+        Tag string `json:"type"`
+    }
+    if err := json.Unmarshal(data, &tagged); err != nil {
+        return err
+    }
+    var err error
+    switch tagged.Tag {
+    case "hello":
+        var value InternalHello
+        err = json.Unmarshal(data, &value)
+        u.Value = value
+    case "empty":
+        var value InternalEmpty
+        err = json.Unmarshal(data, &value)
+        u.Value = value
+    case "maybe_a_string":
+        var value InternalMaybeAString
+        err = json.Unmarshal(data, &value)
+        u.Value = value
+    default:
+        return fmt.Errorf("unknown Internal tag %q", tagged.Tag)
+    }
+    return err
+}
+
+type Adjacent struct {
+    Value isAdjacent
+}
+
+type isAdjacent interface {
+    isAdjacent()
+}
+
+type AdjacentNum struct {
+    Value float64
+}
+
+func (AdjacentNum) isAdjacent() {}
+
+type AdjacentStr struct {
+    Value *string
+}
+
+func (AdjacentStr) isAdjacent() {}
+
+func (u Adjacent) MarshalJSON() ([]byte, error) {
+    switch value := u.Value.(type) {
+    case AdjacentNum:
+        return json.Marshal(struct {
+            Tag string `json:"kind"`
+            Data float64 `json:"data"`
+        }{"num", value.Value})
+    case AdjacentStr:
+        return json.Marshal(struct {
+            Tag string `json:"kind"`
+            Data *string `json:"data,omitempty"`
+        }{"str", value.Value})
+    default:
+        return nil, fmt.Errorf("unexpected Adjacent variant %T", u.Value)
+    }
+}
+
+func (u *Adjacent) UnmarshalJSON(data []byte) error {
+    var tagged struct {
+        Tag string `json:"kind"`
+        Data json.RawMessage `json:"data"`
+    }
+    if err := json.Unmarshal(data, &tagged); err != nil {
+        return err
+    }
+    var err error
+    switch tagged.Tag {
+    case "num":
+        var value AdjacentNum
+        err = json.Unmarshal(tagged.Data, &value.Value)
+        u.Value = value
+    case "str":
+        var value AdjacentStr
+        if tagged.Data != nil {
+            err = json.Unmarshal(tagged.Data, &value.Value)
+        }
+        u.Value = value
+    default:
+        return fmt.Errorf("unknown Adjacent tag %q", tagged.Tag)
+    }
+    return err
+}
+
+type External struct {
+    Value isExternal
+}
+
+type isExternal interface {
+    isExternal()
+}
+
+type ExternalString struct {
+    Value string
+}
+
+func (ExternalString) isExternal() {}
+
+type ExternalNumber struct {
+    Value float64
+}
+
+func (ExternalNumber) isExternal() {}
+
+func (u External) MarshalJSON() ([]byte, error) {
+    switch value := u.Value.(type) {
+    case ExternalString:
+        return json.Marshal(map[string]any{"string": value.Value})
+    case ExternalNumber:
+        return json.Marshal(map[string]any{"number": value.Value})
+    default:
+        return nil, fmt.Errorf("unexpected External variant %T", u.Value)
+    }
+}
+
+func (u *External) UnmarshalJSON(data []byte) error {
+    var tagged map[string]json.RawMessage
+    if err := json.Unmarshal(data, &tagged); err != nil {
+        return err
+    }
+    if len(tagged) != 1 {
+        return fmt.Errorf("expected exactly one key in External, got %d", len(tagged))
+    }
+    for tag, content := range tagged {
+        var err error
+        switch tag {
+        case "string":
+            var value ExternalString
+            err = json.Unmarshal(content, &value.Value)
+            u.Value = value
+        case "number":
+            var value ExternalNumber
+            err = json.Unmarshal(content, &value.Value)
+            u.Value = value
+        default:
+            return fmt.Errorf("unknown External tag %q", tag)
+        }
+        return err
+    }
+    return nil
+}
+
+type Point struct {
+    X float64 `json:"x"`
+    Y float64 `json:"y"`
+}
+
+type Untagged struct {
+    Value isUntagged
+}
+
+type isUntagged interface {
+    isUntagged()
+}
+
+type UntaggedPoint struct {
+    Value Point
+}
+
+func (UntaggedPoint) isUntagged() {}
+
+type UntaggedStringArray struct {
+    Value []string
+}
+
+func (UntaggedStringArray) isUntagged() {}
+
+type UntaggedNumber struct {
+    Value float64
+}
+
+func (UntaggedNumber) isUntagged() {}
+
+func (u Untagged) MarshalJSON() ([]byte, error) {
+    switch value := u.Value.(type) {
+    case UntaggedPoint:
+        return json.Marshal(value.Value)
+    case UntaggedStringArray:
+        return json.Marshal(value.Value)
+    case UntaggedNumber:
+        return json.Marshal(value.Value)
+    default:
+        return nil, fmt.Errorf("unexpected Untagged variant %T", u.Value)
+    }
+}
+
+func (u *Untagged) UnmarshalJSON(data []byte) error {
+    if value := new(UntaggedPoint); json.Unmarshal(data, &value.Value) == nil {
+        u.Value = *value
+        return nil
+    }
+    if value := new(UntaggedStringArray); json.Unmarshal(data, &value.Value) == nil {
+        u.Value = *value
+        return nil
+    }
+    if value := new(UntaggedNumber); json.Unmarshal(data, &value.Value) == nil {
+        u.Value = *value
+        return nil
+    }
+    return fmt.Errorf("data doesn't match any Untagged variant")
+}
+
+type Status string
+
+const (
+    StatusActive Status = "active"
+    StatusInProgress Status = "in-progress"
+)
+
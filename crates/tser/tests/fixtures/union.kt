@@ -0,0 +1,141 @@
+@file:OptIn(kotlinx.serialization.ExperimentalSerializationApi::class)
+
+import kotlinx.serialization.*
+import kotlinx.serialization.descriptors.*
+import kotlinx.serialization.encoding.*
+import kotlinx.serialization.json.*
+
+@Serializable
+@JsonClassDiscriminator("type")
+sealed interface Internal
+
+@Serializable
+@SerialName("hello")
+data class InternalHello(
+    val `val`: Double,
+) : Internal
+
+@Serializable
+@SerialName("empty")
+data object InternalEmpty : Internal
+
+@Serializable
+@SerialName("maybe_a_string")
+data class InternalMaybeAString(
+    val `val`: String? = null,
+) : Internal
+
+@Serializable(with = Adjacent.Serializer::class)
+sealed interface Adjacent {
+    object Serializer : KSerializer<Adjacent> {
+        override val descriptor = buildClassSerialDescriptor("Adjacent")
+
+        override fun serialize(encoder: Encoder, value: Adjacent) {
+            val output = encoder as JsonEncoder
+            val element = buildJsonObject {
+                when (value) {
+                    is AdjacentNum -> {
+                        put("kind", "num")
+                        put("data", output.json.encodeToJsonElement(value.value))
+                    }
+                    is AdjacentStr -> {
+                        put("kind", "str")
+                        value.value?.let { put("data", output.json.encodeToJsonElement(it)) }
+                    }
+                }
+            }
+            output.encodeJsonElement(element)
+        }
+
+        override fun deserialize(decoder: Decoder): Adjacent {
+            val input = decoder as JsonDecoder
+            val element = input.decodeJsonElement().jsonObject
+            val data = element["data"] ?: JsonNull
+            return when (val tag = element["kind"]?.jsonPrimitive?.contentOrNull) {
+                "num" -> AdjacentNum(input.json.decodeFromJsonElement(data))
+                "str" -> AdjacentStr(input.json.decodeFromJsonElement(data))
+                else -> throw SerializationException("Unknown tag $tag")
+            }
+        }
+    }
+}
+
+data class AdjacentNum(val value: Double) : Adjacent
+data class AdjacentStr(val value: String?) : Adjacent
+
+@Serializable(with = External.Serializer::class)
+sealed interface External {
+    object Serializer : KSerializer<External> {
+        override val descriptor = buildClassSerialDescriptor("External")
+
+        override fun serialize(encoder: Encoder, value: External) {
+            val output = encoder as JsonEncoder
+            val element = when (value) {
+                is ExternalString -> buildJsonObject {
+                    put("string", output.json.encodeToJsonElement(value.value))
+                }
+                is ExternalNumber -> buildJsonObject {
+                    put("number", output.json.encodeToJsonElement(value.value))
+                }
+            }
+            output.encodeJsonElement(element)
+        }
+
+        override fun deserialize(decoder: Decoder): External {
+            val input = decoder as JsonDecoder
+            val (tag, element) = input.decodeJsonElement().jsonObject.entries.singleOrNull()
+                ?: throw SerializationException("Expected exactly one key")
+            return when (tag) {
+                "string" -> ExternalString(input.json.decodeFromJsonElement(element))
+                "number" -> ExternalNumber(input.json.decodeFromJsonElement(element))
+                else -> throw SerializationException("Unknown tag $tag")
+            }
+        }
+    }
+}
+
+data class ExternalString(val value: String) : External
+data class ExternalNumber(val value: Double) : External
+
+@Serializable
+data class Point(
+    val x: Double,
+    val y: Double,
+)
+
+@Serializable(with = Untagged.Serializer::class)
+sealed interface Untagged {
+    object Serializer : KSerializer<Untagged> {
+        override val descriptor = buildClassSerialDescriptor("Untagged")
+
+        override fun serialize(encoder: Encoder, value: Untagged) {
+            val output = encoder as JsonEncoder
+            val element = when (value) {
+                is UntaggedPoint -> output.json.encodeToJsonElement(value.value)
+                is UntaggedStringArray -> output.json.encodeToJsonElement(value.value)
+                is UntaggedNumber -> output.json.encodeToJsonElement(value.value)
+            }
+            output.encodeJsonElement(element)
+        }
+
+        override fun deserialize(decoder: Decoder): Untagged {
+            val input = decoder as JsonDecoder
+            val element = input.decodeJsonElement()
+            return runCatching<Untagged> { UntaggedPoint(input.json.decodeFromJsonElement(element)) }
+                .recoverCatching { UntaggedStringArray(input.json.decodeFromJsonElement(element)) }
+                .recoverCatching { UntaggedNumber(input.json.decodeFromJsonElement(element)) }
+                .getOrElse { throw SerializationException("Data doesn't match any variant") }
+        }
+    }
+}
+
+data class UntaggedPoint(val value: Point) : Untagged
+data class UntaggedStringArray(val value: List<String>) : Untagged
+data class UntaggedNumber(val value: Double) : Untagged
+
+@Serializable
+enum class Status {
+    @SerialName("active") Active,
+    @SerialName("in-progress") InProgress,
+}
+
@@ -0,0 +1,247 @@
+package models;
+
+import com.fasterxml.jackson.annotation.*;
+import com.fasterxml.jackson.core.*;
+import com.fasterxml.jackson.core.type.TypeReference;
+import com.fasterxml.jackson.databind.*;
+import com.fasterxml.jackson.databind.annotation.*;
+import com.fasterxml.jackson.databind.deser.std.StdDeserializer;
+import com.fasterxml.jackson.databind.node.NullNode;
+import com.fasterxml.jackson.databind.ser.std.StdSerializer;
+import java.io.IOException;
+import java.math.BigInteger;
+import java.util.*;
+
+public final class Models {
+    private Models() {
+    }
+
+    private static <T> T read(DeserializationContext context, JsonNode node, TypeReference<T> type) throws IOException {
+        JavaType javaType = context.getTypeFactory().constructType(type);
+        return context.readTreeAsValue(node == null ? NullNode.getInstance() : node, javaType);
+    }
+
+    @JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = "type")
+    @JsonSubTypes({
+        @JsonSubTypes.Type(value = InternalHello.class, name = "hello"),
+        @JsonSubTypes.Type(value = InternalEmpty.class, name = "empty"),
+        @JsonSubTypes.Type(value = InternalMaybeAString.class, name = "maybe_a_string"),
+    })
+    public sealed interface Internal permits InternalHello, InternalEmpty, InternalMaybeAString {}
+
+    public static final class InternalHello implements Internal {
+        @JsonProperty(value = "val", required = true)
+        private double val;
+
+        public double getVal() {
+            return val;
+        }
+
+        public void setVal(double val) {
+            this.val = val;
+        }
+    }
+
+    public static final class InternalEmpty implements Internal {
+    }
+
+    public static final class InternalMaybeAString implements Internal {
+        @JsonProperty("val")
+        @JsonInclude(JsonInclude.Include.NON_NULL)
+        private String val;
+
+        public String getVal() {
+            return val;
+        }
+
+        public void setVal(String val) {
+            this.val = val;
+        }
+    }
+
+    @JsonSerialize(using = Adjacent.Serializer.class)
+    @JsonDeserialize(using = Adjacent.Deserializer.class)
+    public sealed interface Adjacent permits AdjacentNum, AdjacentStr {
+        final class Serializer extends StdSerializer<Adjacent> {
+            public Serializer() {
+                super(Adjacent.class);
+            }
+
+            @Override
+            public void serialize(Adjacent value, JsonGenerator generator, SerializerProvider provider) throws IOException {
+                generator.writeStartObject();
+                if (value instanceof AdjacentNum variant) {
+                    generator.writeStringField("kind", "num");
+                    provider.defaultSerializeField("data", variant.value(), generator);
+                } else if (value instanceof AdjacentStr variant) {
+                    generator.writeStringField("kind", "str");
+                    if (variant.value() != null) {
+                        provider.defaultSerializeField("data", variant.value(), generator);
+                    }
+                }
+                generator.writeEndObject();
+            }
+        }
+
+        final class Deserializer extends StdDeserializer<Adjacent> {
+            public Deserializer() {
+                super(Adjacent.class);
+            }
+
+            @Override
+            public Adjacent deserialize(JsonParser parser, DeserializationContext context) throws IOException {
+                JsonNode node = parser.readValueAsTree();
+                String tag = node.path("kind").asText();
+                JsonNode data = node.get("data");
+                return switch (tag) {
+                    case "num" -> new AdjacentNum(read(context, data, new TypeReference<Double>() {}));
+                    case "str" -> new AdjacentStr(read(context, data, new TypeReference<String>() {}));
+                    default -> throw JsonMappingException.from(parser, "Unknown tag " + tag);
+                };
+            }
+        }
+    }
+
+    public record AdjacentNum(double value) implements Adjacent {}
+    public record AdjacentStr(String value) implements Adjacent {}
+
+    @JsonSerialize(using = External.Serializer.class)
+    @JsonDeserialize(using = External.Deserializer.class)
+    public sealed interface External permits ExternalString, ExternalNumber {
+        final class Serializer extends StdSerializer<External> {
+            public Serializer() {
+                super(External.class);
+            }
+
+            @Override
+            public void serialize(External value, JsonGenerator generator, SerializerProvider provider) throws IOException {
+                generator.writeStartObject();
+                if (value instanceof ExternalString variant) {
+                    provider.defaultSerializeField("string", variant.value(), generator);
+                } else if (value instanceof ExternalNumber variant) {
+                    provider.defaultSerializeField("number", variant.value(), generator);
+                }
+                generator.writeEndObject();
+            }
+        }
+
+        final class Deserializer extends StdDeserializer<External> {
+            public Deserializer() {
+                super(External.class);
+            }
+
+            @Override
+            public External deserialize(JsonParser parser, DeserializationContext context) throws IOException {
+                JsonNode node = parser.readValueAsTree();
+                if (!node.isObject() || node.size() != 1) {
+                    throw JsonMappingException.from(parser, "Expected exactly one key");
+                }
+                Map.Entry<String, JsonNode> entry = node.fields().next();
+                return switch (entry.getKey()) {
+                    case "string" -> new ExternalString(read(context, entry.getValue(), new TypeReference<String>() {}));
+                    case "number" -> new ExternalNumber(read(context, entry.getValue(), new TypeReference<Double>() {}));
+                    default -> throw JsonMappingException.from(parser, "Unknown tag " + entry.getKey());
+                };
+            }
+        }
+    }
+
+    public record ExternalString(String value) implements External {}
+    public record ExternalNumber(double value) implements External {}
+
+    public static class Point {
+        @JsonProperty(value = "x", required = true)
+        private double x;
+
+        @JsonProperty(value = "y", required = true)
+        private double y;
+
+        public double getX() {
+            return x;
+        }
+
+        public void setX(double x) {
+            this.x = x;
+        }
+
+        public double getY() {
+            return y;
+        }
+
+        public void setY(double y) {
+            this.y = y;
+        }
+    }
+
+    @JsonSerialize(using = Untagged.Serializer.class)
+    @JsonDeserialize(using = Untagged.Deserializer.class)
+    public sealed interface Untagged permits UntaggedPoint, UntaggedStringArray, UntaggedNumber {
+        final class Serializer extends StdSerializer<Untagged> {
+            public Serializer() {
+                super(Untagged.class);
+            }
+
+            @Override
+            public void serialize(Untagged value, JsonGenerator generator, SerializerProvider provider) throws IOException {
+                if (value instanceof UntaggedPoint variant) {
+                    provider.defaultSerializeValue(variant.value(), generator);
+                } else if (value instanceof UntaggedStringArray variant) {
+                    provider.defaultSerializeValue(variant.value(), generator);
+                } else if (value instanceof UntaggedNumber variant) {
+                    provider.defaultSerializeValue(variant.value(), generator);
+                }
+            }
+        }
+
+        final class Deserializer extends StdDeserializer<Untagged> {
+            public Deserializer() {
+                super(Untagged.class);
+            }
+
+            @Override
+            public Untagged deserialize(JsonParser parser, DeserializationContext context) throws IOException {
+                JsonNode node = parser.readValueAsTree();
+                if (node.isObject()) {
+                    try {
+                        return new UntaggedPoint(read(context, node, new TypeReference<Point>() {}));
+                    } catch (JsonProcessingException ignored) {
+                    }
+                }
+                if (node.isArray()) {
+                    try {
+                        return new UntaggedStringArray(read(context, node, new TypeReference<List<String>>() {}));
+                    } catch (JsonProcessingException ignored) {
+                    }
+                }
+                if (node.isNumber()) {
+                    try {
+                        return new UntaggedNumber(read(context, node, new TypeReference<Double>() {}));
+                    } catch (JsonProcessingException ignored) {
+                    }
+                }
+                throw JsonMappingException.from(parser, "Data doesn't match any variant");
+            }
+        }
+    }
+
+    public record UntaggedPoint(Point value) implements Untagged {}
+    public record UntaggedStringArray(List<String> value) implements Untagged {}
+    public record UntaggedNumber(double value) implements Untagged {}
+
+    public enum Status {
+        ACTIVE("active"),
+        IN_PROGRESS("in-progress");
+
+        private final String value;
+
+        Status(String value) {
+            this.value = value;
+        }
+
+        @JsonValue
+        public String value() {
+            return value;
+        }
+    }
+}
+
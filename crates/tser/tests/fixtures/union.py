@@ -0,0 +1,49 @@
+# Requires pydantic>=2.12
+import enum
+from typing import Annotated, Dict, List, Literal, NewType, Optional, Tuple, Union
+
+from pydantic import BaseModel, ConfigDict, Field
+
+class InternalHello(BaseModel):
+    type: Literal["hello"] = "hello"
+    val: float
+
+class InternalEmpty(BaseModel):
+    type: Literal["empty"] = "empty"
+
+class InternalMaybeAString(BaseModel):
+    type: Literal["maybe_a_string"] = "maybe_a_string"
+    val: Optional[str] = Field(default=None, exclude_if=lambda value: value is None)
+
+Internal = Annotated[Union[InternalHello, InternalEmpty, InternalMaybeAString], Field(discriminator="type")]
+
+class AdjacentNum(BaseModel):
+    kind: Literal["num"] = "num"
+    data: float
+
+class AdjacentStr(BaseModel):
+    kind: Literal["str"] = "str"
+    data: Optional[str] = Field(default=None, exclude_if=lambda value: value is None)
+
+Adjacent = Annotated[Union[AdjacentNum, AdjacentStr], Field(discriminator="kind")]
+
+class ExternalString(BaseModel):
+    model_config = ConfigDict(extra="forbid")
+    string: str
+
+class ExternalNumber(BaseModel):
+    model_config = ConfigDict(extra="forbid")
+    number: float
+
+External = Union[ExternalString, ExternalNumber]
+
+class Point(BaseModel):
+    x: float
+    y: float
+
+Untagged = Annotated[Union[Point, List[str], float], Field(union_mode="left_to_right")]
+
+class Status(enum.Enum):
+    ACTIVE = "active"
+    IN_PROGRESS = "in-progress"
+
@@ -0,0 +1,51 @@
+use serde::{Serialize, Deserialize};
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(tag = "type")]
+pub enum Internal {
+    #[serde(rename = "hello")]
+    Hello {
+        val: f64,
+    },
+    #[serde(rename = "empty")]
+    Empty,
+    #[serde(rename = "maybe_a_string")]
+    MaybeAString {
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        val: Option<String>,
+    },
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(tag = "kind", content = "data")]
+pub enum Adjacent {
+    #[serde(rename = "num")] Num(f64),
+    #[serde(rename = "str")] Str(Option<String>),
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub enum External {
+    #[serde(rename = "string")] String(String),
+    #[serde(rename = "number")] Number(f64),
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub struct Point {
+    pub x: f64,
+    pub y: f64,
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+#[serde(untagged)]
+pub enum Untagged {
+    Point(Point),
+    StringArray(Vec<String>),
+    Number(f64),
+}
+
+#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]
+pub enum Status {
+    #[serde(rename = "active")] Active,
+    #[serde(rename = "in-progress")] InProgress,
+}
+
@@ -0,0 +1,149 @@
+public enum Internal: Codable, Equatable, Hashable {
+    case hello(val: Double)
+    case empty
+    case maybeAString(val: String?)
+
+    private enum CodingKeys: String, CodingKey {
+        case type = "type"
+        case val = "val"
+    }
+
+    public init(from decoder: Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        let tag = try container.decode(String.self, forKey: .type)
+        switch tag {
+        case "hello":
+            self = try .hello(val: container.decode(Double.self, forKey: .val))
+        case "empty":
+            self = .empty
+        case "maybe_a_string":
+            self = try .maybeAString(val: container.decodeIfPresent(String.self, forKey: .val))
+        default:
+            throw DecodingError.dataCorruptedError(forKey: .type, in: container, debugDescription: "Unknown tag \(tag)")
+        }
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.container(keyedBy: CodingKeys.self)
+        switch self {
+        case let .hello(val):
+            try container.encode("hello", forKey: .type)
+            try container.encode(val, forKey: .val)
+        case .empty:
+            try container.encode("empty", forKey: .type)
+        case let .maybeAString(val):
+            try container.encode("maybe_a_string", forKey: .type)
+            try container.encodeIfPresent(val, forKey: .val)
+        }
+    }
+}
+
+public enum Adjacent: Codable, Equatable, Hashable {
+    case num(Double)
+    case str(String?)
+
+    private enum CodingKeys: String, CodingKey {
+        case kind = "kind"
+        case data = "data"
+    }
+
+    public init(from decoder: Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        let tag = try container.decode(String.self, forKey: .kind)
+        switch tag {
+        case "num":
+            self = try .num(container.decode(Double.self, forKey: .data))
+        case "str":
+            self = try .str(container.decodeIfPresent(String.self, forKey: .data))
+        default:
+            throw DecodingError.dataCorruptedError(forKey: .kind, in: container, debugDescription: "Unknown tag \(tag)")
+        }
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.container(keyedBy: CodingKeys.self)
+        switch self {
+        case let .num(value):
+            try container.encode("num", forKey: .kind)
+            try container.encode(value, forKey: .data)
+        case let .str(value):
+            try container.encode("str", forKey: .kind)
+            try container.encodeIfPresent(value, forKey: .data)
+        }
+    }
+}
+
+public enum External: Codable, Equatable, Hashable {
+    case string(String)
+    case number(Double)
+
+    private enum CodingKeys: String, CodingKey {
+        case string = "string"
+        case number = "number"
+    }
+
+    public init(from decoder: Decoder) throws {
+        let container = try decoder.container(keyedBy: CodingKeys.self)
+        guard container.allKeys.count == 1 else {
+            throw DecodingError.dataCorrupted(DecodingError.Context(codingPath: container.codingPath, debugDescription: "Expected exactly one key"))
+        }
+        switch container.allKeys[0] {
+        case .string:
+            self = try .string(container.decode(String.self, forKey: .string))
+        case .number:
+            self = try .number(container.decode(Double.self, forKey: .number))
+        }
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.container(keyedBy: CodingKeys.self)
+        switch self {
+        case let .string(value):
+            try container.encode(value, forKey: .string)
+        case let .number(value):
+            try container.encode(value, forKey: .number)
+        }
+    }
+}
+
+public struct Point: Codable, Equatable, Hashable {
+    public var x: Double
+    public var y: Double
+}
+
+public enum Untagged: Codable, Equatable, Hashable {
+    case point(Point)
+    case stringArray([String])
+    case number(Double)
+
+    public init(from decoder: Decoder) throws {
+        let container = try decoder.singleValueContainer()
+        if let value = try? container.decode(Point.self) {
+            self = .point(value)
+        } else if let value = try? container.decode([String].self) {
+            self = .stringArray(value)
+        } else if let value = try? container.decode(Double.self) {
+            self = .number(value)
+        } else {
+            throw DecodingError.dataCorruptedError(in: container, debugDescription: "Data doesn't match any variant")
+        }
+    }
+
+    public func encode(to encoder: Encoder) throws {
+        var container = encoder.singleValueContainer()
+        switch self {
+        case let .point(value):
+            try container.encode(value)
+        case let .stringArray(value):
+            try container.encode(value)
+        case let .number(value):
+            try container.encode(value)
+        }
+    }
+}
+
+public enum Status: String, Codable, Equatable, Hashable {
+    case active = "active"
+    case in_progress = "in-progress"
+}
+
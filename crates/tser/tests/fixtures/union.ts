@@ -0,0 +1,8 @@
+type Internal = { type: 'hello', val: number } |
+    { type: 'empty' } |
+    { type: 'maybe_a_string', val?: string }
+type Adjacent = { kind: 'num', data: number } | { kind: 'str', data?: string }
+type External = { string: string } | { number: number }
+interface Point { x: number, y: number }
+type Untagged = Point | string[] | number
+type Status = 'active' | 'in-progress'
@@ -0,0 +1,385 @@
+//! Compiles the generated Rust code and checks it against the JSON shapes the TS side produces.
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::{json, Value};
+use std::fmt::Debug;
+
+mod union {
+    include!("fixtures/union.rs");
+}
+
+mod generic {
+    include!("fixtures/generic.rs");
+}
+
+#[allow(dead_code)]
+mod inheritance {
+    include!("fixtures/inheritance.rs");
+}
+
+mod map {
+    include!("fixtures/map.rs");
+}
+
+mod tuple {
+    include!("fixtures/tuple.rs");
+}
+
+#[allow(dead_code)]
+mod doc {
+    include!("fixtures/doc.rs");
+}
+
+mod alias {
+    include!("fixtures/alias.rs");
+}
+
+#[allow(dead_code)]
+mod intersection {
+    include!("fixtures/intersection.rs");
+}
+
+mod number {
+    include!("fixtures/number.rs");
+}
+
+mod rename {
+    include!("fixtures/rename.rs");
+}
+
+mod naming {
+    include!("fixtures/naming.rs");
+}
+
+mod inline {
+    include!("fixtures/inline.rs");
+}
+
+#[allow(dead_code)]
+mod service {
+    include!("fixtures/service.rs");
+}
+
+#[allow(dead_code)]
+mod project {
+    include!("fixtures/project.rs");
+}
+
+fn assert_round_trip<T: Serialize + DeserializeOwned + PartialEq + Debug>(value: T, json: Value) {
+    assert_eq!(serde_json::to_value(&value).unwrap(), json);
+    assert_eq!(serde_json::from_value::<T>(json).unwrap(), value);
+}
+
+#[test]
+fn internally_tagged() {
+    use union::Internal;
+    assert_round_trip(
+        Internal::Hello { val: 1.5 },
+        json!({ "type": "hello", "val": 1.5 }),
+    );
+    assert_round_trip(Internal::Empty, json!({ "type": "empty" }));
+    assert_round_trip(
+        Internal::MaybeAString {
+            val: Some("a".to_string()),
+        },
+        json!({ "type": "maybe_a_string", "val": "a" }),
+    );
+    assert_round_trip(
+        Internal::MaybeAString { val: None },
+        json!({ "type": "maybe_a_string" }),
+    );
+}
+
+#[test]
+fn adjacently_tagged() {
+    use union::Adjacent;
+    assert_round_trip(Adjacent::Num(2.0), json!({ "kind": "num", "data": 2.0 }));
+    assert_round_trip(
+        Adjacent::Str(Some("a".to_string())),
+        json!({ "kind": "str", "data": "a" }),
+    );
+    assert_eq!(
+        serde_json::from_value::<Adjacent>(json!({ "kind": "str" })).unwrap(),
+        Adjacent::Str(None)
+    );
+}
+
+#[test]
+fn externally_tagged() {
+    use union::External;
+    assert_round_trip(External::String("a".to_string()), json!({ "string": "a" }));
+    assert_round_trip(External::Number(1.0), json!({ "number": 1.0 }));
+}
+
+#[test]
+fn untagged() {
+    use union::{Point, Untagged};
+    assert_round_trip(
+        Untagged::Point(Point { x: 1.0, y: 2.0 }),
+        json!({ "x": 1.0, "y": 2.0 }),
+    );
+    assert_round_trip(Untagged::StringArray(vec!["a".to_string()]), json!(["a"]));
+    assert_round_trip(Untagged::Number(3.0), json!(3.0));
+}
+
+#[test]
+fn string_literal_union() {
+    use union::Status;
+    assert_round_trip(Status::Active, json!("active"));
+    assert_round_trip(Status::InProgress, json!("in-progress"));
+}
+
+#[test]
+fn generic() {
+    use generic::{Outcome, Page, Response, User};
+    assert_round_trip(
+        Response {
+            users: Page {
+                items: vec![User {
+                    name: "a".to_string(),
+                }],
+                next: None,
+            },
+            outcome: Outcome::Ok(vec![1.0]),
+        },
+        json!({ "users": { "items": [{ "name": "a" }] }, "outcome": { "ok": [1.0] } }),
+    );
+}
+
+#[test]
+fn inheritance() {
+    use inheritance::User;
+    assert_round_trip(
+        User {
+            id: "1".to_string(),
+            name: "a".to_string(),
+            created: 0.0,
+            items: vec![],
+            email: None,
+        },
+        json!({ "id": "1", "name": "a", "created": 0.0, "items": [] }),
+    );
+}
+
+#[test]
+fn map() {
+    use map::{Inventory, User};
+    assert_round_trip(
+        Inventory {
+            counts: [("apple".to_string(), 2.0)].into(),
+            tags: [("apple".to_string(), vec!["fruit".to_string()])].into(),
+            owners: Some(
+                [
+                    (
+                        "apple".to_string(),
+                        Some(User {
+                            name: "a".to_string(),
+                        }),
+                    ),
+                    ("pear".to_string(), None),
+                ]
+                .into(),
+            ),
+        },
+        json!({
+            "counts": { "apple": 2.0 },
+            "tags": { "apple": ["fruit"] },
+            "owners": { "apple": { "name": "a" }, "pear": null },
+        }),
+    );
+}
+
+#[test]
+fn tuple() {
+    use tuple::Polygon;
+    assert_round_trip(
+        Polygon {
+            points: vec![(0.0, 1.0)],
+            label: ("a".to_string(), None),
+            anchor: Some((2.0, 3.0)),
+        },
+        json!({ "points": [[0.0, 1.0]], "label": ["a", null], "anchor": [2.0, 3.0] }),
+    );
+}
+
+#[test]
+fn alias() {
+    use alias::{User, UserId};
+    assert_round_trip(
+        User {
+            id: UserId("1".to_string()),
+            tags: vec!["admin".to_string()],
+            friends: Some(vec![UserId("2".to_string())]),
+        },
+        json!({ "id": "1", "tags": ["admin"], "friends": ["2"] }),
+    );
+}
+
+#[test]
+fn intersection() {
+    use intersection::Post;
+    assert_round_trip(
+        Post {
+            created_at: "2020-01-01".to_string(),
+            updated_at: None,
+            owner: "a".to_string(),
+            body: "hi".to_string(),
+        },
+        json!({ "createdAt": "2020-01-01", "owner": "a", "body": "hi" }),
+    );
+}
+
+#[test]
+fn number() {
+    use number::Stats;
+    assert_round_trip(
+        Stats {
+            id: -1,
+            count: 9_007_199_254_740_991,
+            level: 255,
+            ratio: 0.5,
+            timestamps: vec![1_600_000_000_000],
+            port: 8080,
+            average: 1.5,
+        },
+        json!({
+            "id": -1,
+            "count": 9_007_199_254_740_991_i64,
+            "level": 255,
+            "ratio": 0.5,
+            "timestamps": [1_600_000_000_000_u64],
+            "port": 8080,
+            "average": 1.5,
+        }),
+    );
+}
+
+#[test]
+fn rename() {
+    use rename::{Body, Event, Headers, Payload, Payment, Shape};
+    assert_round_trip(
+        Headers {
+            content_type: "text/plain".to_string(),
+            x_request_id: Some("1".to_string()),
+            _2fa_enabled: true,
+            accept: "*/*".to_string(),
+        },
+        json!({
+            "content-type": "text/plain",
+            "x-request-id": "1",
+            "2fa_enabled": true,
+            "accept": "*/*",
+        }),
+    );
+    assert_round_trip(
+        Event::Click { client_x: 1.0 },
+        json!({ "kind": "click", "client-x": 1.0 }),
+    );
+    assert_round_trip(
+        Shape::Square { side_length: 2.0 },
+        json!({ "the-kind": "square", "side-length": 2.0 }),
+    );
+    assert_round_trip(
+        Payload::Text("a".to_string()),
+        json!({ "the-kind": "text", "my-data": "a" }),
+    );
+    assert_round_trip(
+        Payment::BankTransfer {
+            iban: "DE00".to_string(),
+            x: 1.0,
+        },
+        json!({ "method": "bank-transfer", "iban": "DE00", "x": 1.0 }),
+    );
+    assert_round_trip(
+        Body::ContentType("text/plain".to_string()),
+        json!({ "content-type": "text/plain" }),
+    );
+}
+
+#[test]
+fn naming() {
+    use naming::{Account, Role};
+    assert_round_trip(
+        Account {
+            account_id: "a".to_string(),
+            user_id: "u".to_string(),
+            r#type: "admin".to_string(),
+            self_: true,
+            oauth2_token: Some("t".to_string()),
+            display_name: "Ann".to_string(),
+        },
+        json!({
+            "accountId": "a",
+            "userID": "u",
+            "type": "admin",
+            "self": true,
+            "oauth2Token": "t",
+            "display_name": "Ann",
+        }),
+    );
+    assert_round_trip(Role::ReadOnly, json!("readOnly"));
+}
+
+#[test]
+fn inline() {
+    use inline::{Money, Order, OrderLines, OrderShippingAddress, Payment, PaymentCardCard};
+    assert_round_trip(
+        Order {
+            id: "1".to_string(),
+            shipping_address: OrderShippingAddress {
+                street: "Main St".to_string(),
+                geo: None,
+            },
+            lines: vec![OrderLines {
+                sku: "A1".to_string(),
+                quantity: 2.0,
+            }],
+            total: Money {
+                amount: 9.5,
+                currency: "EUR".to_string(),
+            },
+        },
+        json!({
+            "id": "1",
+            "shippingAddress": { "street": "Main St", "geo": null },
+            "lines": [{ "sku": "A1", "quantity": 2.0 }],
+            "total": { "amount": 9.5, "currency": "EUR" },
+        }),
+    );
+    assert_round_trip(
+        Payment::Card {
+            card: PaymentCardCard {
+                last4: "4242".to_string(),
+            },
+        },
+        json!({ "method": "card", "card": { "last4": "4242" } }),
+    );
+}
+
+#[test]
+fn service() {
+    use service::{UserServiceMethod, UserServiceSearchRequest};
+    assert_round_trip(
+        UserServiceSearchRequest {
+            query: "ann".to_string(),
+            limit: None,
+        },
+        json!({ "query": "ann" }),
+    );
+    let names = UserServiceMethod::ALL.map(UserServiceMethod::name);
+    assert_eq!(names, ["getUser", "search", "watch", "ping"]);
+}
+
+#[test]
+fn project() {
+    use project::{Admin, Role};
+    assert_round_trip(
+        Admin {
+            id: "1".to_string(),
+            name: "Ann".to_string(),
+            role: Role::Owner,
+        },
+        json!({ "id": "1", "name": "Ann", "role": "owner" }),
+    );
+}
@@ -15,7 +15,7 @@ impl BlockModifier for String {
         target.children.push(BlockChild::Line(self))
     }
 }
-impl<'a> BlockModifier for &'a str {
+impl BlockModifier for &str {
     fn modify_block(self, target: &mut Block) {
         target.children.push(BlockChild::Line(self.to_string()))
     }
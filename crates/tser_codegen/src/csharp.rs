@@ -0,0 +1,718 @@
+use crate::{
+    convert_names, line_doc_comment, quote, variant_type_names, Alias, CodeGen, Enum,
+    EnumValueType, Field, InternallyTaggedUnionBody, Service, Struct, Tuple, Union, UnionKind,
+    Variant,
+};
+use convert_case::Case;
+use tser_block::{block, flatten, Block, BlockModifier};
+use tser_ir::type_expr::primitive::Primitive;
+
+/// Generates records for `System.Text.Json`, targeting .NET 9 with nullable reference types.
+/// Internally tagged unions are polymorphic records, which expect the tag to come first unless
+/// `AllowOutOfOrderMetadataProperties` is set. Other unions have a converter written out.
+pub struct CSharpCodeGen {
+    pub namespace: String,
+}
+
+impl Default for CSharpCodeGen {
+    fn default() -> Self {
+        Self {
+            namespace: "Models".to_string(),
+        }
+    }
+}
+
+fn ident(id: &str) -> String {
+    if KEYWORDS.contains(&id) {
+        format!("@{id}")
+    } else {
+        id.to_string()
+    }
+}
+fn doc_comment(doc: Option<&str>) -> impl BlockModifier {
+    // Doc comments are XML
+    let doc = doc.map(|doc| {
+        doc.replace('&', "&amp;")
+            .replace('<', "&lt;")
+            .replace('>', "&gt;")
+    });
+    let lines = doc.as_deref().map(|doc| doc.lines().collect::<Vec<_>>());
+    match lines.as_deref() {
+        None | Some([]) => flatten![],
+        Some([line]) => flatten![format!("/// <summary>{line}</summary>")],
+        Some(_) => flatten![
+            "/// <summary>",
+            line_doc_comment(doc.as_deref(), "///"),
+            "/// </summary>",
+        ],
+    }
+}
+
+fn optional_field_type(ty: &str) -> String {
+    // A nullable type is already nullable, don't add another `?`
+    if ty.ends_with('?') {
+        ty.to_string()
+    } else {
+        format!("{ty}?")
+    }
+}
+
+/// Properties are PascalCase, with the name on the wire given by `[JsonPropertyName]`. Properties
+/// that aren't optional are `required`, so they have to be present even when nullable, and
+/// optional ones are left out when null.
+fn properties(class_name: &str, fields: &[Field]) -> Block {
+    // A member can't be named like the type declaring it
+    let names = convert_names(
+        std::iter::once(class_name).chain(fields.iter().map(|field| field.name.as_str())),
+        Case::Pascal,
+    );
+    let properties = fields.iter().zip(&names[1..]).map(|(field, name)| {
+        let declaration = if field.optional {
+            flatten![
+                "[JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]",
+                format!(
+                    "public {} {} {{ get; init; }}",
+                    optional_field_type(&field.ty),
+                    ident(name)
+                ),
+            ]
+        } else {
+            flatten![format!(
+                "public required {} {} {{ get; init; }}",
+                field.ty,
+                ident(name)
+            )]
+        };
+        flatten![
+            doc_comment(field.doc.as_deref()),
+            format!("[JsonPropertyName({})]", quote(&field.wire_name)),
+            declaration,
+        ]
+    });
+    let mut property_blocks = vec![];
+    for (idx, property) in properties.enumerate() {
+        if idx > 0 {
+            property_blocks.push(flatten![""]);
+        }
+        property_blocks.push(property);
+    }
+    block![flatten(property_blocks)]
+}
+
+/// `base` is the abstract record of a union variant. Records without properties have no body.
+fn record(name: &str, fields: &[Field], base: Option<&str>) -> Block {
+    let base = base.map(|base| format!(" : {base}")).unwrap_or_default();
+    if fields.is_empty() {
+        return block![format!("public record {name}{base};")];
+    }
+    block![
+        format!("public record {name}{base}"),
+        "{",
+        properties(name, fields),
+        "}",
+    ]
+}
+
+fn single_value_record(name: &str, ty: &str, base: &str) -> String {
+    format!("public record {name}({ty} Value) : {base};")
+}
+
+/// A converter nested in the type it converts, going through `JsonElement`s to read
+fn converter(name: &str, read: Block, write: Block) -> Block {
+    block![
+        format!("public class Converter : JsonConverter<{name}>"),
+        "{",
+        block![
+            format!(
+                "public override {name} Read(ref Utf8JsonReader reader, Type typeToConvert, JsonSerializerOptions options)"
+            ),
+            "{",
+            block!["var element = JsonElement.ParseValue(ref reader);", flatten(read)],
+            "}",
+            "",
+            format!(
+                "public override void Write(Utf8JsonWriter writer, {name} value, JsonSerializerOptions options)"
+            ),
+            "{",
+            write,
+            "}",
+        ],
+        "}",
+    ]
+}
+
+fn deserialize(element: &str, ty: &str) -> String {
+    // Deserializing returns null for a JSON null, which the type has to allow
+    if ty.ends_with('?') {
+        format!("{element}.Deserialize<{ty}>(options)")
+    } else {
+        format!("{element}.Deserialize<{ty}>(options)!")
+    }
+}
+fn serialize(value: &str) -> String {
+    format!("JsonSerializer.Serialize(writer, {value}, options);")
+}
+fn unknown_tag_arm() -> &'static str {
+    r#"_ => throw new JsonException($"Unknown tag {tag}"),"#
+}
+
+/// A switch statement over the variant records, writing each one's value
+fn switch_variants(class_names: &[String], mut write_variant: impl FnMut(usize) -> Block) -> Block {
+    block![
+        "switch (value)",
+        "{",
+        block(
+            class_names
+                .iter()
+                .enumerate()
+                .map(|(idx, class_name)| flatten![
+                    format!("case {class_name} variant:"),
+                    block![flatten(write_variant(idx)), "break;"],
+                ])
+        ),
+        "}",
+    ]
+}
+
+impl CodeGen for CSharpCodeGen {
+    fn head(&self) -> Block {
+        block![
+            "#nullable enable",
+            "",
+            "using System;",
+            "using System.Collections.Generic;",
+            "using System.Linq;",
+            "using System.Text.Json;",
+            "using System.Text.Json.Serialization;",
+            "",
+            format!("namespace {};", self.namespace),
+            "",
+        ]
+    }
+
+    fn identifier_expr(&self, id: &str) -> String {
+        ident(id)
+    }
+
+    fn primitive_expr(&self, primitive: Primitive) -> String {
+        let (signed, unsigned) = match primitive {
+            Primitive::String => return "string".to_string(),
+            Primitive::Bool => return "bool".to_string(),
+            Primitive::Number | Primitive::Float { bits: 64 } => return "double".to_string(),
+            Primitive::Float { .. } => return "float".to_string(),
+            Primitive::Int { bits: 8, .. } => ("sbyte", "byte"),
+            Primitive::Int { bits: 16, .. } => ("short", "ushort"),
+            Primitive::Int { bits: 32, .. } => ("int", "uint"),
+            Primitive::Int { .. } => ("long", "ulong"),
+        };
+        match primitive {
+            Primitive::Int { signed: true, .. } => signed.to_string(),
+            _ => unsigned.to_string(),
+        }
+    }
+
+    fn array_expr(&self, elem: &str) -> String {
+        format!("List<{elem}>")
+    }
+
+    fn map_expr(&self, key: &str, value: &str) -> String {
+        format!("Dictionary<{key}, {value}>")
+    }
+
+    fn tuple_expr(&self, _elements: &[String]) -> String {
+        unreachable!("value tuples serialize as objects, so tuples are hoisted into records")
+    }
+
+    fn optional_expr(&self, unwrapped: &str) -> String {
+        format!("{unwrapped}?")
+    }
+
+    fn generic_expr(&self, name: &str, args: &[String]) -> String {
+        format!("{}<{}>", ident(name), args.join(", "))
+    }
+
+    // Polymorphic records and converters can't be generic, so everything is monomorphized
+    fn supports_generics(&self) -> bool {
+        false
+    }
+
+    // Using aliases have to come before the declarations, and can't refer to each other
+    fn supports_aliases(&self) -> bool {
+        false
+    }
+
+    fn supports_tuples(&self) -> bool {
+        false
+    }
+
+    fn tuple_decl(&self, tuple: Tuple) -> Block {
+        // Encoded as an array, with the elements as properties named like tuple elements
+        let name = ident(&tuple.name);
+        let properties = (1..=tuple.elements.len())
+            .map(|idx| format!("Item{idx}"))
+            .collect::<Vec<_>>();
+        let last = tuple.elements.len().saturating_sub(1);
+        block![
+            format!("[JsonConverter(typeof({name}.Converter))]"),
+            format!(
+                "public record {name}({})",
+                tuple
+                    .elements
+                    .iter()
+                    .zip(&properties)
+                    .map(|(ty, property)| format!("{ty} {property}"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            ),
+            "{",
+            converter(
+                &name,
+                block![
+                    format!("return new {name}("),
+                    // Trailing optional elements may be left out
+                    block(tuple.elements.iter().enumerate().map(|(idx, ty)| {
+                        let element = if ty.ends_with('?') {
+                            format!(
+                                "element.GetArrayLength() > {idx} ? {} : null",
+                                deserialize(&format!("element[{idx}]"), ty)
+                            )
+                        } else {
+                            deserialize(&format!("element[{idx}]"), ty)
+                        };
+                        format!("{element}{}", if idx == last { ");" } else { "," })
+                    })),
+                ],
+                block![
+                    "writer.WriteStartArray();",
+                    flatten(
+                        properties
+                            .iter()
+                            .map(|property| serialize(&format!("value.{property}")))
+                    ),
+                    "writer.WriteEndArray();",
+                ],
+            ),
+            "}",
+        ]
+    }
+
+    fn struct_decl(&self, struct_: Struct) -> Block {
+        block![
+            doc_comment(struct_.doc.as_deref()),
+            flatten(record(&ident(&struct_.name), &struct_.fields, None)),
+        ]
+    }
+
+    fn enum_decl(&self, enum_: Enum) -> Block {
+        let name = ident(&enum_.name);
+        let members = convert_names(
+            enum_.values.iter().map(|value| value.name.as_str()),
+            Case::Pascal,
+        )
+        .into_iter()
+        .map(|member| ident(&member))
+        .collect::<Vec<_>>();
+        let doc = doc_comment(enum_.doc.as_deref());
+        match enum_.value_type {
+            // Enums are serialized as their integer value otherwise
+            EnumValueType::String => block![
+                doc,
+                format!("[JsonConverter(typeof(JsonStringEnumConverter<{name}>))]"),
+                format!("public enum {name}"),
+                "{",
+                block(
+                    enum_
+                        .values
+                        .iter()
+                        .zip(&members)
+                        .map(|(value, member)| flatten![
+                            doc_comment(value.doc.as_deref()),
+                            format!("[JsonStringEnumMemberName({})]", quote(&value.value)),
+                            format!("{member},"),
+                        ])
+                ),
+                "}",
+            ],
+            EnumValueType::Integer => {
+                let fits_int = enum_
+                    .values
+                    .iter()
+                    .all(|value| value.value.parse::<i32>().is_ok());
+                let underlying_type = if fits_int { "" } else { " : long" };
+                block![
+                    doc,
+                    format!("public enum {name}{underlying_type}"),
+                    "{",
+                    block(
+                        enum_
+                            .values
+                            .iter()
+                            .zip(&members)
+                            .map(|(value, member)| flatten![
+                                doc_comment(value.doc.as_deref()),
+                                format!("{member} = {},", value.value),
+                            ])
+                    ),
+                    "}",
+                ]
+            }
+        }
+    }
+
+    fn union_decl(&self, union: Union) -> Block {
+        let name = ident(&union.name);
+        let doc = doc_comment(union.doc.as_deref());
+        let (converter, variant_records) = match union.kind {
+            UnionKind::InternallyTagged(InternallyTaggedUnionBody {
+                tag_field,
+                variants,
+                adjacently_tagged: None,
+                ..
+            }) => {
+                let class_names =
+                    variant_type_names(&name, variants.iter().map(|variant| variant.name.as_str()));
+                return block![
+                    doc,
+                    format!(
+                        "[JsonPolymorphic(TypeDiscriminatorPropertyName = {})]",
+                        quote(&tag_field)
+                    ),
+                    flatten(variants.iter().zip(&class_names).map(
+                        |(variant, class_name)| format!(
+                            "[JsonDerivedType(typeof({class_name}), {})]",
+                            quote(&variant.name)
+                        )
+                    )),
+                    format!("public abstract record {name};"),
+                    flatten(
+                        variants
+                            .iter()
+                            .zip(&class_names)
+                            .map(|(variant, class_name)| {
+                                flatten![
+                                    "",
+                                    doc_comment(variant.doc.as_deref()),
+                                    flatten(record(class_name, &variant.fields, Some(&name))),
+                                ]
+                            })
+                    ),
+                ];
+            }
+            UnionKind::InternallyTagged(InternallyTaggedUnionBody {
+                variants,
+                adjacently_tagged: Some(adjacently_tagged),
+                ..
+            }) => {
+                let tag_field = quote(&adjacently_tagged.tag_field);
+                let data_field = quote(&adjacently_tagged.data_field);
+                let class_names =
+                    variant_type_names(&name, variants.iter().map(|variant| variant.name.as_str()));
+                let types = adjacently_tagged
+                    .variants
+                    .iter()
+                    .map(|data| {
+                        if data.optional {
+                            optional_field_type(&data.ty)
+                        } else {
+                            data.ty.clone()
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                let converter = converter(
+                    &name,
+                    block![
+                        format!("var tag = element.GetProperty({tag_field}).GetString();"),
+                        format!("element.TryGetProperty({data_field}, out var data);"),
+                        "return tag switch",
+                        "{",
+                        block![
+                            flatten(
+                                variants
+                                    .iter()
+                                    .zip(&adjacently_tagged.variants)
+                                    .zip(&types)
+                                    .zip(&class_names)
+                                    .map(|(((variant, data), ty), class_name)| {
+                                        let value = if data.optional {
+                                            format!(
+                                                "data.ValueKind == JsonValueKind.Undefined ? null : {}",
+                                                deserialize("data", ty)
+                                            )
+                                        } else {
+                                            deserialize("data", ty)
+                                        };
+                                        format!(
+                                            "{} => new {class_name}({value}),",
+                                            quote(&variant.name)
+                                        )
+                                    })
+                            ),
+                            unknown_tag_arm(),
+                        ],
+                        "};",
+                    ],
+                    block![
+                        "writer.WriteStartObject();",
+                        flatten(switch_variants(&class_names, |idx| {
+                            let write_data = flatten![
+                                format!("writer.WritePropertyName({data_field});"),
+                                serialize("variant.Value"),
+                            ];
+                            block![
+                                format!(
+                                    "writer.WriteString({tag_field}, {});",
+                                    quote(&variants[idx].name)
+                                ),
+                                if adjacently_tagged.variants[idx].optional {
+                                    flatten![
+                                        "if (variant.Value is not null)",
+                                        "{",
+                                        block![write_data],
+                                        "}",
+                                    ]
+                                } else {
+                                    write_data
+                                },
+                            ]
+                        })),
+                        "writer.WriteEndObject();",
+                    ],
+                );
+                let variant_records = variants
+                    .iter()
+                    .zip(&types)
+                    .zip(&class_names)
+                    .map(|((variant, ty), class_name)| {
+                        flatten![
+                            doc_comment(variant.doc.as_deref()),
+                            single_value_record(class_name, ty, &name),
+                        ]
+                    })
+                    .collect::<Vec<_>>();
+                (converter, block![flatten(variant_records)])
+            }
+            UnionKind::ExternallyTagged(variants) => {
+                let class_names =
+                    variant_type_names(&name, variants.iter().map(|variant| variant.name.as_str()));
+                let converter = converter(
+                    &name,
+                    block![
+                        "var properties = element.EnumerateObject().ToList();",
+                        "if (properties.Count != 1)",
+                        "{",
+                        block![r#"throw new JsonException("Expected exactly one key");"#],
+                        "}",
+                        "var (tag, data) = (properties[0].Name, properties[0].Value);",
+                        "return tag switch",
+                        "{",
+                        block![
+                            flatten(variants.iter().zip(&class_names).map(
+                                |(variant, class_name)| format!(
+                                    "{} => new {class_name}({}),",
+                                    quote(&variant.name),
+                                    deserialize("data", &variant.ty)
+                                )
+                            )),
+                            unknown_tag_arm(),
+                        ],
+                        "};",
+                    ],
+                    block![
+                        "writer.WriteStartObject();",
+                        flatten(switch_variants(&class_names, |idx| block![
+                            format!("writer.WritePropertyName({});", quote(&variants[idx].name)),
+                            serialize("variant.Value"),
+                        ])),
+                        "writer.WriteEndObject();",
+                    ],
+                );
+                let variant_records = variants
+                    .iter()
+                    .zip(&class_names)
+                    .map(|(Variant { ty, doc, .. }, class_name)| {
+                        flatten![
+                            doc_comment(doc.as_deref()),
+                            single_value_record(class_name, ty, &name),
+                        ]
+                    })
+                    .collect::<Vec<_>>();
+                (converter, block![flatten(variant_records)])
+            }
+            UnionKind::Untagged(variants) => {
+                let class_names =
+                    variant_type_names(&name, variants.iter().map(|variant| variant.name.as_str()));
+                let converter = converter(
+                    &name,
+                    block![
+                        flatten(variants.iter().zip(&class_names).map(
+                            |(variant, class_name)| format!(
+                                "try {{ return new {class_name}({}); }} catch (JsonException) {{ }}",
+                                deserialize("element", &variant.ty)
+                            )
+                        )),
+                        r#"throw new JsonException("Data doesn't match any variant");"#,
+                    ],
+                    switch_variants(&class_names, |_| block![serialize("variant.Value")]),
+                );
+                let variant_records = variants
+                    .iter()
+                    .zip(&class_names)
+                    .map(|(variant, class_name)| {
+                        single_value_record(class_name, &variant.ty, &name)
+                    })
+                    .collect::<Vec<_>>();
+                (converter, block![flatten(variant_records)])
+            }
+        };
+        block![
+            doc,
+            format!("[JsonConverter(typeof({name}.Converter))]"),
+            format!("public abstract record {name}"),
+            "{",
+            converter,
+            "}",
+            "",
+            flatten(variant_records),
+        ]
+    }
+
+    fn alias_decl(&self, alias: Alias) -> Block {
+        // Newtypes, and the aliases that can't be inlined, are wrapped in a struct converted as
+        // the value it wraps
+        let name = ident(&alias.name);
+        block![
+            doc_comment(alias.doc.as_deref()),
+            format!("[JsonConverter(typeof({name}.Converter))]"),
+            format!("public readonly record struct {name}({} Value)", alias.ty),
+            "{",
+            block![
+                format!("public class Converter : JsonConverter<{name}>"),
+                "{",
+                block![
+                    format!(
+                        "public override {name} Read(ref Utf8JsonReader reader, Type typeToConvert, JsonSerializerOptions options) =>"
+                    ),
+                    block![format!(
+                        "new(JsonSerializer.Deserialize<{}>(ref reader, options){});",
+                        alias.ty,
+                        if alias.ty.ends_with('?') { "" } else { "!" }
+                    )],
+                    "",
+                    format!(
+                        "public override void Write(Utf8JsonWriter writer, {name} value, JsonSerializerOptions options) =>"
+                    ),
+                    block![serialize("value.Value")],
+                ],
+                "}",
+            ],
+            "}",
+        ]
+    }
+
+    fn service_decl(&self, service: Service) -> Block {
+        let constants = convert_names(
+            service.methods.iter().map(|method| method.name.as_str()),
+            Case::Pascal,
+        );
+        block![
+            doc_comment(service.doc.as_deref()),
+            format!("public static class {}Methods", service.name),
+            "{",
+            block(
+                service
+                    .methods
+                    .iter()
+                    .zip(&constants)
+                    .map(|(method, constant)| flatten![
+                        doc_comment(method.doc.as_deref()),
+                        format!(
+                            "public const string {} = {};",
+                            ident(constant),
+                            quote(&method.name)
+                        ),
+                    ])
+            ),
+            "}",
+        ]
+    }
+}
+
+const KEYWORDS: &[&str] = &[
+    "abstract",
+    "as",
+    "base",
+    "bool",
+    "break",
+    "byte",
+    "case",
+    "catch",
+    "char",
+    "checked",
+    "class",
+    "const",
+    "continue",
+    "decimal",
+    "default",
+    "delegate",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "event",
+    "explicit",
+    "extern",
+    "false",
+    "finally",
+    "fixed",
+    "float",
+    "for",
+    "foreach",
+    "goto",
+    "if",
+    "implicit",
+    "in",
+    "int",
+    "interface",
+    "internal",
+    "is",
+    "lock",
+    "long",
+    "namespace",
+    "new",
+    "null",
+    "object",
+    "operator",
+    "out",
+    "override",
+    "params",
+    "private",
+    "protected",
+    "public",
+    "readonly",
+    "ref",
+    "return",
+    "sbyte",
+    "sealed",
+    "short",
+    "sizeof",
+    "stackalloc",
+    "static",
+    "string",
+    "struct",
+    "switch",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typeof",
+    "uint",
+    "ulong",
+    "unchecked",
+    "unsafe",
+    "ushort",
+    "using",
+    "virtual",
+    "void",
+    "volatile",
+    "while",
+];
@@ -0,0 +1,634 @@
+use crate::{
+    convert_names, line_doc_comment, quote, variant_type_names, Alias, CodeGen, Enum,
+    EnumValueType, Field, InternallyTaggedUnionBody, Service, Struct, Tuple, Union, UnionKind,
+};
+use convert_case::Case;
+use tser_block::{block, flatten, Block};
+use tser_ir::hoist_tuples::hoist_tuples;
+use tser_ir::type_decl::TypeDecl;
+use tser_ir::type_expr::primitive::Primitive;
+use tser_ir::File;
+
+/// Generates types for `encoding/json`. Unions are structs holding one of their variants through
+/// an interface, as `encoding/json` can't decode into an interface. The output is indented with
+/// spaces, which `gofmt` turns into tabs.
+pub struct GoCodeGen {
+    pub package: String,
+}
+
+impl Default for GoCodeGen {
+    fn default() -> Self {
+        Self {
+            package: "models".to_string(),
+        }
+    }
+}
+
+fn ident(id: &str) -> String {
+    if KEYWORDS.contains(&id) {
+        format!("{id}_")
+    } else {
+        id.to_string()
+    }
+}
+/// Only names starting with an upper case letter are exported, and seen by `encoding/json`
+fn exported(name: &str) -> String {
+    if name.starts_with(|c: char| c.is_uppercase()) {
+        name.to_string()
+    } else {
+        format!("X{}", name.trim_start_matches('_'))
+    }
+}
+fn json_tag(name: &str, omit_empty: bool) -> String {
+    let omit_empty = if omit_empty { ",omitempty" } else { "" };
+    let tag = format!("json:{}", quote(&format!("{name}{omit_empty}")));
+    // A raw string can't hold a backquote
+    if tag.contains('`') {
+        quote(&tag)
+    } else {
+        format!("`{tag}`")
+    }
+}
+
+fn optional_field_type(ty: &str) -> String {
+    // A nullable type is already a pointer, don't point to it
+    if ty.starts_with('*') {
+        ty.to_string()
+    } else {
+        format!("*{ty}")
+    }
+}
+
+/// Fields are exported, so PascalCase, and always named on the wire by their tag. Optional ones
+/// are pointers, so that only a missing value is left out rather than any zero value.
+fn fields_block(fields: &[Field]) -> Block {
+    let names = convert_names(fields.iter().map(|field| field.name.as_str()), Case::Pascal);
+    block![flatten(fields.iter().zip(names).map(|(field, name)| {
+        let ty = if field.optional {
+            optional_field_type(&field.ty)
+        } else {
+            field.ty.clone()
+        };
+        flatten![
+            line_doc_comment(field.doc.as_deref(), "//"),
+            format!(
+                "{} {ty} {}",
+                exported(&name),
+                json_tag(&field.wire_name, field.optional)
+            ),
+        ]
+    }))]
+}
+
+fn struct_type(name: &str, fields: &[Field]) -> Block {
+    if fields.is_empty() {
+        return block![format!("type {name} struct{{}}")];
+    }
+    block![format!("type {name} struct {{"), fields_block(fields), "}"]
+}
+
+/// The unexported interface implemented by the variants of a union, named like the method that
+/// marks them
+fn variant_interface(union: &str) -> String {
+    format!("is{union}")
+}
+
+fn variant_decl(union: &str, name: &str, doc: Option<&str>, ty: Block) -> Block {
+    block![
+        line_doc_comment(doc, "//"),
+        flatten(ty),
+        "",
+        format!("func ({name}) {}() {{}}", variant_interface(union)),
+    ]
+}
+
+/// A variant holding a single value, as methods can't be declared on pointer types
+fn single_value_variant(union: &str, name: &str, doc: Option<&str>, ty: &str) -> Block {
+    variant_decl(
+        union,
+        name,
+        doc,
+        block![
+            format!("type {name} struct {{"),
+            block![format!("Value {ty}")],
+            "}"
+        ],
+    )
+}
+
+fn marshal_func(union: &str, cases: Block) -> Block {
+    block![
+        format!("func (u {union}) MarshalJSON() ([]byte, error) {{"),
+        block![
+            "switch value := u.Value.(type) {",
+            flatten(cases),
+            "default:",
+            block![format!(
+                "return nil, fmt.Errorf(\"unexpected {union} variant %T\", u.Value)"
+            )],
+            "}",
+        ],
+        "}",
+    ]
+}
+
+fn unmarshal_func(union: &str, body: Block) -> Block {
+    block![
+        format!("func (u *{union}) UnmarshalJSON(data []byte) error {{"),
+        body,
+        "}",
+    ]
+}
+
+/// Decodes `json` into the variant with `.Value` or without, and stores it in the union
+fn decode_variant(name: &str, json: &str, target: &str) -> Block {
+    block![
+        format!("var value {name}"),
+        format!("err = json.Unmarshal({json}, &value{target})"),
+        "u.Value = value",
+    ]
+}
+
+fn tagged_switch(
+    union: &str,
+    tag: &str,
+    cases: impl IntoIterator<Item = (String, Block)>,
+) -> Block {
+    block![
+        "var err error",
+        format!("switch {tag} {{"),
+        flatten(cases.into_iter().map(|(tag_value, decode)| {
+            flatten![format!("case {}:", quote(&tag_value)), decode]
+        })),
+        "default:",
+        block![format!(
+            "return fmt.Errorf(\"unknown {union} tag %q\", {tag})"
+        )],
+        "}",
+        "return err",
+    ]
+}
+
+impl CodeGen for GoCodeGen {
+    fn head(&self) -> Block {
+        block![format!("package {}", self.package), ""]
+    }
+
+    fn identifier_expr(&self, id: &str) -> String {
+        ident(id)
+    }
+
+    fn primitive_expr(&self, primitive: Primitive) -> String {
+        match primitive {
+            Primitive::String => "string".to_string(),
+            Primitive::Bool => "bool".to_string(),
+            Primitive::Number => "float64".to_string(),
+            Primitive::Float { bits } => format!("float{bits}"),
+            Primitive::Int { bits, signed: true } => format!("int{bits}"),
+            Primitive::Int {
+                bits,
+                signed: false,
+            } => format!("uint{bits}"),
+        }
+    }
+
+    fn array_expr(&self, elem: &str) -> String {
+        format!("[]{elem}")
+    }
+
+    fn map_expr(&self, key: &str, value: &str) -> String {
+        format!("map[{key}]{value}")
+    }
+
+    fn tuple_expr(&self, _elements: &[String]) -> String {
+        unreachable!("Go has no tuples, so they are hoisted into structs")
+    }
+
+    fn optional_expr(&self, unwrapped: &str) -> String {
+        format!("*{unwrapped}")
+    }
+
+    fn generic_expr(&self, _name: &str, _args: &[String]) -> String {
+        unreachable!("generic declarations are monomorphized for Go")
+    }
+
+    // Union variants would all have to take the union's type parameters
+    fn supports_generics(&self) -> bool {
+        false
+    }
+
+    fn supports_tuples(&self) -> bool {
+        false
+    }
+
+    fn tuple_decl(&self, tuple: Tuple) -> Block {
+        // Encoded as an array, decoding straight into the fields
+        let name = ident(&tuple.name);
+        let fields = (0..tuple.elements.len())
+            .map(|idx| format!("V{idx}"))
+            .collect::<Vec<_>>();
+        let list = |prefix: &str| {
+            fields
+                .iter()
+                .map(|field| format!("{prefix}t.{field}"))
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        block![
+            format!("type {name} struct {{"),
+            block(
+                fields
+                    .iter()
+                    .zip(&tuple.elements)
+                    .map(|(field, ty)| format!("{field} {ty}"))
+            ),
+            "}",
+            "",
+            format!("func (t {name}) MarshalJSON() ([]byte, error) {{"),
+            block![format!("return json.Marshal([]any{{{}}})", list(""))],
+            "}",
+            "",
+            format!("func (t *{name}) UnmarshalJSON(data []byte) error {{"),
+            block![format!(
+                "return json.Unmarshal(data, &[]any{{{}}})",
+                list("&")
+            )],
+            "}",
+        ]
+    }
+
+    fn struct_decl(&self, struct_: Struct) -> Block {
+        block![
+            line_doc_comment(struct_.doc.as_deref(), "//"),
+            flatten(struct_type(&ident(&struct_.name), &struct_.fields)),
+        ]
+    }
+
+    fn enum_decl(&self, enum_: Enum) -> Block {
+        let name = ident(&enum_.name);
+        let constants = convert_names(
+            enum_.values.iter().map(|value| value.name.as_str()),
+            Case::Pascal,
+        );
+        let underlying = match enum_.value_type {
+            EnumValueType::String => "string",
+            EnumValueType::Integer => "int64",
+        };
+        block![
+            line_doc_comment(enum_.doc.as_deref(), "//"),
+            format!("type {name} {underlying}"),
+            "",
+            "const (",
+            block(enum_.values.iter().zip(constants).map(|(value, constant)| {
+                let literal = match enum_.value_type {
+                    EnumValueType::String => quote(&value.value),
+                    EnumValueType::Integer => value.value.clone(),
+                };
+                flatten![
+                    line_doc_comment(value.doc.as_deref(), "//"),
+                    format!("{name}{constant} {name} = {literal}"),
+                ]
+            })),
+            ")",
+        ]
+    }
+
+    fn union_decl(&self, union: Union) -> Block {
+        let name = ident(&union.name);
+        let (variants, marshal, unmarshal) = match union.kind {
+            UnionKind::InternallyTagged(InternallyTaggedUnionBody {
+                tag_field,
+                variants,
+                adjacently_tagged: None,
+                ..
+            }) => {
+                let type_names =
+                    variant_type_names(&name, variants.iter().map(|variant| variant.name.as_str()));
+                let tag = json_tag(&tag_field, false);
+                let decls = variants
+                    .iter()
+                    .zip(&type_names)
+                    .map(|(variant, type_name)| {
+                        variant_decl(
+                            &name,
+                            type_name,
+                            variant.doc.as_deref(),
+                            struct_type(type_name, &variant.fields),
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                // The variant's fields are promoted from the embedded struct
+                let marshal = marshal_func(
+                    &name,
+                    block![flatten(variants.iter().zip(&type_names).map(
+                        |(variant, type_name)| flatten![
+                            format!("case {type_name}:"),
+                            block![
+                                "return json.Marshal(struct {",
+                                block![format!("Tag string {tag}"), type_name.as_str()],
+                                format!("}}{{{}, value}})", quote(&variant.name)),
+                            ],
+                        ]
+                    ))],
+                );
+                let unmarshal = unmarshal_func(
+                    &name,
+                    block![
+                        "var tagged struct {",
+                        block![format!("Tag string {tag}")],
+                        "}",
+                        "if err := json.Unmarshal(data, &tagged); err != nil {",
+                        block!["return err"],
+                        "}",
+                        flatten(tagged_switch(
+                            &name,
+                            "tagged.Tag",
+                            variants
+                                .iter()
+                                .zip(&type_names)
+                                .map(|(variant, type_name)| {
+                                    (variant.name.clone(), decode_variant(type_name, "data", ""))
+                                }),
+                        )),
+                    ],
+                );
+                (decls, marshal, unmarshal)
+            }
+            UnionKind::InternallyTagged(InternallyTaggedUnionBody {
+                variants,
+                adjacently_tagged: Some(adjacently_tagged),
+                ..
+            }) => {
+                let type_names =
+                    variant_type_names(&name, variants.iter().map(|variant| variant.name.as_str()));
+                let tag = json_tag(&adjacently_tagged.tag_field, false);
+                let cases = || {
+                    variants
+                        .iter()
+                        .zip(&adjacently_tagged.variants)
+                        .zip(&type_names)
+                };
+                let data_type = |optional: bool, ty: &str| {
+                    if optional {
+                        optional_field_type(ty)
+                    } else {
+                        ty.to_string()
+                    }
+                };
+                let decls = cases()
+                    .map(|((variant, data), type_name)| {
+                        single_value_variant(
+                            &name,
+                            type_name,
+                            variant.doc.as_deref(),
+                            &data_type(data.optional, &data.ty),
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                let marshal = marshal_func(
+                    &name,
+                    block![flatten(cases().map(
+                        |((variant, data), type_name)| flatten![
+                            format!("case {type_name}:"),
+                            block![
+                                "return json.Marshal(struct {",
+                                block![
+                                    format!("Tag string {tag}"),
+                                    format!(
+                                        "Data {} {}",
+                                        data_type(data.optional, &data.ty),
+                                        json_tag(&adjacently_tagged.data_field, data.optional)
+                                    ),
+                                ],
+                                format!("}}{{{}, value.Value}})", quote(&variant.name)),
+                            ],
+                        ]
+                    ))],
+                );
+                let unmarshal = unmarshal_func(
+                    &name,
+                    block![
+                        "var tagged struct {",
+                        block![
+                            format!("Tag string {tag}"),
+                            format!(
+                                "Data json.RawMessage {}",
+                                json_tag(&adjacently_tagged.data_field, false)
+                            ),
+                        ],
+                        "}",
+                        "if err := json.Unmarshal(data, &tagged); err != nil {",
+                        block!["return err"],
+                        "}",
+                        flatten(tagged_switch(
+                            &name,
+                            "tagged.Tag",
+                            cases().map(|((variant, data), type_name)| {
+                                let decode = decode_variant(type_name, "tagged.Data", ".Value");
+                                let decode = if data.optional {
+                                    // Left as nil when the data is missing
+                                    block![
+                                        format!("var value {type_name}"),
+                                        "if tagged.Data != nil {",
+                                        block!["err = json.Unmarshal(tagged.Data, &value.Value)"],
+                                        "}",
+                                        "u.Value = value",
+                                    ]
+                                } else {
+                                    decode
+                                };
+                                (variant.name.clone(), decode)
+                            }),
+                        )),
+                    ],
+                );
+                (decls, marshal, unmarshal)
+            }
+            UnionKind::ExternallyTagged(variants) => {
+                let type_names =
+                    variant_type_names(&name, variants.iter().map(|variant| variant.name.as_str()));
+                let decls = variants
+                    .iter()
+                    .zip(&type_names)
+                    .map(|(variant, type_name)| {
+                        single_value_variant(&name, type_name, variant.doc.as_deref(), &variant.ty)
+                    })
+                    .collect::<Vec<_>>();
+                let marshal = marshal_func(
+                    &name,
+                    block![flatten(variants.iter().zip(&type_names).map(
+                        |(variant, type_name)| flatten![
+                            format!("case {type_name}:"),
+                            block![format!(
+                                "return json.Marshal(map[string]any{{{}: value.Value}})",
+                                quote(&variant.name)
+                            )],
+                        ]
+                    ))],
+                );
+                let unmarshal = unmarshal_func(
+                    &name,
+                    block![
+                        "var tagged map[string]json.RawMessage",
+                        "if err := json.Unmarshal(data, &tagged); err != nil {",
+                        block!["return err"],
+                        "}",
+                        "if len(tagged) != 1 {",
+                        block![format!(
+                            "return fmt.Errorf(\"expected exactly one key in {name}, got %d\", len(tagged))"
+                        )],
+                        "}",
+                        "for tag, content := range tagged {",
+                        tagged_switch(
+                            &name,
+                            "tag",
+                            variants.iter().zip(&type_names).map(|(variant, type_name)| {
+                                (variant.name.clone(), decode_variant(type_name, "content", ".Value"))
+                            }),
+                        ),
+                        "}",
+                        "return nil",
+                    ],
+                );
+                (decls, marshal, unmarshal)
+            }
+            UnionKind::Untagged(variants) => {
+                let type_names =
+                    variant_type_names(&name, variants.iter().map(|variant| variant.name.as_str()));
+                let decls = variants
+                    .iter()
+                    .zip(&type_names)
+                    .map(|(variant, type_name)| {
+                        single_value_variant(&name, type_name, None, &variant.ty)
+                    })
+                    .collect::<Vec<_>>();
+                let marshal = marshal_func(
+                    &name,
+                    block![flatten(type_names.iter().map(|type_name| flatten![
+                        format!("case {type_name}:"),
+                        block!["return json.Marshal(value.Value)"],
+                    ]))],
+                );
+                let unmarshal = unmarshal_func(
+                    &name,
+                    block![
+                        flatten(type_names.iter().map(|type_name| flatten![
+                            format!(
+                                "if value := new({type_name}); json.Unmarshal(data, &value.Value) == nil {{"
+                            ),
+                            block!["u.Value = *value", "return nil"],
+                            "}",
+                        ])),
+                        format!("return fmt.Errorf(\"data doesn't match any {name} variant\")"),
+                    ],
+                );
+                (decls, marshal, unmarshal)
+            }
+        };
+        let interface = variant_interface(&name);
+        block![
+            line_doc_comment(union.doc.as_deref(), "//"),
+            format!("type {name} struct {{"),
+            block![format!("Value {interface}")],
+            "}",
+            "",
+            format!("type {interface} interface {{"),
+            block![format!("{interface}()")],
+            "}",
+            flatten(variants.into_iter().map(|decl| flatten!["", flatten(decl)])),
+            "",
+            flatten(marshal),
+            "",
+            flatten(unmarshal),
+        ]
+    }
+
+    fn alias_decl(&self, alias: Alias) -> Block {
+        let name = ident(&alias.name);
+        let doc = line_doc_comment(alias.doc.as_deref(), "//");
+        if alias.newtype {
+            // A defined type, which encodes like its underlying type
+            block![doc, format!("type {name} {}", alias.ty)]
+        } else {
+            block![doc, format!("type {name} = {}", alias.ty)]
+        }
+    }
+
+    fn file_block(&self, ir_file: &File, decls: Vec<Block>) -> Block {
+        // Go doesn't compile with unused imports. Unions are (un)marshaled by hand, and so are
+        // the structs tuples become.
+        let has_unions = ir_file
+            .type_decls()
+            .any(|type_decl| matches!(type_decl, TypeDecl::Union(_)));
+        let imports = [
+            (has_unions || !hoist_tuples(ir_file).1.is_empty()).then_some("\"encoding/json\""),
+            has_unions.then_some("\"fmt\""),
+        ];
+        let imports = imports.into_iter().flatten().collect::<Vec<_>>();
+        block![
+            flatten(self.head()),
+            (!imports.is_empty()).then(|| flatten!["import (", block(imports), ")", ""]),
+            flatten(decls.into_iter().map(|decl| flatten![flatten(decl), ""])),
+        ]
+    }
+
+    fn service_decl(&self, service: Service) -> Block {
+        let name = format!("{}Method", service.name);
+        let constants = convert_names(
+            service.methods.iter().map(|method| method.name.as_str()),
+            Case::Pascal,
+        )
+        .into_iter()
+        .map(|constant| format!("{name}{constant}"))
+        .collect::<Vec<_>>();
+        block![
+            line_doc_comment(service.doc.as_deref(), "//"),
+            format!("type {name} string"),
+            "",
+            "const (",
+            block(
+                service
+                    .methods
+                    .iter()
+                    .zip(&constants)
+                    .map(|(method, constant)| flatten![
+                        line_doc_comment(method.doc.as_deref(), "//"),
+                        format!("{constant} {name} = {}", quote(&method.name)),
+                    ])
+            ),
+            ")",
+            "",
+            format!("var {name}s = []{name}{{"),
+            block(constants.iter().map(|constant| format!("{constant},"))),
+            "}",
+        ]
+    }
+}
+
+const KEYWORDS: &[&str] = &[
+    "break",
+    "case",
+    "chan",
+    "const",
+    "continue",
+    "default",
+    "defer",
+    "else",
+    "fallthrough",
+    "for",
+    "func",
+    "go",
+    "goto",
+    "if",
+    "import",
+    "interface",
+    "map",
+    "package",
+    "range",
+    "return",
+    "select",
+    "struct",
+    "switch",
+    "type",
+    "var",
+];
@@ -0,0 +1,878 @@
+use crate::{
+    block_doc_comment, convert_names, quote, variant_type_names, Alias, CodeGen, Enum,
+    EnumValueType, Field, InternallyTaggedUnionBody, JsonKind, Service, Struct, Tuple, Union,
+    UnionKind,
+};
+use convert_case::Case;
+use tser_block::{block, flatten, Block, BlockModifier};
+use tser_ir::type_decl::{union::UnionKind as IrUnionKind, TypeDecl};
+use tser_ir::type_expr::primitive::Primitive;
+use tser_ir::File;
+
+/// Generates types for Jackson, nested in a class named `class_name` as a Java file only holds one
+/// public type. Structs are records, or POJOs with getters and setters when `records` is unset.
+/// Unions are sealed interfaces, using `@JsonTypeInfo` when internally tagged and a serializer
+/// written out otherwise. Newtypes and union variants holding a single value are always records.
+pub struct JavaCodeGen {
+    pub package: String,
+    pub class_name: String,
+    pub records: bool,
+}
+
+impl Default for JavaCodeGen {
+    fn default() -> Self {
+        Self {
+            package: "models".to_string(),
+            class_name: "Models".to_string(),
+            records: true,
+        }
+    }
+}
+
+impl JavaCodeGen {
+    /// Generates classes with getters and setters instead of records
+    pub fn pojos() -> Self {
+        Self {
+            records: false,
+            ..Self::default()
+        }
+    }
+
+    /// Deserializes a `JsonNode`, which is missing when null, as `ty`, with the helper declared
+    /// in the outer class when [`uses_read`] says so
+    fn read(&self, node: &str, ty: &str) -> String {
+        format!(
+            "read(context, {node}, new TypeReference<{}>() {{}})",
+            boxed(ty)
+        )
+    }
+
+    /// A record, or a class with a getter and a setter for each field. Tuples leave out the
+    /// property annotations, as they are serialized by position.
+    fn bean(
+        &self,
+        name: &str,
+        fields: &[Field],
+        annotations: impl BlockModifier,
+        supertype: Option<&str>,
+        annotate_properties: bool,
+    ) -> Block {
+        let implements = supertype
+            .map(|supertype| format!(" implements {supertype}"))
+            .unwrap_or_default();
+        let names = convert_names(fields.iter().map(|field| field.name.as_str()), Case::Camel)
+            .into_iter()
+            .map(|name| ident(&name))
+            .collect::<Vec<_>>();
+        let field_annotations = |field: &Field| {
+            if annotate_properties {
+                property_annotations(field)
+            } else {
+                vec![]
+            }
+        };
+        let types = fields
+            .iter()
+            .map(|field| {
+                if field.optional {
+                    boxed(&field.ty)
+                } else {
+                    field.ty.clone()
+                }
+            })
+            .collect::<Vec<_>>();
+        if self.records {
+            if fields.is_empty() {
+                return block![
+                    annotations,
+                    format!("public record {name}(){implements} {{}}")
+                ];
+            }
+            let last = fields.len() - 1;
+            return block![
+                annotations,
+                format!("public record {name}("),
+                block(fields.iter().zip(&names).zip(&types).enumerate().map(
+                    |(idx, ((field, name), ty))| flatten![
+                        block_doc_comment(field.doc.as_deref()),
+                        format!(
+                            "{}{ty} {name}{}",
+                            field_annotations(field)
+                                .iter()
+                                .map(|annotation| format!("{annotation} "))
+                                .collect::<String>(),
+                            if idx == last { "" } else { "," }
+                        ),
+                    ]
+                )),
+                format!("){implements} {{}}"),
+            ];
+        }
+        // Records are final already
+        let modifiers = if supertype.is_some() {
+            "public static final class"
+        } else {
+            "public static class"
+        };
+        let members = fields
+            .iter()
+            .zip(&names)
+            .zip(&types)
+            .map(|((field, name), ty)| {
+                block![
+                    block_doc_comment(field.doc.as_deref()),
+                    flatten(field_annotations(field)),
+                    format!("private {ty} {name};"),
+                ]
+            })
+            .chain(names.iter().zip(&types).map(|(name, ty)| {
+                let accessor = accessor_suffix(name);
+                block![
+                    format!("public {ty} get{accessor}() {{"),
+                    block![format!("return {name};")],
+                    "}",
+                    "",
+                    format!("public void set{accessor}({ty} {name}) {{"),
+                    block![format!("this.{name} = {name};")],
+                    "}",
+                ]
+            }))
+            .collect::<Vec<_>>();
+        block![
+            annotations,
+            format!("{modifiers} {name}{implements} {{"),
+            block(separated(members)),
+            "}",
+        ]
+    }
+}
+
+fn ident(id: &str) -> String {
+    if KEYWORDS.contains(&id) {
+        format!("{id}_")
+    } else {
+        id.to_string()
+    }
+}
+/// Whether a deserializer calls the `read` helper, which the ones written out for unions other
+/// than internally tagged ones do
+fn uses_read(ir_file: &File) -> bool {
+    ir_file.type_decls().any(|type_decl| match type_decl {
+        TypeDecl::Union(union) => match &union.kind {
+            IrUnionKind::InternallyTagged(internally_tagged) => {
+                internally_tagged.as_adjacently_tagged().is_some()
+            }
+            IrUnionKind::ExternallyTagged(_) | IrUnionKind::Untagged(_) => true,
+        },
+        _ => false,
+    })
+}
+
+/// The `JsonNode` method telling whether a node is of a kind
+fn node_kind_check(json_kind: JsonKind) -> &'static str {
+    match json_kind {
+        JsonKind::String => "isTextual",
+        JsonKind::Number => "isNumber",
+        JsonKind::Integer => "isIntegralNumber",
+        JsonKind::Boolean => "isBoolean",
+        JsonKind::Array => "isArray",
+        JsonKind::Object => "isObject",
+    }
+}
+
+/// Primitive types can't be null, or type arguments
+fn boxed(ty: &str) -> String {
+    match ty {
+        "boolean" => "Boolean",
+        "byte" => "Byte",
+        "short" => "Short",
+        "int" => "Integer",
+        "long" => "Long",
+        "float" => "Float",
+        "double" => "Double",
+        _ => ty,
+    }
+    .to_string()
+}
+
+/// The name on the wire is always given, and optional properties are left out when null
+fn property_annotations(field: &Field) -> Vec<String> {
+    if field.optional {
+        vec![
+            format!("@JsonProperty({})", quote(&field.wire_name)),
+            "@JsonInclude(JsonInclude.Include.NON_NULL)".to_string(),
+        ]
+    } else {
+        vec![format!(
+            "@JsonProperty(value = {}, required = true)",
+            quote(&field.wire_name)
+        )]
+    }
+}
+
+/// `fooBar` has `getFooBar` and `setFooBar`
+fn accessor_suffix(name: &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => first.to_uppercase().chain(chars).collect(),
+        None => String::new(),
+    }
+}
+
+/// Puts a blank line between the members of a class
+fn separated(members: Vec<Block>) -> Block {
+    let mut separated = Block::default();
+    for (idx, member) in members.into_iter().enumerate() {
+        if idx > 0 {
+            "".modify_block(&mut separated);
+        }
+        flatten(member).modify_block(&mut separated);
+    }
+    separated
+}
+
+fn single_value_record(name: &str, ty: &str, supertype: &str) -> String {
+    format!("public record {name}({ty} value) implements {supertype} {{}}")
+}
+
+/// A serializer and deserializer nested in the sealed interface, which reads a `JsonNode` first
+fn union_codec(name: &str, serialize: Block, deserialize: Block) -> Block {
+    block![
+        format!("final class Serializer extends StdSerializer<{name}> {{"),
+        block![
+            "public Serializer() {",
+            block![format!("super({name}.class);")],
+            "}",
+            "",
+            "@Override",
+            format!(
+                "public void serialize({name} value, JsonGenerator generator, SerializerProvider provider) throws IOException {{"
+            ),
+            serialize,
+            "}",
+        ],
+        "}",
+        "",
+        format!("final class Deserializer extends StdDeserializer<{name}> {{"),
+        block![
+            "public Deserializer() {",
+            block![format!("super({name}.class);")],
+            "}",
+            "",
+            "@Override",
+            format!(
+                "public {name} deserialize(JsonParser parser, DeserializationContext context) throws IOException {{"
+            ),
+            block!["JsonNode node = parser.readValueAsTree();", flatten(deserialize)],
+            "}",
+        ],
+        "}",
+    ]
+}
+
+/// An `if` chain over the variant types, as switches can't match types before Java 21
+fn instanceof_chain(
+    class_names: &[String],
+    mut write_variant: impl FnMut(usize) -> Block,
+) -> Block {
+    let branches = class_names.iter().enumerate().map(|(idx, class_name)| {
+        let keyword = if idx == 0 { "if" } else { "} else if" };
+        flatten![
+            format!("{keyword} (value instanceof {class_name} variant) {{"),
+            write_variant(idx),
+        ]
+    });
+    block![flatten(branches.collect::<Vec<_>>()), "}"]
+}
+
+fn unknown_tag_case(tag: &str) -> String {
+    format!(r#"default -> throw JsonMappingException.from(parser, "Unknown tag " + {tag});"#)
+}
+
+/// `members` are the enum constant names, the literals they serialize as, and their docs
+fn enum_class(
+    name: &str,
+    value_type: &str,
+    doc: Option<&str>,
+    members: impl IntoIterator<Item = (String, String, Option<String>)>,
+) -> Block {
+    let members = members.into_iter().collect::<Vec<_>>();
+    let constants = convert_names(
+        members.iter().map(|(name, _, _)| name.as_str()),
+        Case::UpperSnake,
+    );
+    let last = members.len().saturating_sub(1);
+    block![
+        block_doc_comment(doc),
+        format!("public enum {name} {{"),
+        block![
+            flatten(members.iter().zip(&constants).enumerate().map(
+                |(idx, ((_, literal, doc), constant))| flatten![
+                    block_doc_comment(doc.as_deref()),
+                    format!(
+                        "{}({literal}){}",
+                        ident(constant),
+                        if idx == last { ";" } else { "," }
+                    ),
+                ]
+            )),
+            "",
+            format!("private final {value_type} value;"),
+            "",
+            format!("{name}({value_type} value) {{"),
+            block!["this.value = value;"],
+            "}",
+            "",
+            "@JsonValue",
+            format!("public {value_type} value() {{"),
+            block!["return value;"],
+            "}",
+        ],
+        "}",
+    ]
+}
+
+impl CodeGen for JavaCodeGen {
+    fn head(&self) -> Block {
+        block![
+            format!("package {};", self.package),
+            "",
+            "import com.fasterxml.jackson.annotation.*;",
+            "import com.fasterxml.jackson.core.*;",
+            "import com.fasterxml.jackson.core.type.TypeReference;",
+            "import com.fasterxml.jackson.databind.*;",
+            "import com.fasterxml.jackson.databind.annotation.*;",
+            "import com.fasterxml.jackson.databind.deser.std.StdDeserializer;",
+            "import com.fasterxml.jackson.databind.node.NullNode;",
+            "import com.fasterxml.jackson.databind.ser.std.StdSerializer;",
+            "import java.io.IOException;",
+            "import java.math.BigInteger;",
+            "import java.util.*;",
+            "",
+        ]
+    }
+
+    fn identifier_expr(&self, id: &str) -> String {
+        ident(id)
+    }
+
+    fn primitive_expr(&self, primitive: Primitive) -> String {
+        // Unsigned integers take the next larger type
+        match primitive {
+            Primitive::String => "String",
+            Primitive::Bool => "boolean",
+            Primitive::Number | Primitive::Float { bits: 64 } => "double",
+            Primitive::Float { .. } => "float",
+            Primitive::Int {
+                bits: 8,
+                signed: true,
+            } => "byte",
+            Primitive::Int { bits: 8, .. }
+            | Primitive::Int {
+                bits: 16,
+                signed: true,
+            } => "short",
+            Primitive::Int { bits: 16, .. }
+            | Primitive::Int {
+                bits: 32,
+                signed: true,
+            } => "int",
+            Primitive::Int { bits: 32, .. } | Primitive::Int { signed: true, .. } => "long",
+            Primitive::Int { .. } => "BigInteger",
+        }
+        .to_string()
+    }
+
+    fn array_expr(&self, elem: &str) -> String {
+        format!("List<{}>", boxed(elem))
+    }
+
+    fn map_expr(&self, key: &str, value: &str) -> String {
+        format!("Map<{}, {}>", boxed(key), boxed(value))
+    }
+
+    fn tuple_expr(&self, _elements: &[String]) -> String {
+        unreachable!("Java has no tuples, so they are hoisted into classes")
+    }
+
+    fn optional_expr(&self, unwrapped: &str) -> String {
+        boxed(unwrapped)
+    }
+
+    fn generic_expr(&self, name: &str, args: &[String]) -> String {
+        let args = args.iter().map(|arg| boxed(arg)).collect::<Vec<_>>();
+        format!("{}<{}>", ident(name), args.join(", "))
+    }
+
+    // Serializers of generic sealed interfaces would need the type arguments, so everything is
+    // monomorphized instead
+    fn supports_generics(&self) -> bool {
+        false
+    }
+
+    fn supports_aliases(&self) -> bool {
+        false
+    }
+
+    fn supports_tuples(&self) -> bool {
+        false
+    }
+
+    fn tuple_decl(&self, tuple: Tuple) -> Block {
+        // Encoded as an array of the properties in order, with trailing ones left null if missing
+        let properties = (1..=tuple.elements.len())
+            .map(|idx| format!("item{idx}"))
+            .collect::<Vec<_>>();
+        let fields = properties
+            .iter()
+            .zip(&tuple.elements)
+            .map(|(property, ty)| Field {
+                name: property.clone(),
+                wire_name: property.clone(),
+                ty: ty.clone(),
+                optional: false,
+                doc: None,
+            })
+            .collect::<Vec<_>>();
+        let property_order = properties
+            .iter()
+            .map(|property| quote(property))
+            .collect::<Vec<_>>()
+            .join(", ");
+        self.bean(
+            &ident(&tuple.name),
+            &fields,
+            flatten![
+                "@JsonFormat(shape = JsonFormat.Shape.ARRAY)",
+                format!("@JsonPropertyOrder({{{property_order}}})"),
+            ],
+            None,
+            false,
+        )
+    }
+
+    fn struct_decl(&self, struct_: Struct) -> Block {
+        block![
+            block_doc_comment(struct_.doc.as_deref()),
+            flatten(self.bean(
+                &ident(&struct_.name),
+                &struct_.fields,
+                flatten![],
+                None,
+                true
+            )),
+        ]
+    }
+
+    fn enum_decl(&self, enum_: Enum) -> Block {
+        let value_type = match enum_.value_type {
+            EnumValueType::String => "String",
+            EnumValueType::Integer => "long",
+        };
+        let name = ident(&enum_.name);
+        let members = enum_
+            .values
+            .into_iter()
+            .map(|value| {
+                let literal = match enum_.value_type {
+                    EnumValueType::String => quote(&value.value),
+                    EnumValueType::Integer => format!("{}L", value.value),
+                };
+                (value.name, literal, value.doc)
+            })
+            .collect::<Vec<_>>();
+        let enum_block = enum_class(&name, value_type, enum_.doc.as_deref(), members);
+        match enum_.value_type {
+            EnumValueType::String => enum_block,
+            // `@JsonValue` alone reads integers as the index of the constant
+            EnumValueType::Integer => {
+                let mut children = enum_block.into_iter().collect::<Vec<_>>();
+                let closing = children.pop();
+                block![
+                    flatten(children),
+                    block![
+                        "",
+                        "@JsonCreator",
+                        format!("public static {name} of(long value) {{"),
+                        block![
+                            format!("for ({name} constant : values()) {{"),
+                            block![
+                                "if (constant.value == value) {",
+                                block!["return constant;"],
+                                "}",
+                            ],
+                            "}",
+                            format!(
+                                "throw new IllegalArgumentException(\"Unknown {} \" + value);",
+                                enum_.name
+                            ),
+                        ],
+                        "}",
+                    ],
+                    closing,
+                ]
+            }
+        }
+    }
+
+    fn union_decl(&self, union: Union) -> Block {
+        let name = ident(&union.name);
+        let doc = block_doc_comment(union.doc.as_deref());
+        let permits = |class_names: &[String]| {
+            format!(
+                "public sealed interface {name} permits {}",
+                class_names.join(", ")
+            )
+        };
+        let (class_names, codec, variant_records) = match union.kind {
+            UnionKind::InternallyTagged(InternallyTaggedUnionBody {
+                tag_field,
+                variants,
+                adjacently_tagged: None,
+                ..
+            }) => {
+                let class_names =
+                    variant_type_names(&name, variants.iter().map(|variant| variant.name.as_str()));
+                let sub_types = variants
+                    .iter()
+                    .zip(&class_names)
+                    .map(|(variant, class_name)| {
+                        format!(
+                            "@JsonSubTypes.Type(value = {class_name}.class, name = {}),",
+                            quote(&variant.name)
+                        )
+                    })
+                    .collect::<Vec<_>>();
+                return block![
+                    doc,
+                    format!(
+                        "@JsonTypeInfo(use = JsonTypeInfo.Id.NAME, include = JsonTypeInfo.As.PROPERTY, property = {})",
+                        quote(&tag_field)
+                    ),
+                    "@JsonSubTypes({",
+                    block(sub_types),
+                    "})",
+                    format!("{} {{}}", permits(&class_names)),
+                    flatten(
+                        variants
+                            .iter()
+                            .zip(&class_names)
+                            .map(|(variant, class_name)| {
+                                flatten![
+                                    "",
+                                    block_doc_comment(variant.doc.as_deref()),
+                                    flatten(self.bean(
+                                        class_name,
+                                        &variant.fields,
+                                        flatten![],
+                                        Some(&name),
+                                        true,
+                                    )),
+                                ]
+                            })
+                    ),
+                ];
+            }
+            UnionKind::InternallyTagged(InternallyTaggedUnionBody {
+                variants,
+                adjacently_tagged: Some(adjacently_tagged),
+                ..
+            }) => {
+                let tag_field = quote(&adjacently_tagged.tag_field);
+                let data_field = quote(&adjacently_tagged.data_field);
+                let class_names =
+                    variant_type_names(&name, variants.iter().map(|variant| variant.name.as_str()));
+                let types = adjacently_tagged
+                    .variants
+                    .iter()
+                    .map(|data| {
+                        if data.optional {
+                            boxed(&data.ty)
+                        } else {
+                            data.ty.clone()
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                let codec = union_codec(
+                    &name,
+                    block![
+                        "generator.writeStartObject();",
+                        flatten(instanceof_chain(&class_names, |idx| {
+                            let write_data = format!(
+                                "provider.defaultSerializeField({data_field}, variant.value(), generator);"
+                            );
+                            block![
+                                format!(
+                                    "generator.writeStringField({tag_field}, {});",
+                                    quote(&variants[idx].name)
+                                ),
+                                if adjacently_tagged.variants[idx].optional {
+                                    flatten![
+                                        "if (variant.value() != null) {",
+                                        block![write_data],
+                                        "}",
+                                    ]
+                                } else {
+                                    flatten![write_data]
+                                },
+                            ]
+                        })),
+                        "generator.writeEndObject();",
+                    ],
+                    block![
+                        format!("String tag = node.path({tag_field}).asText();"),
+                        format!("JsonNode data = node.get({data_field});"),
+                        "return switch (tag) {",
+                        block![
+                            flatten(variants.iter().zip(&types).zip(&class_names).map(
+                                |((variant, ty), class_name)| format!(
+                                    "case {} -> new {class_name}({});",
+                                    quote(&variant.name),
+                                    self.read("data", ty)
+                                )
+                            )),
+                            unknown_tag_case("tag"),
+                        ],
+                        "};",
+                    ],
+                );
+                let variant_records = variants
+                    .iter()
+                    .zip(&types)
+                    .zip(&class_names)
+                    .map(|((variant, ty), class_name)| {
+                        flatten![
+                            block_doc_comment(variant.doc.as_deref()),
+                            single_value_record(class_name, ty, &name),
+                        ]
+                    })
+                    .collect::<Vec<_>>();
+                (class_names, codec, variant_records)
+            }
+            UnionKind::ExternallyTagged(variants) => {
+                let class_names =
+                    variant_type_names(&name, variants.iter().map(|variant| variant.name.as_str()));
+                let codec = union_codec(
+                    &name,
+                    block![
+                        "generator.writeStartObject();",
+                        flatten(instanceof_chain(&class_names, |idx| block![format!(
+                            "provider.defaultSerializeField({}, variant.value(), generator);",
+                            quote(&variants[idx].name)
+                        )])),
+                        "generator.writeEndObject();",
+                    ],
+                    block![
+                        "if (!node.isObject() || node.size() != 1) {",
+                        block![
+                            r#"throw JsonMappingException.from(parser, "Expected exactly one key");"#
+                        ],
+                        "}",
+                        "Map.Entry<String, JsonNode> entry = node.fields().next();",
+                        "return switch (entry.getKey()) {",
+                        block![
+                            flatten(variants.iter().zip(&class_names).map(
+                                |(variant, class_name)| format!(
+                                    "case {} -> new {class_name}({});",
+                                    quote(&variant.name),
+                                    self.read("entry.getValue()", &variant.ty)
+                                )
+                            )),
+                            unknown_tag_case("entry.getKey()"),
+                        ],
+                        "};",
+                    ],
+                );
+                let variant_records = variants
+                    .iter()
+                    .zip(&class_names)
+                    .map(|(variant, class_name)| {
+                        flatten![
+                            block_doc_comment(variant.doc.as_deref()),
+                            single_value_record(class_name, &variant.ty, &name),
+                        ]
+                    })
+                    .collect::<Vec<_>>();
+                (class_names, codec, variant_records)
+            }
+            UnionKind::Untagged(variants) => {
+                let class_names =
+                    variant_type_names(&name, variants.iter().map(|variant| variant.name.as_str()));
+                let codec = union_codec(
+                    &name,
+                    instanceof_chain(&class_names, |_| {
+                        block!["provider.defaultSerializeValue(variant.value(), generator);"]
+                    }),
+                    block![
+                        // Jackson converts between scalars, like a number to a string, so the
+                        // variants that can only be one kind of value are only tried on it
+                        flatten(
+                            variants
+                                .iter()
+                                .zip(&class_names)
+                                .map(|(variant, class_name)| {
+                                    let attempt = flatten![
+                                        "try {",
+                                        block![format!(
+                                            "return new {class_name}({});",
+                                            self.read("node", &variant.ty)
+                                        )],
+                                        "} catch (JsonProcessingException ignored) {",
+                                        "}",
+                                    ];
+                                    match variant.json_kind {
+                                        Some(json_kind) => flatten![
+                                            format!(
+                                                "if (node.{}()) {{",
+                                                node_kind_check(json_kind)
+                                            ),
+                                            block![attempt],
+                                            "}",
+                                        ],
+                                        None => attempt,
+                                    }
+                                })
+                        ),
+                        r#"throw JsonMappingException.from(parser, "Data doesn't match any variant");"#,
+                    ],
+                );
+                let variant_records = variants
+                    .iter()
+                    .zip(&class_names)
+                    .map(|(variant, class_name)| {
+                        flatten![single_value_record(class_name, &variant.ty, &name)]
+                    })
+                    .collect::<Vec<_>>();
+                (class_names, codec, variant_records)
+            }
+        };
+        block![
+            doc,
+            format!("@JsonSerialize(using = {name}.Serializer.class)"),
+            format!("@JsonDeserialize(using = {name}.Deserializer.class)"),
+            format!("{} {{", permits(&class_names)),
+            codec,
+            "}",
+            "",
+            flatten(variant_records),
+        ]
+    }
+
+    fn alias_decl(&self, alias: Alias) -> Block {
+        // Newtypes, and the aliases that can't be inlined, are records serialized as the value
+        // they wrap
+        let name = ident(&alias.name);
+        block![
+            block_doc_comment(alias.doc.as_deref()),
+            format!("public record {name}({} value) {{", alias.ty),
+            block![
+                "@JsonCreator(mode = JsonCreator.Mode.DELEGATING)",
+                format!("public {name} {{"),
+                "}",
+                "",
+                "@JsonValue",
+                "@Override",
+                format!("public {} value() {{", alias.ty),
+                block!["return value;"],
+                "}",
+            ],
+            "}",
+        ]
+    }
+
+    fn service_decl(&self, service: Service) -> Block {
+        enum_class(
+            &format!("{}Method", service.name),
+            "String",
+            service.doc.as_deref(),
+            service
+                .methods
+                .into_iter()
+                .map(|method| (method.name.clone(), quote(&method.name), method.doc)),
+        )
+    }
+
+    fn file_block(&self, ir_file: &File, decls: Vec<Block>) -> Block {
+        let decls = separated(decls);
+        let read_helper = uses_read(ir_file).then(|| {
+            flatten![
+                "",
+                "private static <T> T read(DeserializationContext context, JsonNode node, TypeReference<T> type) throws IOException {",
+                block![
+                    "JavaType javaType = context.getTypeFactory().constructType(type);",
+                    "return context.readTreeAsValue(node == null ? NullNode.getInstance() : node, javaType);",
+                ],
+                "}",
+            ]
+        });
+        block![
+            flatten(self.head()),
+            format!("public final class {} {{", self.class_name),
+            block![
+                format!("private {}() {{", self.class_name),
+                "}",
+                read_helper,
+                "",
+                flatten(decls),
+            ],
+            "}",
+            "",
+        ]
+    }
+}
+
+const KEYWORDS: &[&str] = &[
+    "abstract",
+    "assert",
+    "boolean",
+    "break",
+    "byte",
+    "case",
+    "catch",
+    "char",
+    "class",
+    "const",
+    "continue",
+    "default",
+    "do",
+    "double",
+    "else",
+    "enum",
+    "extends",
+    "false",
+    "final",
+    "finally",
+    "float",
+    "for",
+    "goto",
+    "if",
+    "implements",
+    "import",
+    "instanceof",
+    "int",
+    "interface",
+    "long",
+    "native",
+    "new",
+    "null",
+    "package",
+    "private",
+    "protected",
+    "public",
+    "return",
+    "short",
+    "static",
+    "strictfp",
+    "super",
+    "switch",
+    "synchronized",
+    "this",
+    "throw",
+    "throws",
+    "transient",
+    "true",
+    "try",
+    "void",
+    "volatile",
+    "while",
+];
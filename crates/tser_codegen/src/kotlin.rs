@@ -0,0 +1,606 @@
+use crate::{
+    block_doc_comment, convert_names, variant_type_names, Alias, CodeGen, Enum, EnumValueType,
+    Field, InternallyTaggedUnionBody, Service, Struct, Tuple, Union, UnionKind, Variant,
+};
+use convert_case::Case;
+use tser_block::{block, flatten, Block, BlockModifier};
+use tser_ir::type_expr::primitive::Primitive;
+
+/// Generates classes for kotlinx.serialization's `Json` format. Unions are sealed interfaces,
+/// using a class discriminator when internally tagged and a serializer written out otherwise.
+pub struct KotlinCodeGen;
+
+fn quote(string: &str) -> String {
+    // `$` starts a string template
+    crate::quote(string).replace('$', "\\$")
+}
+
+fn ident(id: &str) -> String {
+    if KEYWORDS.contains(&id) {
+        format!("`{id}`")
+    } else {
+        id.to_string()
+    }
+}
+fn serial_name_attr(name: &str) -> String {
+    format!("@SerialName({})", quote(name))
+}
+
+fn optional_field_type(ty: &str) -> String {
+    // A nullable type is already nullable, don't add another `?`
+    if ty.ends_with('?') {
+        ty.to_string()
+    } else {
+        format!("{ty}?")
+    }
+}
+
+/// Properties are camelCase, with the name on the wire given by `@SerialName` if it differs.
+/// Optional ones default to null, which the `Json` format leaves out unless `encodeDefaults` is set.
+fn constructor_params(fields: &[Field]) -> Block {
+    let names = convert_names(fields.iter().map(|field| field.name.as_str()), Case::Camel);
+    block![flatten(fields.iter().zip(names).map(|(field, name)| {
+        let serial_name = if name == field.wire_name {
+            String::new()
+        } else {
+            format!("{} ", serial_name_attr(&field.wire_name))
+        };
+        let declaration = if field.optional {
+            format!(
+                "val {}: {} = null,",
+                ident(&name),
+                optional_field_type(&field.ty)
+            )
+        } else {
+            format!("val {}: {},", ident(&name), field.ty)
+        };
+        flatten![
+            block_doc_comment(field.doc.as_deref()),
+            format!("{serial_name}{declaration}"),
+        ]
+    }))]
+}
+
+/// `supertype` is the sealed interface of a union variant. Classes without fields are objects, as
+/// data classes need a property.
+fn data_class(
+    name: &str,
+    fields: &[Field],
+    annotations: impl BlockModifier,
+    supertype: Option<&str>,
+) -> Block {
+    let supertype = supertype
+        .map(|supertype| format!(" : {supertype}"))
+        .unwrap_or_default();
+    if fields.is_empty() {
+        return block![annotations, format!("data object {name}{supertype}")];
+    }
+    block![
+        annotations,
+        format!("data class {name}("),
+        constructor_params(fields),
+        format!("){supertype}"),
+    ]
+}
+
+fn single_value_class(name: &str, ty: &str, supertype: &str) -> String {
+    format!("data class {name}(val value: {ty}) : {supertype}")
+}
+
+/// A serializer for the `Json` format only, going through `JsonElement`s
+fn json_serializer(name: &str, serialize: Block, deserialize: Block) -> Block {
+    block![
+        format!("object Serializer : KSerializer<{name}> {{"),
+        block![
+            format!(
+                "override val descriptor = buildClassSerialDescriptor({})",
+                quote(name)
+            ),
+            "",
+            format!("override fun serialize(encoder: Encoder, value: {name}) {{"),
+            block!["val output = encoder as JsonEncoder", flatten(serialize)],
+            "}",
+            "",
+            format!("override fun deserialize(decoder: Decoder): {name} {{"),
+            block!["val input = decoder as JsonDecoder", flatten(deserialize)],
+            "}",
+        ],
+        "}",
+    ]
+}
+
+const ENCODE_ELEMENT: &str = "output.encodeJsonElement(element)";
+
+fn encode_value(value: &str) -> String {
+    format!("output.json.encodeToJsonElement({value})")
+}
+fn decode_value(element: &str) -> String {
+    format!("input.json.decodeFromJsonElement({element})")
+}
+fn unknown_tag_branch() -> &'static str {
+    r#"else -> throw SerializationException("Unknown tag $tag")"#
+}
+
+impl CodeGen for KotlinCodeGen {
+    fn head(&self) -> Block {
+        block![
+            // For @JsonClassDiscriminator
+            "@file:OptIn(kotlinx.serialization.ExperimentalSerializationApi::class)",
+            "",
+            "import kotlinx.serialization.*",
+            "import kotlinx.serialization.descriptors.*",
+            "import kotlinx.serialization.encoding.*",
+            "import kotlinx.serialization.json.*",
+            "",
+        ]
+    }
+
+    fn identifier_expr(&self, id: &str) -> String {
+        ident(id)
+    }
+
+    fn primitive_expr(&self, primitive: Primitive) -> String {
+        let (signed, unsigned) = match primitive {
+            Primitive::String => return "String".to_string(),
+            Primitive::Bool => return "Boolean".to_string(),
+            Primitive::Number | Primitive::Float { bits: 64 } => return "Double".to_string(),
+            Primitive::Float { .. } => return "Float".to_string(),
+            Primitive::Int { bits: 8, .. } => ("Byte", "UByte"),
+            Primitive::Int { bits: 16, .. } => ("Short", "UShort"),
+            Primitive::Int { bits: 32, .. } => ("Int", "UInt"),
+            Primitive::Int { .. } => ("Long", "ULong"),
+        };
+        match primitive {
+            Primitive::Int { signed: true, .. } => signed.to_string(),
+            _ => unsigned.to_string(),
+        }
+    }
+
+    fn array_expr(&self, elem: &str) -> String {
+        format!("List<{elem}>")
+    }
+
+    fn map_expr(&self, key: &str, value: &str) -> String {
+        format!("Map<{key}, {value}>")
+    }
+
+    fn tuple_expr(&self, _elements: &[String]) -> String {
+        unreachable!("Pair and Triple serialize as objects, so tuples are hoisted into classes")
+    }
+
+    fn optional_expr(&self, unwrapped: &str) -> String {
+        format!("{unwrapped}?")
+    }
+
+    fn generic_expr(&self, name: &str, args: &[String]) -> String {
+        format!("{}<{}>", ident(name), args.join(", "))
+    }
+
+    // Serializers of generic sealed interfaces would have to take the serializers of the type
+    // arguments, so everything is monomorphized instead
+    fn supports_generics(&self) -> bool {
+        false
+    }
+
+    fn supports_tuples(&self) -> bool {
+        false
+    }
+
+    fn tuple_decl(&self, tuple: Tuple) -> Block {
+        // Encoded as an array, with the elements as properties named like tuple elements
+        let name = ident(&tuple.name);
+        let properties = (0..tuple.elements.len())
+            .map(|idx| format!("_{idx}"))
+            .collect::<Vec<_>>();
+        block![
+            format!("@Serializable(with = {name}.Serializer::class)"),
+            format!("data class {name}("),
+            block(
+                properties
+                    .iter()
+                    .zip(&tuple.elements)
+                    .map(|(property, ty)| format!("val {property}: {ty},"))
+            ),
+            ") {",
+            json_serializer(
+                &name,
+                block![
+                    "val element = buildJsonArray {",
+                    block(properties.iter().map(|property| format!(
+                        "add({})",
+                        encode_value(&format!("value.{property}"))
+                    ))),
+                    "}",
+                    ENCODE_ELEMENT,
+                ],
+                block![
+                    "val array = input.decodeJsonElement().jsonArray",
+                    format!("return {name}("),
+                    // Trailing optional elements may be left out
+                    block(tuple.elements.iter().enumerate().map(|(idx, ty)| {
+                        let element = if ty.ends_with('?') {
+                            format!("array.getOrElse({idx}) {{ JsonNull }}")
+                        } else {
+                            format!("array[{idx}]")
+                        };
+                        format!("{},", decode_value(&element))
+                    })),
+                    ")",
+                ],
+            ),
+            "}",
+        ]
+    }
+
+    fn struct_decl(&self, struct_: Struct) -> Block {
+        block![
+            block_doc_comment(struct_.doc.as_deref()),
+            flatten(data_class(
+                &ident(&struct_.name),
+                &struct_.fields,
+                "@Serializable",
+                None,
+            )),
+        ]
+    }
+
+    fn enum_decl(&self, enum_: Enum) -> Block {
+        let name = ident(&enum_.name);
+        let entries = convert_names(
+            enum_.values.iter().map(|value| value.name.as_str()),
+            Case::Pascal,
+        )
+        .into_iter()
+        .map(|entry| ident(&entry))
+        .collect::<Vec<_>>();
+        let doc = block_doc_comment(enum_.doc.as_deref());
+        match enum_.value_type {
+            EnumValueType::String => block![
+                doc,
+                "@Serializable",
+                format!("enum class {name} {{"),
+                block(
+                    enum_
+                        .values
+                        .iter()
+                        .zip(&entries)
+                        .map(|(value, entry)| flatten![
+                            block_doc_comment(value.doc.as_deref()),
+                            format!("{} {entry},", serial_name_attr(&value.value)),
+                        ])
+                ),
+                "}",
+            ],
+            // Enums are serialized by name otherwise
+            EnumValueType::Integer => {
+                let last = entries.len().saturating_sub(1);
+                block![
+                    doc,
+                    format!("@Serializable(with = {name}.Serializer::class)"),
+                    format!("enum class {name}(val value: Long) {{"),
+                    block![
+                        flatten(enum_.values.iter().zip(&entries).enumerate().map(
+                            |(idx, (value, entry))| flatten![
+                                block_doc_comment(value.doc.as_deref()),
+                                format!(
+                                    "{entry}({}){}",
+                                    value.value,
+                                    if idx == last { ";" } else { "," }
+                                ),
+                            ]
+                        )),
+                        "",
+                        format!("object Serializer : KSerializer<{name}> {{"),
+                        block![
+                            format!(
+                                "override val descriptor = PrimitiveSerialDescriptor({}, PrimitiveKind.LONG)",
+                                quote(&enum_.name)
+                            ),
+                            "",
+                            format!(
+                                "override fun serialize(encoder: Encoder, value: {name}) = encoder.encodeLong(value.value)"
+                            ),
+                            "",
+                            format!("override fun deserialize(decoder: Decoder): {name} {{"),
+                            block![
+                                "val value = decoder.decodeLong()",
+                                format!("return {name}.entries.firstOrNull {{ it.value == value }}"),
+                                block![format!(
+                                    "?: throw SerializationException(\"Unknown {} $value\")",
+                                    enum_.name
+                                )],
+                            ],
+                            "}",
+                        ],
+                        "}",
+                    ],
+                    "}",
+                ]
+            }
+        }
+    }
+
+    fn union_decl(&self, union: Union) -> Block {
+        let name = ident(&union.name);
+        let doc = block_doc_comment(union.doc.as_deref());
+        let (serializer, variant_classes) = match union.kind {
+            UnionKind::InternallyTagged(InternallyTaggedUnionBody {
+                tag_field,
+                variants,
+                adjacently_tagged: None,
+                ..
+            }) => {
+                let class_names =
+                    variant_type_names(&name, variants.iter().map(|variant| variant.name.as_str()));
+                return block![
+                    doc,
+                    "@Serializable",
+                    format!("@JsonClassDiscriminator({})", quote(&tag_field)),
+                    format!("sealed interface {name}"),
+                    flatten(
+                        variants
+                            .iter()
+                            .zip(&class_names)
+                            .map(|(variant, class_name)| {
+                                flatten![
+                                    "",
+                                    block_doc_comment(variant.doc.as_deref()),
+                                    flatten(data_class(
+                                        class_name,
+                                        &variant.fields,
+                                        flatten!["@Serializable", serial_name_attr(&variant.name)],
+                                        Some(&name),
+                                    )),
+                                ]
+                            })
+                    ),
+                ];
+            }
+            UnionKind::InternallyTagged(InternallyTaggedUnionBody {
+                variants,
+                adjacently_tagged: Some(adjacently_tagged),
+                ..
+            }) => {
+                let tag_field = quote(&adjacently_tagged.tag_field);
+                let data_field = quote(&adjacently_tagged.data_field);
+                let class_names =
+                    variant_type_names(&name, variants.iter().map(|variant| variant.name.as_str()));
+                let cases = || {
+                    variants
+                        .iter()
+                        .zip(&adjacently_tagged.variants)
+                        .zip(&class_names)
+                };
+                let serializer = json_serializer(
+                    &name,
+                    block![
+                        "val element = buildJsonObject {",
+                        block![
+                            "when (value) {",
+                            block(cases().map(|((variant, data), class_name)| flatten![
+                                format!("is {class_name} -> {{"),
+                                block![
+                                    format!("put({tag_field}, {})", quote(&variant.name)),
+                                    if data.optional {
+                                        format!(
+                                            "value.value?.let {{ put({data_field}, {}) }}",
+                                            encode_value("it")
+                                        )
+                                    } else {
+                                        format!("put({data_field}, {})", encode_value("value.value"))
+                                    },
+                                ],
+                                "}",
+                            ])),
+                            "}",
+                        ],
+                        "}",
+                        ENCODE_ELEMENT,
+                    ],
+                    block![
+                        "val element = input.decodeJsonElement().jsonObject",
+                        format!("val data = element[{data_field}] ?: JsonNull"),
+                        format!(
+                            "return when (val tag = element[{tag_field}]?.jsonPrimitive?.contentOrNull) {{"
+                        ),
+                        block![
+                            flatten(cases().map(|((variant, _), class_name)| format!(
+                                "{} -> {class_name}({})",
+                                quote(&variant.name),
+                                decode_value("data")
+                            ))),
+                            unknown_tag_branch(),
+                        ],
+                        "}",
+                    ],
+                );
+                let variant_classes = cases()
+                    .map(|((variant, data), class_name)| {
+                        let ty = if data.optional {
+                            optional_field_type(&data.ty)
+                        } else {
+                            data.ty.clone()
+                        };
+                        flatten![
+                            block_doc_comment(variant.doc.as_deref()),
+                            single_value_class(class_name, &ty, &name),
+                        ]
+                    })
+                    .collect::<Vec<_>>();
+                (serializer, block![flatten(variant_classes)])
+            }
+            UnionKind::ExternallyTagged(variants) => {
+                let class_names =
+                    variant_type_names(&name, variants.iter().map(|variant| variant.name.as_str()));
+                let serializer = json_serializer(
+                    &name,
+                    block![
+                        "val element = when (value) {",
+                        block(variants.iter().zip(&class_names).map(|(variant, class_name)| {
+                            flatten![
+                                format!("is {class_name} -> buildJsonObject {{"),
+                                block![format!(
+                                    "put({}, {})",
+                                    quote(&variant.name),
+                                    encode_value("value.value")
+                                )],
+                                "}",
+                            ]
+                        })),
+                        "}",
+                        ENCODE_ELEMENT,
+                    ],
+                    block![
+                        "val (tag, element) = input.decodeJsonElement().jsonObject.entries.singleOrNull()",
+                        block![r#"?: throw SerializationException("Expected exactly one key")"#],
+                        "return when (tag) {",
+                        block![
+                            flatten(variants.iter().zip(&class_names).map(
+                                |(variant, class_name)| format!(
+                                    "{} -> {class_name}({})",
+                                    quote(&variant.name),
+                                    decode_value("element")
+                                )
+                            )),
+                            unknown_tag_branch(),
+                        ],
+                        "}",
+                    ],
+                );
+                let variant_classes = variants
+                    .iter()
+                    .zip(&class_names)
+                    .map(|(Variant { ty, doc, .. }, class_name)| {
+                        flatten![
+                            block_doc_comment(doc.as_deref()),
+                            single_value_class(class_name, ty, &name),
+                        ]
+                    })
+                    .collect::<Vec<_>>();
+                (serializer, block![flatten(variant_classes)])
+            }
+            UnionKind::Untagged(variants) => {
+                let class_names =
+                    variant_type_names(&name, variants.iter().map(|variant| variant.name.as_str()));
+                let serializer = json_serializer(
+                    &name,
+                    block![
+                        "val element = when (value) {",
+                        block(class_names.iter().map(|class_name| format!(
+                            "is {class_name} -> {}",
+                            encode_value("value.value")
+                        ))),
+                        "}",
+                        ENCODE_ELEMENT,
+                    ],
+                    block![
+                        "val element = input.decodeJsonElement()",
+                        flatten(class_names.iter().enumerate().map(|(idx, class_name)| {
+                            let decode = format!("{{ {class_name}({}) }}", decode_value("element"));
+                            if idx == 0 {
+                                format!("return runCatching<{name}> {decode}")
+                            } else {
+                                format!("    .recoverCatching {decode}")
+                            }
+                        })),
+                        r#"    .getOrElse { throw SerializationException("Data doesn't match any variant") }"#,
+                    ],
+                );
+                let variant_classes = variants
+                    .iter()
+                    .zip(&class_names)
+                    .map(|(variant, class_name)| single_value_class(class_name, &variant.ty, &name))
+                    .collect::<Vec<_>>();
+                (serializer, block![flatten(variant_classes)])
+            }
+        };
+        block![
+            doc,
+            format!("@Serializable(with = {name}.Serializer::class)"),
+            format!("sealed interface {name} {{"),
+            serializer,
+            "}",
+            "",
+            flatten(variant_classes),
+        ]
+    }
+
+    fn alias_decl(&self, alias: Alias) -> Block {
+        let name = ident(&alias.name);
+        let doc = block_doc_comment(alias.doc.as_deref());
+        if alias.newtype {
+            // Value classes are encoded as the value they wrap
+            block![
+                doc,
+                "@JvmInline",
+                "@Serializable",
+                format!("value class {name}(val value: {})", alias.ty),
+            ]
+        } else {
+            block![doc, format!("typealias {name} = {}", alias.ty)]
+        }
+    }
+
+    fn service_decl(&self, service: Service) -> Block {
+        let entries = convert_names(
+            service.methods.iter().map(|method| method.name.as_str()),
+            Case::Pascal,
+        );
+        block![
+            block_doc_comment(service.doc.as_deref()),
+            "@Serializable",
+            format!(
+                "enum class {}Method(val methodName: String) {{",
+                service.name
+            ),
+            block(
+                service
+                    .methods
+                    .iter()
+                    .zip(&entries)
+                    .map(|(method, entry)| flatten![
+                        block_doc_comment(method.doc.as_deref()),
+                        format!(
+                            "{} {}({}),",
+                            serial_name_attr(&method.name),
+                            ident(entry),
+                            quote(&method.name)
+                        ),
+                    ])
+            ),
+            "}",
+        ]
+    }
+}
+
+// Hard keywords, which can't be used as identifiers without backticks
+const KEYWORDS: &[&str] = &[
+    "as",
+    "break",
+    "class",
+    "continue",
+    "do",
+    "else",
+    "false",
+    "for",
+    "fun",
+    "if",
+    "in",
+    "interface",
+    "is",
+    "null",
+    "object",
+    "package",
+    "return",
+    "super",
+    "this",
+    "throw",
+    "true",
+    "try",
+    "typealias",
+    "typeof",
+    "val",
+    "var",
+    "when",
+    "while",
+];
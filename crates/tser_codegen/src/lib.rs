@@ -1,12 +1,24 @@
+pub mod csharp;
+pub mod go;
+pub mod java;
+pub mod kotlin;
+pub mod python;
 pub mod rust;
 pub mod swift;
 
-use tser_block::{block, flatten, Block};
+use convert_case::{Boundary, Case, Casing};
+use std::collections::HashSet;
+use tser_block::{block, flatten, Block, BlockModifier};
+use tser_ir::hoist_tuples::{hoist_tuples, NamedTuple};
+use tser_ir::inline_aliases::inline_aliases;
+use tser_ir::monomorphize::{monomorphize, MonomorphizeError};
+use tser_ir::service::{Body as IrBody, Method as IrMethod, Service as IrService};
 use tser_ir::type_decl::enum_::EnumKind;
 use tser_ir::type_decl::union::AdjacentlyTaggedUnionBody as IrAdjacentlyTaggedUnionBody;
+use tser_ir::type_decl::TypeDecl;
 use tser_ir::type_decl::{
-    enum_::Enum as IrEnum, struct_::Struct as IrStruct, union::Union as IrUnion,
-    union::UnionKind as IrUnionKind,
+    alias::Alias as IrAlias, enum_::Enum as IrEnum, struct_::Struct as IrStruct,
+    union::Union as IrUnion, union::UnionKind as IrUnionKind,
 };
 use tser_ir::type_expr::{primitive::Primitive, TypeExpr, TypeExprKind};
 use tser_ir::File;
@@ -16,62 +28,122 @@ pub enum EnumValueType {
     String,
 }
 
+pub struct Field {
+    pub name: String,
+    pub wire_name: String, // Backends rename the field if this differs from `name`
+    pub ty: String,
+    pub optional: bool,
+    pub doc: Option<String>,
+}
+
 pub struct Struct {
     pub name: String,
-    pub fields: Vec<(String, String)>,
+    pub type_params: Vec<String>,
+    pub fields: Vec<Field>, // Including inherited fields
+    pub doc: Option<String>,
 }
 impl Struct {
-    fn from_ir(ir_struct: &IrStruct, code_gen: &dyn CodeGen) -> Self {
+    fn from_ir(ir_struct: &IrStruct, ir_file: &File, scope: &Scope) -> Self {
+        let ir_fields = ir_struct
+            .resolve_fields(ir_file)
+            .expect("inheritance should have been checked by the parser");
         Self {
             name: ir_struct.name.to_string(),
-            fields: ir_struct
-                .fields
+            type_params: ir_struct.type_params.clone(),
+            fields: ir_fields
                 .iter()
-                .map(|ir_field| {
-                    let ty = type_expr_to_string(&ir_field.ty, code_gen);
-                    (ir_field.name.clone(), ty)
+                .map(|ir_field| Field {
+                    name: ir_field.name.clone(),
+                    wire_name: ir_field.wire_name.clone(),
+                    ty: type_expr_to_string(&ir_field.ty, scope),
+                    optional: ir_field.optional,
+                    doc: ir_field.doc.clone(),
                 })
                 .collect(),
+            doc: ir_struct.doc.clone(),
         }
     }
 }
 
-fn type_expr_to_string(type_expr: &TypeExpr, code_gen: &dyn CodeGen) -> String {
+/// The backend, and the names declared before the declaration being converted. References to
+/// any other name are forward references.
+struct Scope<'a> {
+    code_gen: &'a dyn CodeGen,
+    declared: &'a HashSet<String>,
+}
+
+fn type_expr_to_string(type_expr: &TypeExpr, scope: &Scope) -> String {
     let unwrapped = match &type_expr.kind {
-        TypeExprKind::Primitive(primitive) => code_gen.primitive_expr(*primitive),
+        TypeExprKind::Primitive(primitive) => scope.code_gen.primitive_expr(*primitive),
         TypeExprKind::ArrayOf(element) => {
-            let element_string = type_expr_to_string(element.as_ref(), code_gen);
-            code_gen.array_expr(element_string.as_str())
+            let element_string = type_expr_to_string(element.as_ref(), scope);
+            scope.code_gen.array_expr(element_string.as_str())
+        }
+        TypeExprKind::MapOf { key, value } => scope.code_gen.map_expr(
+            &type_expr_to_string(key, scope),
+            &type_expr_to_string(value, scope),
+        ),
+        TypeExprKind::Identifier(id) if scope.declared.contains(id) => {
+            scope.code_gen.identifier_expr(id)
+        }
+        TypeExprKind::Identifier(id) => scope.code_gen.forward_identifier_expr(id),
+        TypeExprKind::Tuple(elements) => {
+            let elements = elements
+                .iter()
+                .map(|element| type_expr_to_string(element, scope))
+                .collect::<Vec<_>>();
+            scope.code_gen.tuple_expr(&elements)
+        }
+        TypeExprKind::Generic { name, args } => {
+            let args = args
+                .iter()
+                .map(|arg| type_expr_to_string(arg, scope))
+                .collect::<Vec<_>>();
+            scope.code_gen.generic_expr(name, &args)
         }
-        TypeExprKind::Identifier(id) => code_gen.identifier_expr(id),
     };
     if type_expr.nullable {
-        code_gen.optional_expr(unwrapped.as_str())
+        scope.code_gen.optional_expr(unwrapped.as_str())
     } else {
         unwrapped
     }
 }
 
+pub struct EnumValue {
+    pub name: String,
+    pub value: String,
+    pub doc: Option<String>,
+}
+
 pub struct Enum {
     pub name: String,
     pub value_type: EnumValueType,
-    pub values: Vec<(String, String)>,
+    pub values: Vec<EnumValue>,
+    pub doc: Option<String>,
 }
 impl Enum {
-    fn from_ir(ir_enum: &IrEnum, _code_gen: &dyn CodeGen) -> Self {
-        let (value_type, values): (EnumValueType, Vec<(String, String)>) = match &ir_enum.kind {
+    fn from_ir(ir_enum: &IrEnum, _scope: &Scope) -> Self {
+        let (value_type, values): (EnumValueType, Vec<EnumValue>) = match &ir_enum.kind {
             EnumKind::Integers(integers) => (
                 EnumValueType::Integer,
                 integers
                     .iter()
-                    .map(|val| (val.name.to_string(), val.value.to_string()))
+                    .map(|val| EnumValue {
+                        name: val.name.to_string(),
+                        value: val.value.to_string(),
+                        doc: val.doc.clone(),
+                    })
                     .collect(),
             ),
             EnumKind::Strings(strings) => (
                 EnumValueType::String,
                 strings
                     .iter()
-                    .map(|val| (val.name.to_string(), val.value.clone()))
+                    .map(|val| EnumValue {
+                        name: val.name.to_string(),
+                        value: val.value.clone(),
+                        doc: val.doc.clone(),
+                    })
                     .collect(),
             ),
         };
@@ -79,18 +151,96 @@ impl Enum {
             name: ir_enum.name.to_string(),
             value_type,
             values,
+            doc: ir_enum.doc.clone(),
+        }
+    }
+}
+
+/// A tuple type given a name, for languages without tuples
+pub struct Tuple {
+    pub name: String,
+    pub elements: Vec<String>,
+}
+impl Tuple {
+    fn from_ir(named_tuple: &NamedTuple, scope: &Scope) -> Self {
+        Self {
+            name: named_tuple.name.clone(),
+            elements: named_tuple
+                .elements
+                .iter()
+                .map(|element| type_expr_to_string(element, scope))
+                .collect(),
+        }
+    }
+}
+
+/// The kind of JSON value a type is written as
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum JsonKind {
+    String,
+    Number,
+    Integer,
+    Boolean,
+    Array,
+    Object,
+}
+
+/// The kind of JSON value `ty` is always written as, or `None` if it may be more than one, like
+/// a nullable type or a union
+fn json_kind(ty: &TypeExpr, ir_file: &File) -> Option<JsonKind> {
+    if ty.nullable {
+        return None;
+    }
+    let name = match &ty.kind {
+        TypeExprKind::Primitive(Primitive::String) => return Some(JsonKind::String),
+        TypeExprKind::Primitive(Primitive::Bool) => return Some(JsonKind::Boolean),
+        TypeExprKind::Primitive(Primitive::Number | Primitive::Float { .. }) => {
+            return Some(JsonKind::Number)
         }
+        TypeExprKind::Primitive(Primitive::Int { .. }) => return Some(JsonKind::Integer),
+        TypeExprKind::ArrayOf(_) | TypeExprKind::Tuple(_) => return Some(JsonKind::Array),
+        TypeExprKind::MapOf { .. } => return Some(JsonKind::Object),
+        TypeExprKind::Identifier(name) | TypeExprKind::Generic { name, .. } => name,
+    };
+    match ir_file
+        .type_decls()
+        .find(|type_decl| type_decl.name() == name)?
+    {
+        TypeDecl::Struct(_) => Some(JsonKind::Object),
+        TypeDecl::Enum(ir_enum) => match ir_enum.kind {
+            EnumKind::Strings(_) => Some(JsonKind::String),
+            EnumKind::Integers(_) => Some(JsonKind::Integer),
+        },
+        // The type parameters of a generic alias may be anything
+        TypeDecl::Alias(ir_alias) if ir_alias.type_params.is_empty() => {
+            json_kind(&ir_alias.ty, ir_file)
+        }
+        TypeDecl::Alias(_) | TypeDecl::Union(_) => None,
     }
 }
 
+/// A union variant with a single value
+pub struct Variant {
+    pub name: String,
+    pub ty: String,
+    pub doc: Option<String>,
+    /// Lets backends whose decoders convert between kinds, like a number to a string, check the
+    /// kind before trying an untagged variant
+    pub json_kind: Option<JsonKind>,
+}
+
 pub enum UnionKind {
-    ExternallyTagged(Vec<(String, String)>),
+    ExternallyTagged(Vec<Variant>),
     InternallyTagged(InternallyTaggedUnionBody),
+    /// Variant names are derived from the types, like "StringArray". Like serde, decoding tries
+    /// the variants in order and the first one that decodes wins.
+    Untagged(Vec<Variant>),
 }
 
 pub struct InternallyTaggedUnionBody {
-    pub tag_field: String,
-    pub variants: Vec<Struct>, // Struct::name is the value of tag_field
+    pub tag_field: String,      // The JSON key
+    pub tag_field_name: String, // tag_field as an identifier, for backends that need one
+    pub variants: Vec<Struct>,  // Struct::name is the value of tag_field
     pub adjacently_tagged: Option<AdjacentlyTaggedUnionBody>,
 }
 
@@ -101,107 +251,389 @@ pub struct AdjacentlyTaggedUnionVariant {
 
 pub struct AdjacentlyTaggedUnionBody {
     pub tag_field: String,
-    pub data_field: String,
+    pub tag_field_name: String,
+    pub data_field: String,      // The JSON key
+    pub data_field_name: String, // data_field as an identifier, for backends that need one
     pub variants: Vec<AdjacentlyTaggedUnionVariant>,
 }
 impl AdjacentlyTaggedUnionBody {
     fn from_ir(
         ir_adjacently_tagged_union_body: &IrAdjacentlyTaggedUnionBody,
-        code_gen: &dyn CodeGen,
+        scope: &Scope,
     ) -> Self {
         Self {
             tag_field: ir_adjacently_tagged_union_body.tag_field.clone(),
+            tag_field_name: ir_adjacently_tagged_union_body.tag_field_name.clone(),
             data_field: ir_adjacently_tagged_union_body.data_field.clone(),
+            data_field_name: ir_adjacently_tagged_union_body.data_field_name.clone(),
             variants: ir_adjacently_tagged_union_body
                 .variants
                 .iter()
                 .map(|variant| AdjacentlyTaggedUnionVariant {
                     optional: variant.optional,
-                    ty: type_expr_to_string(&variant.ty, code_gen),
+                    ty: type_expr_to_string(&variant.ty, scope),
                 })
                 .collect(),
         }
     }
 }
 impl UnionKind {
-    fn from_ir(ir_union_kind: &IrUnionKind, code_gen: &dyn CodeGen) -> Self {
+    fn from_ir(ir_union_kind: &IrUnionKind, ir_file: &File, scope: &Scope) -> Self {
         match ir_union_kind {
             IrUnionKind::ExternallyTagged(variants) => Self::ExternallyTagged(
                 variants
                     .iter()
-                    .map(|variants| {
-                        (
-                            variants.name.clone(),
-                            type_expr_to_string(&variants.ty, code_gen),
-                        )
+                    .map(|variant| Variant {
+                        name: variant.name.clone(),
+                        ty: type_expr_to_string(&variant.ty, scope),
+                        doc: variant.doc.clone(),
+                        json_kind: json_kind(&variant.ty, ir_file),
                     })
                     .collect(),
             ),
             IrUnionKind::InternallyTagged(internally_tagged) => {
                 Self::InternallyTagged(InternallyTaggedUnionBody {
                     tag_field: internally_tagged.tag_field.clone(),
+                    tag_field_name: internally_tagged.tag_field_name.clone(),
                     variants: internally_tagged
                         .variants
                         .iter()
-                        .map(|ir_struct| Struct::from_ir(ir_struct, code_gen))
+                        .map(|ir_struct| Struct::from_ir(ir_struct, ir_file, scope))
                         .collect(),
                     adjacently_tagged: internally_tagged.as_adjacently_tagged().map(
                         |ir_adjacently_tagged| {
-                            AdjacentlyTaggedUnionBody::from_ir(&ir_adjacently_tagged, code_gen)
+                            AdjacentlyTaggedUnionBody::from_ir(&ir_adjacently_tagged, scope)
                         },
                     ),
                 })
             }
+            IrUnionKind::Untagged(variants) => Self::Untagged(
+                variants
+                    .iter()
+                    .map(|ty| Variant {
+                        name: ty.descriptive_name(),
+                        ty: type_expr_to_string(ty, scope),
+                        doc: None,
+                        json_kind: json_kind(ty, ir_file),
+                    })
+                    .collect(),
+            ),
         }
     }
 }
 
 pub struct Union {
     pub name: String,
+    pub type_params: Vec<String>,
     pub kind: UnionKind,
+    pub doc: Option<String>,
 }
 impl Union {
-    fn from_ir(ir_union: &IrUnion, code_gen: &dyn CodeGen) -> Self {
+    fn from_ir(ir_union: &IrUnion, ir_file: &File, scope: &Scope) -> Self {
         Self {
             name: ir_union.name.to_string(),
-            kind: UnionKind::from_ir(&ir_union.kind, code_gen),
+            type_params: ir_union.type_params.clone(),
+            kind: UnionKind::from_ir(&ir_union.kind, ir_file, scope),
+            doc: ir_union.doc.clone(),
+        }
+    }
+}
+
+pub struct Alias {
+    pub name: String,
+    pub type_params: Vec<String>,
+    pub ty: String,
+    pub newtype: bool,
+    pub doc: Option<String>,
+}
+impl Alias {
+    fn from_ir(ir_alias: &IrAlias, scope: &Scope) -> Self {
+        Self {
+            name: ir_alias.name.to_string(),
+            type_params: ir_alias.type_params.clone(),
+            ty: type_expr_to_string(&ir_alias.ty, scope),
+            newtype: ir_alias.newtype,
+            doc: ir_alias.doc.clone(),
+        }
+    }
+}
+
+/// The type a method sends or receives, which is a stream of them if `stream` is set
+pub struct MethodBody {
+    pub ty: String,
+    pub stream: bool,
+}
+impl MethodBody {
+    fn from_ir(ir_body: &IrBody, scope: &Scope) -> Option<Self> {
+        let (type_decl, stream) = match (&ir_body.unary, &ir_body.stream_item) {
+            (Some(type_decl), _) => (type_decl, false),
+            (None, Some(type_decl)) => (type_decl, true),
+            (None, None) => return None,
+        };
+        Some(Self {
+            ty: scope.code_gen.identifier_expr(type_decl.name()),
+            stream,
+        })
+    }
+}
+
+pub struct Method {
+    pub name: String, // As declared, which is also the name on the wire
+    pub request: Option<MethodBody>,
+    pub response: Option<MethodBody>,
+    pub doc: Option<String>,
+}
+impl Method {
+    fn from_ir(ir_method: &IrMethod, scope: &Scope) -> Self {
+        Self {
+            name: ir_method.name.clone(),
+            request: MethodBody::from_ir(&ir_method.request, scope),
+            response: MethodBody::from_ir(&ir_method.response, scope),
+            doc: ir_method.doc.clone(),
+        }
+    }
+}
+
+/// The request and response types of the methods are declared separately
+pub struct Service {
+    pub name: String,
+    pub methods: Vec<Method>,
+    pub doc: Option<String>,
+}
+impl Service {
+    fn from_ir(ir_service: &IrService, scope: &Scope) -> Self {
+        Self {
+            name: ir_service.name.clone(),
+            methods: ir_service
+                .methods
+                .iter()
+                .map(|ir_method| Method::from_ir(ir_method, scope))
+                .collect(),
+            doc: ir_service.doc.clone(),
+        }
+    }
+}
+
+/// Converts a name from the IR, which is a valid identifier in the source's case, to the case a
+/// backend's naming policy asks for. The result is still a valid identifier, but may be a keyword.
+pub fn convert_name(name: &str, case: Case) -> String {
+    // Digits stay attached to their word, so `oauth2Token` becomes `oauth2_token`
+    let converted = name
+        .with_boundaries(&Boundary::defaults())
+        .without_boundaries(&[
+            Boundary::UpperDigit,
+            Boundary::LowerDigit,
+            Boundary::DigitLower,
+        ])
+        .to_case(case);
+    match converted.chars().next() {
+        None => name.to_string(),
+        Some(first) if first.is_ascii_digit() => format!("_{converted}"),
+        Some(_) => converted,
+    }
+}
+
+/// Converts names declared next to each other with [`convert_name`], numbering the ones that
+/// would clash, like `fooBar` and `foo_bar` in snake_case
+pub fn convert_names<'a>(names: impl IntoIterator<Item = &'a str>, case: Case) -> Vec<String> {
+    let mut converted: Vec<String> = vec![];
+    for name in names {
+        let base = convert_name(name, case);
+        let mut unique = base.clone();
+        let mut suffix = 1;
+        while converted.contains(&unique) {
+            unique = format!("{base}_{suffix}");
+            suffix += 1;
+        }
+        converted.push(unique);
+    }
+    converted
+}
+
+/// Prefixes each line of a doc comment, for languages with line doc comments like `///`
+pub fn line_doc_comment(doc: Option<&str>, prefix: &str) -> impl BlockModifier {
+    let lines = doc.into_iter().flat_map(str::lines).map(|line| {
+        if line.is_empty() {
+            prefix.to_string()
+        } else {
+            format!("{prefix} {line}")
+        }
+    });
+    flatten(lines.collect::<Vec<_>>())
+}
+
+/// A `/** ... */` doc comment, on one line if the text fits on one
+pub fn block_doc_comment(doc: Option<&str>) -> impl BlockModifier {
+    // A `*/` in the text would end the comment early
+    let doc = doc.map(|doc| doc.replace("*/", "*&#47;"));
+    let lines = doc.as_deref().map(|doc| doc.lines().collect::<Vec<_>>());
+    match lines.as_deref() {
+        None | Some([]) => flatten![],
+        Some([line]) => flatten![format!("/** {line} */")],
+        Some(_) => flatten!["/**", line_doc_comment(doc.as_deref(), " *"), " */"],
+    }
+}
+
+/// A double-quoted string literal with C-style escapes, which the languages with such literals
+/// share
+pub fn quote(string: &str) -> String {
+    let mut quoted = String::from('"');
+    for c in string.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            _ => quoted.push(c),
         }
     }
+    quoted.push('"');
+    quoted
+}
+
+/// Names the types of a union's variants after the union, like `ShapeCircle`. Backends declare
+/// them next to the union rather than nested in it, as nested ones would shadow the types they
+/// refer to.
+pub fn variant_type_names<'a>(
+    union: &str,
+    names: impl IntoIterator<Item = &'a str>,
+) -> Vec<String> {
+    convert_names(names, Case::Pascal)
+        .into_iter()
+        .map(|name| format!("{union}{name}"))
+        .collect()
 }
 
 pub trait CodeGen {
     fn head(&self) -> Block;
     fn identifier_expr(&self, id: &str) -> String;
+    /// A reference to a type declared further down the file, or to the one being declared
+    fn forward_identifier_expr(&self, id: &str) -> String {
+        self.identifier_expr(id)
+    }
     fn primitive_expr(&self, primitive: Primitive) -> String;
     fn array_expr(&self, elem: &str) -> String;
+    fn map_expr(&self, key: &str, value: &str) -> String;
+    fn tuple_expr(&self, elements: &[String]) -> String;
     fn optional_expr(&self, unwrapped: &str) -> String;
+    fn generic_expr(&self, name: &str, args: &[String]) -> String;
+
+    /// Generic declarations are monomorphized before being passed to backends without generics
+    fn supports_generics(&self) -> bool {
+        true
+    }
+
+    /// References to aliases that aren't newtypes are replaced with the types they stand for, for
+    /// backends without type aliases. Only aliases that can't be inlined reach `alias_decl`.
+    fn supports_aliases(&self) -> bool {
+        true
+    }
+
+    /// Tuple types are replaced with named declarations from `tuple_decl` for backends without
+    /// tuples that serialize as arrays
+    fn supports_tuples(&self) -> bool {
+        true
+    }
+    fn tuple_decl(&self, _tuple: Tuple) -> Block {
+        unreachable!("only called when supports_tuples() is false")
+    }
 
     fn struct_decl(&self, struct_: Struct) -> Block;
     fn enum_decl(&self, enum_: Enum) -> Block;
     fn union_decl(&self, union: Union) -> Block;
+    /// Newtypes must serialize exactly like the type they wrap
+    fn alias_decl(&self, alias: Alias) -> Block;
+    /// Lists the methods of the service
+    fn service_decl(&self, service: Service) -> Block;
+
+    /// Lays out the file, for languages that put the declarations inside something or only
+    /// import what they use. `ir_file` is the file the declarations were generated from, before
+    /// its tuples were hoisted.
+    fn file_block(&self, _ir_file: &File, decls: Vec<Block>) -> Block {
+        block![
+            flatten(self.head()),
+            flatten(decls.into_iter().map(|decl| flatten![flatten(decl), ""])),
+        ]
+    }
 }
 
-pub fn generate(ir_file: &File, code_gen: &dyn CodeGen) -> String {
-    use tser_ir::type_decl::TypeDecl;
+fn type_decl_block(type_decl: &TypeDecl, ir_file: &File, scope: &Scope) -> Block {
+    let code_gen = scope.code_gen;
+    match type_decl {
+        TypeDecl::Struct(ir_struct) => {
+            code_gen.struct_decl(Struct::from_ir(ir_struct, ir_file, scope))
+        }
+        TypeDecl::Union(ir_union) => code_gen.union_decl(Union::from_ir(ir_union, ir_file, scope)),
+        TypeDecl::Enum(ir_enum) => code_gen.enum_decl(Enum::from_ir(ir_enum, scope)),
+        TypeDecl::Alias(ir_alias) => code_gen.alias_decl(Alias::from_ir(ir_alias, scope)),
+    }
+}
+
+/// Fails if the backend has no generics and the generic types can't be copied for each
+/// instantiation
+pub fn generate(ir_file: &File, code_gen: &dyn CodeGen) -> Result<String, MonomorphizeError> {
     use tser_ir::Item;
-    let head = code_gen.head();
-    let item_blocks = ir_file.items.iter().map(|item| {
-        let item_block = match item {
-            Item::Service(_) => unimplemented!(),
-            Item::TypeDecl(type_decl) => match type_decl {
-                TypeDecl::Struct(ir_struct) => {
-                    code_gen.struct_decl(Struct::from_ir(ir_struct, code_gen))
-                }
-                TypeDecl::Union(ir_union) => {
-                    code_gen.union_decl(Union::from_ir(ir_union, code_gen))
-                }
-                TypeDecl::Enum(ir_enum) => code_gen.enum_decl(Enum::from_ir(ir_enum, code_gen)),
-            },
+    let monomorphized;
+    let ir_file = if code_gen.supports_generics() {
+        ir_file
+    } else {
+        monomorphized = monomorphize(ir_file)?;
+        &monomorphized
+    };
+    let inlined;
+    let ir_file = if code_gen.supports_aliases() {
+        ir_file
+    } else {
+        inlined = inline_aliases(ir_file);
+        &inlined
+    };
+    let unhoisted = ir_file;
+    let (hoisted, named_tuples);
+    let ir_file = if code_gen.supports_tuples() {
+        named_tuples = vec![];
+        ir_file
+    } else {
+        (hoisted, named_tuples) = hoist_tuples(ir_file);
+        &hoisted
+    };
+    let mut decls = vec![];
+    let mut declared = HashSet::new();
+    for item in &ir_file.items {
+        // The request and response types of a service come before it
+        let type_decls = match item {
+            Item::Service(ir_service) => ir_service
+                .methods
+                .iter()
+                .flat_map(IrMethod::body_decls)
+                .collect(),
+            Item::TypeDecl(type_decl) => vec![type_decl],
         };
-        flatten![flatten(item_block), ""]
-    });
-
-    let file_block = block![flatten(head), flatten(item_blocks)];
+        for type_decl in type_decls {
+            let scope = Scope {
+                code_gen,
+                declared: &declared,
+            };
+            decls.push(type_decl_block(type_decl, ir_file, &scope));
+            declared.insert(type_decl.name().to_string());
+        }
+        if let Item::Service(ir_service) = item {
+            let scope = Scope {
+                code_gen,
+                declared: &declared,
+            };
+            decls.push(code_gen.service_decl(Service::from_ir(ir_service, &scope)));
+        }
+    }
+    let scope = Scope {
+        code_gen,
+        declared: &declared,
+    };
+    decls.extend(
+        named_tuples
+            .iter()
+            .map(|named_tuple| code_gen.tuple_decl(Tuple::from_ir(named_tuple, &scope))),
+    );
 
-    file_block.string()
+    Ok(code_gen.file_block(unhoisted, decls).string())
 }
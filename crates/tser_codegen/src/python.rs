@@ -0,0 +1,369 @@
+use crate::{
+    convert_names, line_doc_comment, quote, variant_type_names, Alias, CodeGen, Enum,
+    EnumValueType, Field, InternallyTaggedUnionBody, Service, Struct, Union, UnionKind,
+};
+use convert_case::Case;
+use tser_block::{block, flatten, Block, BlockModifier};
+use tser_ir::type_expr::primitive::Primitive;
+
+/// Generates pydantic models, which need pydantic 2.12 or later for `exclude_if`. Types declared
+/// further down are referred to by string forward references, which pydantic resolves when a
+/// model is first used, so declarations can come in any order.
+pub struct PythonCodeGen;
+
+fn ident(id: &str) -> String {
+    if KEYWORDS.contains(&id) {
+        format!("{id}_")
+    } else {
+        id.to_string()
+    }
+}
+/// A docstring for the class, field or enum member it follows or starts
+fn docstring(doc: Option<&str>) -> impl BlockModifier {
+    let doc = doc.map(|doc| doc.replace('\\', "\\\\").replace('"', "\\\""));
+    let lines = doc.as_deref().map(|doc| doc.lines().collect::<Vec<_>>());
+    let lines = match lines.as_deref() {
+        None | Some([]) => vec![],
+        Some([line]) => vec![format!("\"\"\"{line}\"\"\"")],
+        Some([first, rest @ ..]) => std::iter::once(format!("\"\"\"{first}"))
+            .chain(rest.iter().map(|line| line.to_string()))
+            .chain(["\"\"\"".to_string()])
+            .collect(),
+    };
+    flatten(lines)
+}
+
+fn optional_field_type(ty: &str) -> String {
+    // A nullable type is already an Optional, don't nest it
+    if ty.starts_with("Optional[") {
+        ty.to_string()
+    } else {
+        format!("Optional[{ty}]")
+    }
+}
+
+/// A field as a model declares it, which is how tag fields are declared too
+struct ModelField {
+    name: String,
+    wire_name: String,
+    ty: String,
+    default: Option<String>,
+    exclude_none: bool,
+    doc: Option<String>,
+}
+
+impl ModelField {
+    fn from_field(field: &Field) -> Self {
+        Self {
+            name: field.name.clone(),
+            wire_name: field.wire_name.clone(),
+            ty: if field.optional {
+                optional_field_type(&field.ty)
+            } else {
+                field.ty.clone()
+            },
+            default: field.optional.then(|| "None".to_string()),
+            // Left out when missing, rather than sent as null
+            exclude_none: field.optional,
+            doc: field.doc.clone(),
+        }
+    }
+
+    fn tag(tag_field: &str, tag: &str) -> Self {
+        Self {
+            name: tag_field.to_string(),
+            wire_name: tag_field.to_string(),
+            ty: format!("Literal[{}]", quote(tag)),
+            default: Some(quote(tag)),
+            exclude_none: false,
+            doc: None,
+        }
+    }
+}
+
+fn snake_case_names(fields: &[ModelField]) -> Vec<String> {
+    convert_names(fields.iter().map(|field| field.name.as_str()), Case::Snake)
+        .into_iter()
+        .map(|name| {
+            // pydantic doesn't allow leading underscores, like the one in front of a digit
+            if name.starts_with('_') {
+                format!("field{name}")
+            } else {
+                ident(&name)
+            }
+        })
+        .collect()
+}
+
+/// Fields are snake_case, with an alias for the name on the wire if it differs. The model is
+/// configured to serialize by alias, and to accept either name.
+fn model_class(name: &str, doc: Option<&str>, fields: &[ModelField], forbid_extra: bool) -> Block {
+    let names = snake_case_names(fields);
+    let aliased = fields
+        .iter()
+        .zip(&names)
+        .any(|(field, name)| *name != field.wire_name);
+    let config = [
+        forbid_extra.then_some("extra=\"forbid\""),
+        aliased.then_some("validate_by_name=True, serialize_by_alias=True"),
+    ]
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>();
+    let lines = fields.iter().zip(&names).map(|(field, name)| {
+        let mut args = vec![];
+        if let Some(default) = &field.default {
+            args.push(format!("default={default}"));
+        }
+        if *name != field.wire_name {
+            args.push(format!("alias={}", quote(&field.wire_name)));
+        }
+        if field.exclude_none {
+            args.push("exclude_if=lambda value: value is None".to_string());
+        }
+        let value = match (args.as_slice(), &field.default) {
+            ([], _) => String::new(),
+            ([_], Some(default)) => format!(" = {default}"),
+            _ => format!(" = Field({})", args.join(", ")),
+        };
+        flatten![
+            format!("{name}: {}{value}", field.ty),
+            docstring(field.doc.as_deref()),
+        ]
+    });
+    block![
+        format!("class {name}(BaseModel):"),
+        block![
+            docstring(doc),
+            (!config.is_empty())
+                .then(|| format!("model_config = ConfigDict({})", config.join(", "))),
+            flatten(lines),
+            (doc.is_none() && config.is_empty() && fields.is_empty()).then_some("pass"),
+        ],
+    ]
+}
+
+fn union_of(types: &[String]) -> String {
+    format!("Union[{}]", types.join(", "))
+}
+
+/// Enum members are UPPER_SNAKE_CASE
+fn enum_class(
+    name: &str,
+    base: &str,
+    doc: Option<&str>,
+    members: impl IntoIterator<Item = (String, String, Option<String>)>,
+) -> Block {
+    let members = members.into_iter().collect::<Vec<_>>();
+    let member_names = convert_names(
+        members.iter().map(|(name, _, _)| name.as_str()),
+        Case::UpperSnake,
+    );
+    block![
+        format!("class {name}({base}):"),
+        block![
+            docstring(doc),
+            flatten(members.iter().zip(member_names).map(
+                |((_, value, doc), member_name)| flatten![
+                    format!("{member_name} = {value}"),
+                    docstring(doc.as_deref()),
+                ]
+            )),
+            (doc.is_none() && members.is_empty()).then_some("pass"),
+        ],
+    ]
+}
+
+impl CodeGen for PythonCodeGen {
+    fn head(&self) -> Block {
+        block![
+            "# Requires pydantic>=2.12",
+            "import enum",
+            "from typing import Annotated, Dict, List, Literal, NewType, Optional, Tuple, Union",
+            "",
+            "from pydantic import BaseModel, ConfigDict, Field",
+            "",
+        ]
+    }
+
+    fn identifier_expr(&self, id: &str) -> String {
+        ident(id)
+    }
+
+    fn forward_identifier_expr(&self, id: &str) -> String {
+        quote(&ident(id))
+    }
+
+    fn primitive_expr(&self, primitive: Primitive) -> String {
+        match primitive {
+            Primitive::String => "str".to_string(),
+            Primitive::Bool => "bool".to_string(),
+            Primitive::Number | Primitive::Float { .. } => "float".to_string(),
+            Primitive::Int { .. } => "int".to_string(),
+        }
+    }
+
+    fn array_expr(&self, elem: &str) -> String {
+        format!("List[{elem}]")
+    }
+
+    fn map_expr(&self, key: &str, value: &str) -> String {
+        format!("Dict[{key}, {value}]")
+    }
+
+    fn tuple_expr(&self, elements: &[String]) -> String {
+        format!("Tuple[{}]", elements.join(", "))
+    }
+
+    fn optional_expr(&self, unwrapped: &str) -> String {
+        format!("Optional[{unwrapped}]")
+    }
+
+    fn generic_expr(&self, _name: &str, _args: &[String]) -> String {
+        unreachable!("generic declarations are monomorphized for Python")
+    }
+
+    // Generic models would need a TypeVar declared for each type parameter
+    fn supports_generics(&self) -> bool {
+        false
+    }
+
+    fn struct_decl(&self, struct_: Struct) -> Block {
+        let fields = struct_
+            .fields
+            .iter()
+            .map(ModelField::from_field)
+            .collect::<Vec<_>>();
+        model_class(
+            &ident(&struct_.name),
+            struct_.doc.as_deref(),
+            &fields,
+            false,
+        )
+    }
+
+    fn enum_decl(&self, enum_: Enum) -> Block {
+        let base = match enum_.value_type {
+            EnumValueType::String => "enum.Enum",
+            EnumValueType::Integer => "enum.IntEnum",
+        };
+        enum_class(
+            &ident(&enum_.name),
+            base,
+            enum_.doc.as_deref(),
+            enum_.values.into_iter().map(|value| {
+                let literal = match enum_.value_type {
+                    EnumValueType::String => quote(&value.value),
+                    EnumValueType::Integer => value.value,
+                };
+                (value.name, literal, value.doc)
+            }),
+        )
+    }
+
+    fn union_decl(&self, union: Union) -> Block {
+        let name = ident(&union.name);
+        let (classes, ty) = match union.kind {
+            // Adjacently tagged unions are internally tagged ones with a single field in each
+            // variant, which is how pydantic sees them
+            UnionKind::InternallyTagged(InternallyTaggedUnionBody {
+                tag_field,
+                variants,
+                ..
+            }) => {
+                let class_names =
+                    variant_type_names(&name, variants.iter().map(|variant| variant.name.as_str()));
+                let classes = variants
+                    .iter()
+                    .zip(&class_names)
+                    .map(|(variant, class_name)| {
+                        let fields = std::iter::once(ModelField::tag(&tag_field, &variant.name))
+                            .chain(variant.fields.iter().map(ModelField::from_field))
+                            .collect::<Vec<_>>();
+                        model_class(class_name, variant.doc.as_deref(), &fields, false)
+                    })
+                    .collect::<Vec<_>>();
+                let discriminator = snake_case_names(&[ModelField::tag(&tag_field, "")]).remove(0);
+                let ty = format!(
+                    "Annotated[{}, Field(discriminator={})]",
+                    union_of(&class_names),
+                    quote(&discriminator)
+                );
+                (classes, ty)
+            }
+            // Objects with a single field named after the variant, and nothing else
+            UnionKind::ExternallyTagged(variants) => {
+                let class_names =
+                    variant_type_names(&name, variants.iter().map(|variant| variant.name.as_str()));
+                let classes = variants
+                    .iter()
+                    .zip(&class_names)
+                    .map(|(variant, class_name)| {
+                        let field = ModelField {
+                            name: variant.name.clone(),
+                            wire_name: variant.name.clone(),
+                            ty: variant.ty.clone(),
+                            default: None,
+                            exclude_none: false,
+                            doc: None,
+                        };
+                        model_class(class_name, variant.doc.as_deref(), &[field], true)
+                    })
+                    .collect::<Vec<_>>();
+                (classes, union_of(&class_names))
+            }
+            UnionKind::Untagged(variants) => {
+                let types = variants
+                    .iter()
+                    .map(|variant| variant.ty.clone())
+                    .collect::<Vec<_>>();
+                let ty = format!(
+                    "Annotated[{}, Field(union_mode=\"left_to_right\")]",
+                    union_of(&types)
+                );
+                (vec![], ty)
+            }
+        };
+        block![
+            flatten(
+                classes
+                    .into_iter()
+                    .map(|class| flatten![flatten(class), ""])
+            ),
+            line_doc_comment(union.doc.as_deref(), "#"),
+            format!("{name} = {ty}"),
+        ]
+    }
+
+    fn alias_decl(&self, alias: Alias) -> Block {
+        let name = ident(&alias.name);
+        let doc = line_doc_comment(alias.doc.as_deref(), "#");
+        if alias.newtype {
+            block![
+                doc,
+                format!("{name} = NewType({}, {})", quote(&name), alias.ty)
+            ]
+        } else {
+            block![doc, format!("{name} = {}", alias.ty)]
+        }
+    }
+
+    fn service_decl(&self, service: Service) -> Block {
+        enum_class(
+            &format!("{}Method", service.name),
+            "enum.Enum",
+            service.doc.as_deref(),
+            service
+                .methods
+                .into_iter()
+                .map(|method| (method.name.clone(), quote(&method.name), method.doc)),
+        )
+    }
+}
+
+const KEYWORDS: &[&str] = &[
+    "False", "None", "True", "and", "as", "assert", "async", "await", "break", "class", "continue",
+    "def", "del", "elif", "else", "except", "finally", "for", "from", "global", "if", "import",
+    "in", "is", "lambda", "nonlocal", "not", "or", "pass", "raise", "return", "try", "while",
+    "with", "yield",
+];
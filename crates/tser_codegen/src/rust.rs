@@ -1,16 +1,121 @@
-use crate::{CodeGen, Enum, EnumValueType, Struct, Union};
-use tser_block::{block, Block};
+use crate::{
+    convert_names, line_doc_comment, quote, Alias, CodeGen, Enum, EnumValue, EnumValueType, Field,
+    InternallyTaggedUnionBody, Service, Struct, Union, UnionKind, Variant,
+};
+use convert_case::Case;
+use tser_block::{block, flatten, Block};
 use tser_ir::type_expr::primitive::Primitive;
 
 pub struct RustCodeGen;
 
-const DERIVE_LINE: &str = "#[derive(Serialize, Deserialize, Clone, Eq, PartialEq, Debug)]";
+// Eq is left out because f64 doesn't implement it
+const DERIVE_LINE: &str = "#[derive(Serialize, Deserialize, Clone, PartialEq, Debug)]";
 
-fn quote(string: &str) -> String {
-    format!("\"{}\"", string.escape_default())
-}
 fn ident(id: &str) -> String {
-    id.to_string() // TODO: check keywords
+    match id {
+        // These can't be raw identifiers
+        "crate" | "self" | "Self" | "super" => format!("{id}_"),
+        _ if KEYWORDS.contains(&id) => format!("r#{id}"),
+        _ => id.to_string(),
+    }
+}
+/// The name serde sees for an identifier
+fn unraw(ident: &str) -> &str {
+    ident.strip_prefix("r#").unwrap_or(ident)
+}
+/// What `#[serde(rename_all = "camelCase")]` renames a field to, following serde's rules
+fn serde_camel_case(field: &str) -> String {
+    let mut pascal = String::new();
+    let mut capitalize = true;
+    for c in field.chars() {
+        if c == '_' {
+            capitalize = true;
+        } else if capitalize {
+            pascal.push(c.to_ascii_uppercase());
+            capitalize = false;
+        } else {
+            pascal.push(c);
+        }
+    }
+    let mut chars = pascal.chars();
+    match chars.next() {
+        Some(first) => first.to_ascii_lowercase().to_string() + chars.as_str(),
+        None => pascal,
+    }
+}
+fn type_params_decl(type_params: &[String]) -> String {
+    if type_params.is_empty() {
+        String::new()
+    } else {
+        format!("<{}>", type_params.join(", "))
+    }
+}
+fn rename_attr(name: &str) -> String {
+    format!("#[serde(rename = {})]", quote(name))
+}
+
+/// Fields are snake_case. Renaming them back to camelCase with `rename_all` is preferred
+/// over renaming them one by one, so the attribute is returned if any field needs it.
+fn fields_block(fields: Vec<Field>, visibility: &str) -> (Option<&'static str>, Block) {
+    let idents = convert_names(fields.iter().map(|field| field.name.as_str()), Case::Snake)
+        .into_iter()
+        .map(|name| ident(&name))
+        .collect::<Vec<_>>();
+    let camel_case = fields.iter().zip(&idents).any(|(field, ident)| {
+        unraw(ident) != field.wire_name && serde_camel_case(unraw(ident)) == field.wire_name
+    });
+    let lines = fields.into_iter().zip(idents).map(|(field, ident)| {
+        let Field {
+            wire_name,
+            ty,
+            optional,
+            doc,
+            ..
+        } = field;
+        let serde_name = if camel_case {
+            serde_camel_case(unraw(&ident))
+        } else {
+            unraw(&ident).to_string()
+        };
+        flatten![
+            line_doc_comment(doc.as_deref(), "///"),
+            (serde_name != wire_name).then(|| rename_attr(&wire_name)),
+            optional.then_some(r#"#[serde(default, skip_serializing_if = "Option::is_none")]"#),
+            if optional {
+                format!("{visibility}{ident}: Option<{ty}>,")
+            } else {
+                format!("{visibility}{ident}: {ty},")
+            },
+        ]
+    });
+    (
+        camel_case.then_some(r#"#[serde(rename_all = "camelCase")]"#),
+        block![flatten(lines)],
+    )
+}
+
+/// Enum variants are PascalCase
+fn variant_idents<'a>(names: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    convert_names(names, Case::Pascal)
+        .into_iter()
+        .map(|name| ident(&name))
+        .collect()
+}
+
+fn struct_variant(variant: Struct, variant_ident: &str) -> Block {
+    let doc = line_doc_comment(variant.doc.as_deref(), "///");
+    if variant.fields.is_empty() {
+        return block![doc, rename_attr(&variant.name), format!("{variant_ident},")];
+    }
+    let (rename_all, fields) = fields_block(variant.fields, "");
+    block![
+        doc,
+        rename_attr(&variant.name),
+        rename_all,
+        format!("{variant_ident} {{"),
+        fields,
+        "},",
+    ]
 }
 
 impl CodeGen for RustCodeGen {
@@ -22,53 +127,223 @@ impl CodeGen for RustCodeGen {
     }
     fn primitive_expr(&self, primitive: Primitive) -> String {
         match primitive {
-            Primitive::String => "String",
-            Primitive::Number => "f64",
-            Primitive::Bool => "bool",
+            Primitive::String => "String".to_string(),
+            Primitive::Number => "f64".to_string(),
+            Primitive::Int { bits, signed: true } => format!("i{bits}"),
+            Primitive::Int {
+                bits,
+                signed: false,
+            } => format!("u{bits}"),
+            Primitive::Float { bits } => format!("f{bits}"),
+            Primitive::Bool => "bool".to_string(),
         }
-        .to_string()
     }
     fn array_expr(&self, elem: &str) -> String {
         format!("Vec<{}>", elem)
     }
+    fn map_expr(&self, key: &str, value: &str) -> String {
+        // Qualified so that files without maps don't get an unused import
+        format!("std::collections::HashMap<{key}, {value}>")
+    }
+    fn tuple_expr(&self, elements: &[String]) -> String {
+        match elements {
+            [element] => format!("({element},)"),
+            _ => format!("({})", elements.join(", ")),
+        }
+    }
     fn optional_expr(&self, unwrapped: &str) -> String {
         format!("Option<{}>", unwrapped)
     }
+    fn generic_expr(&self, name: &str, args: &[String]) -> String {
+        format!("{}<{}>", ident(name), args.join(", "))
+    }
     fn struct_decl(&self, struct_: Struct) -> Block {
+        let (rename_all, fields) = fields_block(struct_.fields, "pub ");
         block![
+            line_doc_comment(struct_.doc.as_deref(), "///"),
             DERIVE_LINE,
-            format!("pub struct {} {{", struct_.name),
-            block(
-                struct_
-                    .fields
-                    .into_iter()
-                    .map(|(field, ty)| format!("pub {field}: {ty},"))
+            rename_all,
+            format!(
+                "pub struct {}{} {{",
+                struct_.name,
+                type_params_decl(&struct_.type_params)
             ),
+            fields,
             "}",
         ]
     }
     fn enum_decl(&self, enum_: Enum) -> Block {
+        let idents = variant_idents(enum_.values.iter().map(|value| value.name.as_str()));
         block![
+            line_doc_comment(enum_.doc.as_deref(), "///"),
             DERIVE_LINE,
             match enum_.value_type {
                 EnumValueType::Integer => Some("#[repr(i64)]"),
                 EnumValueType::String => None,
             },
             format!("pub enum {} {{", enum_.name),
-            block(
-                enum_
-                    .values
-                    .into_iter()
-                    .map(|(name, val)| match enum_.value_type {
-                        EnumValueType::Integer => format!("{} = {},", ident(&name), val),
+            block(enum_.values.into_iter().zip(idents).map(
+                |(EnumValue { name, value, doc }, ident)| flatten![
+                    line_doc_comment(doc.as_deref(), "///"),
+                    match enum_.value_type {
+                        // Serialized by name, which has to stay the same
+                        EnumValueType::Integer if unraw(&ident) != name =>
+                            format!("{} {ident} = {value},", rename_attr(&name)),
+                        EnumValueType::Integer => format!("{ident} = {value},"),
                         EnumValueType::String =>
-                            format!("#[serde(rename = {})] {},", quote(&val), ident(&name)),
-                    })
-            ),
+                            format!("#[serde(rename = {})] {ident},", quote(&value)),
+                    }
+                ]
+            )),
             "}"
         ]
     }
-    fn union_decl(&self, _union: Union) -> Block {
-        unimplemented!()
+    fn union_decl(&self, union: Union) -> Block {
+        let (tag_attr, variants) = match union.kind {
+            UnionKind::ExternallyTagged(variants) => {
+                let idents = variant_idents(variants.iter().map(|variant| variant.name.as_str()));
+                (
+                    None,
+                    block![flatten(variants.into_iter().zip(idents).map(
+                        |(Variant { name, ty, doc, .. }, ident)| flatten![
+                            line_doc_comment(doc.as_deref(), "///"),
+                            format!("{} {ident}({ty}),", rename_attr(&name)),
+                        ]
+                    ))],
+                )
+            }
+            UnionKind::InternallyTagged(InternallyTaggedUnionBody {
+                variants,
+                adjacently_tagged: Some(adjacently_tagged),
+                ..
+            }) => (
+                Some(format!(
+                    "#[serde(tag = {}, content = {})]",
+                    quote(&adjacently_tagged.tag_field),
+                    quote(&adjacently_tagged.data_field)
+                )),
+                block![flatten(
+                    variants
+                        .iter()
+                        .zip(adjacently_tagged.variants)
+                        .zip(variant_idents(
+                            variants.iter().map(|variant| variant.name.as_str())
+                        ))
+                        .map(|((variant, data), ident)| {
+                            let ty = if data.optional {
+                                format!("Option<{}>", data.ty)
+                            } else {
+                                data.ty
+                            };
+                            flatten![
+                                line_doc_comment(variant.doc.as_deref(), "///"),
+                                format!("{} {ident}({ty}),", rename_attr(&variant.name)),
+                            ]
+                        })
+                )],
+            ),
+            UnionKind::InternallyTagged(InternallyTaggedUnionBody {
+                tag_field,
+                variants,
+                adjacently_tagged: None,
+                ..
+            }) => (
+                Some(format!("#[serde(tag = {})]", quote(&tag_field))),
+                block![flatten(
+                    variant_idents(variants.iter().map(|variant| variant.name.as_str()))
+                        .into_iter()
+                        .zip(variants)
+                        .map(|(ident, variant)| flatten(struct_variant(variant, &ident)))
+                )],
+            ),
+            UnionKind::Untagged(variants) => (
+                Some("#[serde(untagged)]".to_string()),
+                block![flatten(
+                    variant_idents(variants.iter().map(|variant| variant.name.as_str()))
+                        .into_iter()
+                        .zip(variants)
+                        .map(|(ident, Variant { ty, .. })| format!("{ident}({ty}),"))
+                )],
+            ),
+        };
+        block![
+            line_doc_comment(union.doc.as_deref(), "///"),
+            DERIVE_LINE,
+            tag_attr,
+            format!(
+                "pub enum {}{} {{",
+                union.name,
+                type_params_decl(&union.type_params)
+            ),
+            variants,
+            "}",
+        ]
+    }
+    fn alias_decl(&self, alias: Alias) -> Block {
+        let name = format!("{}{}", alias.name, type_params_decl(&alias.type_params));
+        let doc = line_doc_comment(alias.doc.as_deref(), "///");
+        if alias.newtype {
+            block![
+                doc,
+                DERIVE_LINE,
+                "#[serde(transparent)]",
+                format!("pub struct {name}(pub {});", alias.ty),
+            ]
+        } else {
+            block![doc, format!("pub type {name} = {};", alias.ty)]
+        }
+    }
+    fn service_decl(&self, service: Service) -> Block {
+        let idents = variant_idents(service.methods.iter().map(|method| method.name.as_str()));
+        block![
+            line_doc_comment(service.doc.as_deref(), "///"),
+            "#[derive(Clone, Copy, PartialEq, Eq, Hash, Debug)]",
+            format!("pub enum {}Method {{", service.name),
+            block(
+                service
+                    .methods
+                    .iter()
+                    .zip(&idents)
+                    .map(|(method, ident)| flatten![
+                        line_doc_comment(method.doc.as_deref(), "///"),
+                        format!("{ident},"),
+                    ])
+            ),
+            "}",
+            "",
+            format!("impl {}Method {{", service.name),
+            block![
+                format!(
+                    "pub const ALL: [Self; {}] = [{}];",
+                    idents.len(),
+                    idents
+                        .iter()
+                        .map(|ident| format!("Self::{ident}"))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+                "",
+                "/// The name of the method as declared",
+                "pub fn name(self) -> &'static str {",
+                block![
+                    "match self {",
+                    block(service.methods.iter().zip(&idents).map(|(method, ident)| {
+                        format!("Self::{ident} => {},", quote(&method.name))
+                    })),
+                    "}",
+                ],
+                "}",
+            ],
+            "}",
+        ]
     }
 }
+
+// Strict and reserved keywords of the 2021 edition
+const KEYWORDS: &[&str] = &[
+    "as", "async", "await", "break", "const", "continue", "crate", "dyn", "else", "enum", "extern",
+    "false", "fn", "for", "if", "impl", "in", "let", "loop", "match", "mod", "move", "mut", "pub",
+    "ref", "return", "self", "Self", "static", "struct", "super", "trait", "true", "type",
+    "unsafe", "use", "where", "while", "abstract", "become", "box", "do", "final", "macro",
+    "override", "priv", "try", "typeof", "unsized", "virtual", "yield",
+];
@@ -1,22 +1,292 @@
-use crate::{CodeGen, Enum, EnumValueType, Struct, Union};
-use tser_block::{block, Block};
+use crate::{
+    convert_names, line_doc_comment, quote, Alias, CodeGen, Enum, EnumValue, EnumValueType, Field,
+    InternallyTaggedUnionBody, Service, Struct, Tuple, Union, UnionKind, Variant,
+};
+use convert_case::Case;
+use tser_block::{block, flatten, Block};
 use tser_ir::type_expr::primitive::Primitive;
 
 pub struct SwiftCodeGen;
 
 fn ident(id: &str) -> String {
     if KEYWORDS.contains(&id) {
-        format!("`${id}`")
+        format!("`{id}`")
     } else {
         id.to_string()
     }
 }
-fn quote(string: &str) -> String {
-    format!("\"{}\"", string.escape_default())
-}
 
 const PROTOCOLS: &str = "Codable, Equatable, Hashable";
 
+fn type_params_decl(type_params: &[String]) -> String {
+    if type_params.is_empty() {
+        return String::new();
+    }
+    let type_params = type_params
+        .iter()
+        .map(|type_param| format!("{}: Codable & Equatable & Hashable", ident(type_param)))
+        .collect::<Vec<_>>();
+    format!("<{}>", type_params.join(", "))
+}
+
+/// Properties and enum cases are camelCase. They may come from tags and quoted keys, which
+/// aren't always identifiers, like `bankTransfer` for `'bank-transfer'`.
+fn swift_names<'a>(names: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    let sanitized = names
+        .into_iter()
+        .map(|name| {
+            name.chars()
+                .map(|c| if c.is_alphanumeric() { c } else { '_' })
+                .collect::<String>()
+        })
+        .collect::<Vec<_>>();
+    convert_names(sanitized.iter().map(String::as_str), Case::Camel)
+}
+
+fn optional_field_type(ty: &str) -> String {
+    // A nullable type is already an Optional, don't nest it
+    if ty.ends_with('?') {
+        ty.to_string()
+    } else {
+        format!("{ty}?")
+    }
+}
+fn field_type(field: &Field) -> String {
+    if field.optional {
+        optional_field_type(&field.ty)
+    } else {
+        field.ty.clone()
+    }
+}
+
+fn decode_expr(ty: &str, optional: bool, key: &str) -> String {
+    if optional {
+        format!(
+            "container.decodeIfPresent({}.self, forKey: .{})",
+            ty.strip_suffix('?').unwrap_or(ty),
+            ident(key)
+        )
+    } else {
+        format!("container.decode({ty}.self, forKey: .{})", ident(key))
+    }
+}
+fn encode_stmt(value: &str, optional: bool, key: &str) -> String {
+    let method = if optional {
+        "encodeIfPresent"
+    } else {
+        "encode"
+    };
+    format!("try container.{method}({value}, forKey: .{})", ident(key))
+}
+
+/// The cases of a `CodingKeys` enum, as pairs of the case name and the JSON key. Each key has
+/// one case, and a name taken by a different key is numbered, like `the_kind` for `the-kind` and
+/// `the_kind_1` for `the_kind`.
+struct CodingKeys(Vec<(String, String)>);
+
+impl CodingKeys {
+    /// `keys` are pairs of an identifier and the JSON key
+    fn new<'a>(keys: impl IntoIterator<Item = (&'a str, &'a str)>) -> Self {
+        let mut cases: Vec<(String, String)> = vec![];
+        for (name, key) in keys {
+            if cases.iter().any(|(_, existing)| existing == key) {
+                continue;
+            }
+            let mut unique = name.to_string();
+            let mut suffix = 1;
+            while cases.iter().any(|(existing, _)| *existing == unique) {
+                unique = format!("{name}_{suffix}");
+                suffix += 1;
+            }
+            cases.push((unique, key.to_string()));
+        }
+        Self(cases)
+    }
+
+    /// The case name of a JSON key passed to `new`
+    fn case(&self, key: &str) -> &str {
+        self.0
+            .iter()
+            .find(|(_, existing)| existing == key)
+            .map(|(name, _)| name.as_str())
+            .expect("every key should have a case")
+    }
+
+    fn decl(&self) -> Block {
+        block![
+            "private enum CodingKeys: String, CodingKey {",
+            block(self.0.iter().map(|(name, key)| format!(
+                "case {} = {}",
+                ident(name),
+                quote(key)
+            ))),
+            "}",
+        ]
+    }
+}
+
+/// A case of a Swift enum with associated values, and how it is decoded and encoded.
+struct UnionCase {
+    doc: Option<String>,
+    decl: String,
+    decode: String,
+    pattern: String,
+    encode: Vec<String>,
+}
+
+impl UnionCase {
+    fn with_doc(self, doc: Option<String>) -> Self {
+        Self { doc, ..self }
+    }
+
+    fn from_struct(variant: &Struct, case_name: &str, tag_field: &str, keys: &CodingKeys) -> Self {
+        let name = ident(case_name);
+        let encode_tag = encode_stmt(&quote(&variant.name), false, keys.case(tag_field));
+        if variant.fields.is_empty() {
+            return Self {
+                doc: variant.doc.clone(),
+                decl: format!("case {name}"),
+                decode: format!("self = .{name}"),
+                pattern: format!(".{name}"),
+                encode: vec![encode_tag],
+            };
+        }
+        let labels = swift_names(variant.fields.iter().map(|field| field.name.as_str()));
+        let join = |f: &dyn Fn((&Field, &String)) -> String| {
+            variant
+                .fields
+                .iter()
+                .zip(&labels)
+                .map(f)
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+        Self {
+            doc: variant.doc.clone(),
+            decl: format!(
+                "case {name}({})",
+                join(&|(field, label)| format!("{}: {}", ident(label), field_type(field)))
+            ),
+            decode: format!(
+                "self = try .{name}({})",
+                join(&|(field, label)| format!(
+                    "{}: {}",
+                    ident(label),
+                    decode_expr(&field.ty, field.optional, keys.case(&field.wire_name))
+                ))
+            ),
+            pattern: format!("let .{name}({})", join(&|(_, label)| ident(label))),
+            encode: std::iter::once(encode_tag)
+                .chain(variant.fields.iter().zip(&labels).map(|(field, label)| {
+                    encode_stmt(&ident(label), field.optional, keys.case(&field.wire_name))
+                }))
+                .collect(),
+        }
+    }
+
+    fn single_value(
+        name: &str,
+        ty: &str,
+        optional: bool,
+        key: &str,
+        encode_tag: Option<String>,
+    ) -> Self {
+        let name = ident(name);
+        let ty_decl = if optional {
+            optional_field_type(ty)
+        } else {
+            ty.to_string()
+        };
+        Self {
+            doc: None,
+            decl: format!("case {name}({ty_decl})"),
+            decode: format!("self = try .{name}({})", decode_expr(ty, optional, key)),
+            pattern: format!("let .{name}(value)"),
+            encode: encode_tag
+                .into_iter()
+                .chain([encode_stmt("value", optional, key)])
+                .collect(),
+        }
+    }
+
+    fn untagged(name: &str, ty: &str) -> Self {
+        let name = ident(name);
+        Self {
+            doc: None,
+            decl: format!("case {name}({ty})"),
+            decode: format!("self = .{name}(value)"),
+            pattern: format!("let .{name}(value)"),
+            encode: vec!["try container.encode(value)".to_string()],
+        }
+    }
+}
+
+const KEYED_DECODING_CONTAINER: &str =
+    "let container = try decoder.container(keyedBy: CodingKeys.self)";
+
+fn decode_untagged_body(types: &[String], cases: &[UnionCase]) -> Block {
+    block![
+        "let container = try decoder.singleValueContainer()",
+        flatten(
+            types
+                .iter()
+                .zip(cases)
+                .enumerate()
+                .map(|(idx, (ty, case))| flatten![
+                    format!(
+                        "{}if let value = try? container.decode({ty}.self) {{",
+                        if idx == 0 { "" } else { "} else " }
+                    ),
+                    block![case.decode.as_str()],
+                ])
+        ),
+        "} else {",
+        block![
+            r#"throw DecodingError.dataCorruptedError(in: container, debugDescription: "Data doesn't match any variant")"#
+        ],
+        "}",
+    ]
+}
+
+/// `tag_case` is the case of the tag in `CodingKeys`
+fn decode_tagged_body(tag_case: &str, tags: &[String], cases: &[UnionCase]) -> Block {
+    block![
+        KEYED_DECODING_CONTAINER,
+        format!(
+            "let tag = try container.decode(String.self, forKey: .{})",
+            ident(tag_case)
+        ),
+        "switch tag {",
+        flatten(tags.iter().zip(cases).map(|(tag, case)| flatten![
+            format!("case {}:", quote(tag)),
+            block![case.decode.as_str()],
+        ])),
+        "default:",
+        block![format!(
+            r#"throw DecodingError.dataCorruptedError(forKey: .{}, in: container, debugDescription: "Unknown tag \(tag)")"#,
+            ident(tag_case)
+        )],
+        "}",
+    ]
+}
+
+fn decode_externally_tagged_body(names: &[String], cases: &[UnionCase]) -> Block {
+    block![
+        KEYED_DECODING_CONTAINER,
+        "guard container.allKeys.count == 1 else {",
+        block![
+            r#"throw DecodingError.dataCorrupted(DecodingError.Context(codingPath: container.codingPath, debugDescription: "Expected exactly one key"))"#
+        ],
+        "}",
+        "switch container.allKeys[0] {",
+        flatten(names.iter().zip(cases).map(|(name, case)| flatten![
+            format!("case .{}:", ident(name)),
+            block![case.decode.as_str()],
+        ])),
+        "}",
+    ]
+}
+
 impl CodeGen for SwiftCodeGen {
     fn head(&self) -> Block {
         block![]
@@ -28,30 +298,130 @@ impl CodeGen for SwiftCodeGen {
 
     fn primitive_expr(&self, primitive: Primitive) -> String {
         match primitive {
-            Primitive::String => "String",
-            Primitive::Bool => "Bool",
-            Primitive::Number => "Double",
+            Primitive::String => "String".to_string(),
+            Primitive::Bool => "Bool".to_string(),
+            Primitive::Number | Primitive::Float { bits: 64 } => "Double".to_string(),
+            Primitive::Float { .. } => "Float".to_string(),
+            Primitive::Int { bits, signed: true } => format!("Int{bits}"),
+            Primitive::Int {
+                bits,
+                signed: false,
+            } => format!("UInt{bits}"),
         }
-        .to_string()
     }
 
     fn array_expr(&self, elem: &str) -> String {
         format!("[{elem}]")
     }
 
+    fn map_expr(&self, key: &str, value: &str) -> String {
+        format!("[{key}: {value}]")
+    }
+
+    fn tuple_expr(&self, _elements: &[String]) -> String {
+        unreachable!("Swift tuples aren't Codable, so they are hoisted into structs")
+    }
+
     fn optional_expr(&self, unwrapped: &str) -> String {
         format!("{unwrapped}?")
     }
 
+    fn generic_expr(&self, name: &str, args: &[String]) -> String {
+        format!("{}<{}>", ident(name), args.join(", "))
+    }
+
+    fn supports_tuples(&self) -> bool {
+        false
+    }
+
+    fn tuple_decl(&self, tuple: Tuple) -> Block {
+        // Encoded as an array, with the elements as properties named like tuple elements
+        let names = (0..tuple.elements.len())
+            .map(|idx| format!("_{idx}"))
+            .collect::<Vec<_>>();
+        let elements = || names.iter().zip(&tuple.elements);
+        block![
+            format!("public struct {}: {} {{", ident(&tuple.name), PROTOCOLS),
+            block![
+                flatten(elements().map(|(name, ty)| format!("public var {name}: {ty}"))),
+                "",
+                // Declaring init(from:) drops the memberwise initializer
+                format!(
+                    "public init({}) {{",
+                    elements()
+                        .map(|(name, ty)| format!("_ {name}: {ty}"))
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ),
+                block(names.iter().map(|name| format!("self.{name} = {name}"))),
+                "}",
+                "",
+                "public init(from decoder: Decoder) throws {",
+                block![
+                    "var container = try decoder.unkeyedContainer()",
+                    flatten(elements().map(|(name, ty)| match ty.strip_suffix('?') {
+                        Some(unwrapped) =>
+                            format!("{name} = try container.decodeIfPresent({unwrapped}.self)"),
+                        None => format!("{name} = try container.decode({ty}.self)"),
+                    })),
+                ],
+                "}",
+                "",
+                "public func encode(to encoder: Encoder) throws {",
+                block![
+                    "var container = encoder.unkeyedContainer()",
+                    flatten(
+                        names
+                            .iter()
+                            .map(|name| format!("try container.encode({name})"))
+                    ),
+                ],
+                "}",
+            ],
+            "}",
+        ]
+    }
+
     fn struct_decl(&self, struct_: Struct) -> Block {
+        let names = swift_names(struct_.fields.iter().map(|field| field.name.as_str()));
         block![
-            format!("public struct {}: {} {{", ident(&struct_.name), PROTOCOLS),
-            block(
+            line_doc_comment(struct_.doc.as_deref(), "///"),
+            format!(
+                "public struct {}{}: {} {{",
+                ident(&struct_.name),
+                type_params_decl(&struct_.type_params),
+                PROTOCOLS
+            ),
+            block![
+                flatten(
+                    struct_
+                        .fields
+                        .iter()
+                        .zip(&names)
+                        .map(|(field, name)| flatten![
+                            line_doc_comment(field.doc.as_deref(), "///"),
+                            format!("public var {}: {}", ident(name), field_type(field)),
+                        ])
+                ),
+                // Synthesized Codable conformance uses the property names unless told otherwise
                 struct_
                     .fields
                     .iter()
-                    .map(|(name, ty)| format!("public var {}: {}", name, ty))
-            ),
+                    .zip(&names)
+                    .any(|(field, name)| *name != field.wire_name)
+                    .then(|| flatten![
+                        "",
+                        flatten(
+                            CodingKeys::new(
+                                names
+                                    .iter()
+                                    .zip(&struct_.fields)
+                                    .map(|(name, field)| (name.as_str(), field.wire_name.as_str()))
+                            )
+                            .decl()
+                        )
+                    ]),
+            ],
             "}"
         ]
     }
@@ -62,26 +432,253 @@ impl CodeGen for SwiftCodeGen {
             EnumValueType::Integer => "Int64",
         };
         block![
+            line_doc_comment(enum_.doc.as_deref(), "///"),
             format!(
                 "public enum {}: {}, {} {{",
                 ident(&enum_.name),
                 value_type,
                 PROTOCOLS
             ),
-            block(enum_.values.into_iter().map(|(name, val)| format!(
-                "case {} = {}",
-                ident(&name),
-                match enum_.value_type {
-                    EnumValueType::Integer => val,
-                    EnumValueType::String => quote(&val),
-                }
-            ))),
+            block(
+                enum_
+                    .values
+                    .into_iter()
+                    .map(|EnumValue { name, value, doc }| flatten![
+                        line_doc_comment(doc.as_deref(), "///"),
+                        format!(
+                            "case {} = {}",
+                            ident(&name),
+                            match enum_.value_type {
+                                EnumValueType::Integer => value,
+                                EnumValueType::String => quote(&value),
+                            }
+                        ),
+                    ])
+            ),
             "}"
         ]
     }
 
-    fn union_decl(&self, _union: Union) -> Block {
-        todo!()
+    fn union_decl(&self, union: Union) -> Block {
+        // Synthesized Codable conformance can't express serde's enum representations,
+        // so init(from:) and encode(to:) are written out
+        let (cases, coding_keys, decode_body) = match union.kind {
+            UnionKind::ExternallyTagged(variants) => {
+                // The variant names are the keys, and name both the cases and their keys
+                let names = swift_names(variants.iter().map(|variant| variant.name.as_str()));
+                let keys = CodingKeys::new(
+                    names
+                        .iter()
+                        .zip(&variants)
+                        .map(|(name, variant)| (name.as_str(), variant.name.as_str())),
+                );
+                let cases = variants
+                    .iter()
+                    .zip(&names)
+                    .map(
+                        |(
+                            Variant {
+                                name: key, ty, doc, ..
+                            },
+                            name,
+                        )| {
+                            UnionCase::single_value(name, ty, false, keys.case(key), None)
+                                .with_doc(doc.clone())
+                        },
+                    )
+                    .collect::<Vec<_>>();
+                let decode_body = decode_externally_tagged_body(&names, &cases);
+                (cases, Some(keys), decode_body)
+            }
+            UnionKind::InternallyTagged(InternallyTaggedUnionBody {
+                variants,
+                adjacently_tagged: Some(adjacently_tagged),
+                ..
+            }) => {
+                let names = swift_names([
+                    adjacently_tagged.tag_field_name.as_str(),
+                    adjacently_tagged.data_field_name.as_str(),
+                ]);
+                let keys = CodingKeys::new([
+                    (names[0].as_str(), adjacently_tagged.tag_field.as_str()),
+                    (names[1].as_str(), adjacently_tagged.data_field.as_str()),
+                ]);
+                let tag_case = keys.case(&adjacently_tagged.tag_field);
+                let data_case = keys.case(&adjacently_tagged.data_field);
+                let tags = variants
+                    .iter()
+                    .map(|variant| variant.name.clone())
+                    .collect::<Vec<_>>();
+                let cases = variants
+                    .iter()
+                    .zip(&adjacently_tagged.variants)
+                    .zip(swift_names(tags.iter().map(String::as_str)))
+                    .map(|((variant, data), case_name)| {
+                        UnionCase::single_value(
+                            &case_name,
+                            &data.ty,
+                            data.optional,
+                            data_case,
+                            Some(encode_stmt(&quote(&variant.name), false, tag_case)),
+                        )
+                        .with_doc(variant.doc.clone())
+                    })
+                    .collect::<Vec<_>>();
+                let decode_body = decode_tagged_body(tag_case, &tags, &cases);
+                (cases, Some(keys), decode_body)
+            }
+            UnionKind::InternallyTagged(InternallyTaggedUnionBody {
+                tag_field,
+                tag_field_name,
+                variants,
+                adjacently_tagged: None,
+            }) => {
+                let fields = variants
+                    .iter()
+                    .flat_map(|variant| &variant.fields)
+                    .collect::<Vec<_>>();
+                // Fields of different variants may share a key, and then share a case
+                let names = swift_names(
+                    std::iter::once(tag_field_name.as_str())
+                        .chain(fields.iter().map(|field| field.name.as_str())),
+                );
+                let keys = CodingKeys::new(
+                    names.iter().map(String::as_str).zip(
+                        std::iter::once(tag_field.as_str())
+                            .chain(fields.iter().map(|field| field.wire_name.as_str())),
+                    ),
+                );
+                let tags = variants
+                    .iter()
+                    .map(|variant| variant.name.clone())
+                    .collect::<Vec<_>>();
+                let cases = variants
+                    .iter()
+                    .zip(swift_names(tags.iter().map(String::as_str)))
+                    .map(|(variant, case_name)| {
+                        UnionCase::from_struct(variant, &case_name, &tag_field, &keys)
+                    })
+                    .collect::<Vec<_>>();
+                let decode_body = decode_tagged_body(keys.case(&tag_field), &tags, &cases);
+                (cases, Some(keys), decode_body)
+            }
+            UnionKind::Untagged(variants) => {
+                let types = variants
+                    .iter()
+                    .map(|variant| variant.ty.clone())
+                    .collect::<Vec<_>>();
+                let cases = variants
+                    .iter()
+                    .zip(swift_names(
+                        variants.iter().map(|variant| variant.name.as_str()),
+                    ))
+                    .map(|(variant, case_name)| UnionCase::untagged(&case_name, &variant.ty))
+                    .collect::<Vec<_>>();
+                let decode_body = decode_untagged_body(&types, &cases);
+                (cases, None, decode_body)
+            }
+        };
+        let encoding_container = if coding_keys.is_some() {
+            "var container = encoder.container(keyedBy: CodingKeys.self)"
+        } else {
+            "var container = encoder.singleValueContainer()"
+        };
+        block![
+            line_doc_comment(union.doc.as_deref(), "///"),
+            format!(
+                "public enum {}{}: {} {{",
+                ident(&union.name),
+                type_params_decl(&union.type_params),
+                PROTOCOLS
+            ),
+            block![
+                flatten(cases.iter().map(|case| flatten![
+                    line_doc_comment(case.doc.as_deref(), "///"),
+                    case.decl.as_str(),
+                ])),
+                "",
+                coding_keys.map(|coding_keys| flatten![flatten(coding_keys.decl()), ""]),
+                "public init(from decoder: Decoder) throws {",
+                decode_body,
+                "}",
+                "",
+                "public func encode(to encoder: Encoder) throws {",
+                block![
+                    encoding_container,
+                    "switch self {",
+                    flatten(cases.iter().map(|case| flatten![
+                        format!("case {}:", case.pattern),
+                        block(case.encode.iter().map(String::as_str)),
+                    ])),
+                    "}",
+                ],
+                "}",
+            ],
+            "}",
+        ]
+    }
+
+    fn alias_decl(&self, alias: Alias) -> Block {
+        let name = ident(&alias.name);
+        let doc = line_doc_comment(alias.doc.as_deref(), "///");
+        if !alias.newtype {
+            // The constraints come from whatever uses the type parameters
+            let type_params = if alias.type_params.is_empty() {
+                String::new()
+            } else {
+                let type_params = alias.type_params.iter().map(|type_param| ident(type_param));
+                format!("<{}>", type_params.collect::<Vec<_>>().join(", "))
+            };
+            return block![
+                doc,
+                format!("public typealias {name}{type_params} = {}", alias.ty)
+            ];
+        }
+        // Encoded as the wrapped value rather than an object with a rawValue key
+        block![
+            doc,
+            format!(
+                "public struct {name}{}: {PROTOCOLS} {{",
+                type_params_decl(&alias.type_params)
+            ),
+            block![
+                format!("public var rawValue: {}", alias.ty),
+                "",
+                format!("public init(_ rawValue: {}) {{", alias.ty),
+                block!["self.rawValue = rawValue"],
+                "}",
+                "",
+                "public init(from decoder: Decoder) throws {",
+                block![format!(
+                    "rawValue = try decoder.singleValueContainer().decode({}.self)",
+                    alias.ty
+                )],
+                "}",
+                "",
+                "public func encode(to encoder: Encoder) throws {",
+                block![
+                    "var container = encoder.singleValueContainer()",
+                    "try container.encode(rawValue)",
+                ],
+                "}",
+            ],
+            "}",
+        ]
+    }
+
+    fn service_decl(&self, service: Service) -> Block {
+        block![
+            line_doc_comment(service.doc.as_deref(), "///"),
+            format!(
+                "public enum {}Method: String, CaseIterable, {} {{",
+                service.name, PROTOCOLS
+            ),
+            block(service.methods.into_iter().map(|method| flatten![
+                line_doc_comment(method.doc.as_deref(), "///"),
+                format!("case {} = {}", ident(&method.name), quote(&method.name)),
+            ])),
+            "}",
+        ]
     }
 }
 
@@ -0,0 +1,131 @@
+use crate::service::Service;
+use crate::type_decl::TypeDecl;
+use crate::type_expr::{TypeExpr, TypeExprKind};
+use crate::{File, Item};
+
+/// A tuple type with a name made up for it, like `NumberNumberTuple` for `[number, number]`
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct NamedTuple {
+    pub name: String,
+    pub elements: Vec<TypeExpr>,
+}
+
+fn hoist_type_expr(type_expr: &mut TypeExpr, tuples: &mut Vec<NamedTuple>) {
+    match &mut type_expr.kind {
+        TypeExprKind::ArrayOf(element) => hoist_type_expr(element, tuples),
+        TypeExprKind::MapOf { key, value } => {
+            hoist_type_expr(key, tuples);
+            hoist_type_expr(value, tuples);
+        }
+        TypeExprKind::Generic { args, .. } => {
+            args.iter_mut().for_each(|arg| hoist_type_expr(arg, tuples))
+        }
+        TypeExprKind::Tuple(elements) => {
+            elements
+                .iter_mut()
+                .for_each(|element| hoist_type_expr(element, tuples));
+            let elements = elements.clone();
+            let name = type_expr.kind.descriptive_name();
+            if !tuples.iter().any(|tuple| tuple.name == name) {
+                tuples.push(NamedTuple {
+                    name: name.clone(),
+                    elements,
+                });
+            }
+            type_expr.kind = TypeExprKind::Identifier(name);
+        }
+        TypeExprKind::Identifier(_) | TypeExprKind::Primitive(_) => {}
+    }
+}
+
+fn hoist_type_decl(type_decl: &mut TypeDecl, tuples: &mut Vec<NamedTuple>) {
+    type_decl.for_each_type_expr_mut(&mut |type_expr| hoist_type_expr(type_expr, tuples));
+}
+
+/// Replaces tuple types with references to named tuples, which are returned in order of first use.
+/// For backends whose target language has no tuples that can be serialized as JSON arrays.
+pub fn hoist_tuples(file: &File) -> (File, Vec<NamedTuple>) {
+    let mut tuples = vec![];
+    let mut file = file.clone();
+    for item in &mut file.items {
+        match item {
+            Item::TypeDecl(type_decl) => hoist_type_decl(type_decl, &mut tuples),
+            Item::Service(Service { methods, .. }) => {
+                for method in methods {
+                    method.for_each_type_expr_mut(&mut |type_expr| {
+                        hoist_type_expr(type_expr, &mut tuples)
+                    });
+                }
+            }
+        }
+    }
+    (file, tuples)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::type_decl::struct_::{Field, Struct};
+    use crate::type_expr::primitive::Primitive;
+
+    fn ty(kind: TypeExprKind) -> TypeExpr {
+        TypeExpr {
+            nullable: false,
+            kind,
+        }
+    }
+    fn point() -> TypeExpr {
+        ty(TypeExprKind::Tuple(vec![
+            ty(TypeExprKind::Primitive(Primitive::Number)),
+            ty(TypeExprKind::Primitive(Primitive::Number)),
+        ]))
+    }
+
+    #[test]
+    fn nested_tuples() {
+        let field = |name: &str, ty: TypeExpr| Field {
+            name: name.to_string(),
+            wire_name: name.to_string(),
+            ty,
+            optional: false,
+            doc: None,
+        };
+        let file = File {
+            items: vec![Item::TypeDecl(TypeDecl::Struct(Struct {
+                name: "Shape".to_string(),
+                type_params: vec![],
+                extends: vec![],
+                fields: vec![
+                    field("points", ty(TypeExprKind::ArrayOf(Box::new(point())))),
+                    field(
+                        "labeled",
+                        ty(TypeExprKind::Tuple(vec![
+                            ty(TypeExprKind::Primitive(Primitive::String)),
+                            point(),
+                        ])),
+                    ),
+                    field("origin", point()),
+                ],
+                doc: None,
+            }))],
+        };
+        let (file, tuples) = hoist_tuples(&file);
+        let names = tuples
+            .iter()
+            .map(|tuple| tuple.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["NumberNumberTuple", "StringNumberNumberTupleTuple"]);
+        assert_eq!(
+            tuples[1].elements[1],
+            ty(TypeExprKind::Identifier("NumberNumberTuple".to_string()))
+        );
+        let shape = match &file.items[0] {
+            Item::TypeDecl(TypeDecl::Struct(st)) => st,
+            _ => unreachable!(),
+        };
+        assert_eq!(
+            shape.fields[2].ty,
+            ty(TypeExprKind::Identifier("NumberNumberTuple".to_string()))
+        );
+    }
+}
@@ -0,0 +1,217 @@
+use crate::service::Service;
+use crate::type_decl::alias::Alias;
+use crate::type_decl::TypeDecl;
+use crate::type_expr::{TypeExpr, TypeExprKind};
+use crate::{File, Item};
+use std::collections::HashMap;
+
+fn for_each_identifier(type_expr: &TypeExpr, f: &mut impl FnMut(&str)) {
+    match &type_expr.kind {
+        TypeExprKind::Identifier(name) => f(name),
+        TypeExprKind::ArrayOf(element) => for_each_identifier(element, f),
+        TypeExprKind::MapOf { key, value } => {
+            for_each_identifier(key, f);
+            for_each_identifier(value, f);
+        }
+        TypeExprKind::Tuple(elements) => elements
+            .iter()
+            .for_each(|element| for_each_identifier(element, f)),
+        TypeExprKind::Generic { args, .. } => {
+            args.iter().for_each(|arg| for_each_identifier(arg, f))
+        }
+        TypeExprKind::Primitive(_) => {}
+    }
+}
+
+/// Whether the alias refers to itself, directly or through other aliases
+fn is_recursive(name: &str, aliases: &HashMap<&str, &TypeExpr>) -> bool {
+    let mut pending = vec![name];
+    let mut visited = vec![];
+    while let Some(current) = pending.pop() {
+        let mut recursive = false;
+        for_each_identifier(aliases[current], &mut |referenced| {
+            if referenced == name {
+                recursive = true;
+            } else if let Some((&referenced, _)) = aliases.get_key_value(referenced) {
+                if !visited.contains(&referenced) {
+                    visited.push(referenced);
+                    pending.push(referenced);
+                }
+            }
+        });
+        if recursive {
+            return true;
+        }
+    }
+    false
+}
+
+fn inline_type_expr(type_expr: &mut TypeExpr, aliases: &HashMap<&str, &TypeExpr>) {
+    match &mut type_expr.kind {
+        TypeExprKind::Identifier(name) => {
+            if let Some(&ty) = aliases.get(name.as_str()) {
+                let nullable = type_expr.nullable;
+                *type_expr = ty.clone();
+                type_expr.nullable |= nullable;
+                inline_type_expr(type_expr, aliases);
+            }
+        }
+        TypeExprKind::ArrayOf(element) => inline_type_expr(element, aliases),
+        TypeExprKind::MapOf { key, value } => {
+            inline_type_expr(key, aliases);
+            inline_type_expr(value, aliases);
+        }
+        TypeExprKind::Tuple(elements) => elements
+            .iter_mut()
+            .for_each(|element| inline_type_expr(element, aliases)),
+        TypeExprKind::Generic { args, .. } => args
+            .iter_mut()
+            .for_each(|arg| inline_type_expr(arg, aliases)),
+        TypeExprKind::Primitive(_) => {}
+    }
+}
+
+/// Replaces references to aliases that aren't newtypes with the types they stand for, and
+/// removes their declarations. For backends whose target language has no type aliases. Generic
+/// aliases and recursive ones, which can't be inlined, are kept, and so are the request and
+/// response bodies of services.
+pub fn inline_aliases(file: &File) -> File {
+    let candidates = file
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            Item::TypeDecl(TypeDecl::Alias(Alias {
+                name,
+                type_params,
+                ty,
+                newtype: false,
+                ..
+            })) if type_params.is_empty() => Some((name.as_str(), ty)),
+            _ => None,
+        })
+        .collect::<HashMap<&str, &TypeExpr>>();
+    let aliases = candidates
+        .iter()
+        .filter(|(name, _)| !is_recursive(name, &candidates))
+        .map(|(&name, &ty)| (name, ty))
+        .collect::<HashMap<&str, &TypeExpr>>();
+    let items = file
+        .items
+        .iter()
+        .filter(|item| match item {
+            Item::TypeDecl(type_decl) => !aliases.contains_key(type_decl.name()),
+            Item::Service(_) => true,
+        })
+        .map(|item| {
+            let mut item = item.clone();
+            match &mut item {
+                Item::TypeDecl(type_decl) => type_decl
+                    .for_each_type_expr_mut(&mut |type_expr| inline_type_expr(type_expr, &aliases)),
+                Item::Service(Service { methods, .. }) => {
+                    for method in methods {
+                        method.for_each_type_expr_mut(&mut |type_expr| {
+                            inline_type_expr(type_expr, &aliases)
+                        });
+                    }
+                }
+            }
+            item
+        })
+        .collect();
+    File { items }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::type_decl::struct_::{Field, Struct};
+    use crate::type_expr::primitive::Primitive;
+
+    fn ty(kind: TypeExprKind) -> TypeExpr {
+        TypeExpr {
+            nullable: false,
+            kind,
+        }
+    }
+    fn identifier(name: &str) -> TypeExpr {
+        ty(TypeExprKind::Identifier(name.to_string()))
+    }
+    fn alias(name: &str, ty: TypeExpr, newtype: bool) -> Item {
+        Item::TypeDecl(TypeDecl::Alias(Alias {
+            name: name.to_string(),
+            type_params: vec![],
+            ty,
+            newtype,
+            doc: None,
+        }))
+    }
+    fn struct_item(name: &str, field_ty: TypeExpr) -> Item {
+        Item::TypeDecl(TypeDecl::Struct(Struct {
+            name: name.to_string(),
+            type_params: vec![],
+            extends: vec![],
+            fields: vec![Field {
+                name: "field".to_string(),
+                wire_name: "field".to_string(),
+                ty: field_ty,
+                optional: false,
+                doc: None,
+            }],
+            doc: None,
+        }))
+    }
+    fn field_ty(file: &File, name: &str) -> TypeExpr {
+        match file.find_type_decl(name) {
+            Some(TypeDecl::Struct(st)) => st.fields[0].ty.clone(),
+            _ => unreachable!(),
+        }
+    }
+
+    #[test]
+    fn inline_through_aliases() {
+        let string = ty(TypeExprKind::Primitive(Primitive::String));
+        let file = File {
+            items: vec![
+                alias("UserId", string.clone(), true),
+                alias("Name", string.clone(), false),
+                alias(
+                    "Names",
+                    TypeExpr {
+                        nullable: true,
+                        kind: TypeExprKind::ArrayOf(Box::new(identifier("Name"))),
+                    },
+                    false,
+                ),
+                struct_item("User", identifier("UserId")),
+                struct_item("Group", identifier("Names")),
+            ],
+        };
+        let inlined = inline_aliases(&file);
+        let names = inlined.items.iter().map(Item::name).collect::<Vec<_>>();
+        assert_eq!(names, ["UserId", "User", "Group"]);
+        assert_eq!(field_ty(&inlined, "User"), identifier("UserId"));
+        assert_eq!(
+            field_ty(&inlined, "Group"),
+            TypeExpr {
+                nullable: true,
+                kind: TypeExprKind::ArrayOf(Box::new(string)),
+            }
+        );
+    }
+
+    #[test]
+    fn keep_recursive_aliases() {
+        let nested = ty(TypeExprKind::ArrayOf(Box::new(identifier("Nested"))));
+        let file = File {
+            items: vec![
+                alias("Nested", nested.clone(), false),
+                alias("Tree", identifier("Nested"), false),
+                struct_item("Forest", identifier("Tree")),
+            ],
+        };
+        let inlined = inline_aliases(&file);
+        let names = inlined.items.iter().map(Item::name).collect::<Vec<_>>();
+        assert_eq!(names, ["Nested", "Forest"]);
+        assert_eq!(field_ty(&inlined, "Forest"), identifier("Nested"));
+    }
+}
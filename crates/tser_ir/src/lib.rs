@@ -1,10 +1,15 @@
+pub mod hoist_tuples;
+pub mod inline_aliases;
+pub mod monomorphize;
+pub mod project;
 pub mod service;
 pub mod type_decl;
 pub mod type_expr;
 
-use service::Service;
+use service::{Method, Service};
 use type_decl::TypeDecl;
 
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub enum Item {
     TypeDecl(TypeDecl),
     Service(Service),
@@ -19,6 +24,31 @@ impl Item {
     }
 }
 
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct File {
     pub items: Vec<Item>,
 }
+
+impl File {
+    pub fn find_type_decl(&self, name: &str) -> Option<&TypeDecl> {
+        self.items.iter().find_map(|item| match item {
+            Item::TypeDecl(type_decl) if type_decl.name() == name => Some(type_decl),
+            _ => None,
+        })
+    }
+
+    /// The type declarations, including the request and response types of services
+    pub fn type_decls(&self) -> impl Iterator<Item = &TypeDecl> {
+        self.items.iter().flat_map(|item| {
+            let (type_decl, service) = match item {
+                Item::TypeDecl(type_decl) => (Some(type_decl), None),
+                Item::Service(service) => (None, Some(service)),
+            };
+            type_decl.into_iter().chain(
+                service
+                    .into_iter()
+                    .flat_map(|service| service.methods.iter().flat_map(Method::body_decls)),
+            )
+        })
+    }
+}
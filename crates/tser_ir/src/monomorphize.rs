@@ -0,0 +1,417 @@
+use crate::service::Service;
+use crate::type_decl::TypeDecl;
+use crate::type_expr::{TypeExpr, TypeExprKind};
+use crate::{File, Item};
+use std::collections::{HashMap, HashSet};
+use std::fmt::{Display, Formatter};
+
+// Polymorphic recursion like `interface Foo<T> { foo: Foo<T[]> }` never stops instantiating
+const MAX_INSTANTIATION_DEPTH: usize = 32;
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum MonomorphizeError {
+    /// Names the generic type, which is instantiated with ever deeper type arguments
+    TooDeep(String),
+    /// Names the instance, which is also the name of a declared type or of another instance
+    NameClash(String),
+}
+
+impl Display for MonomorphizeError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::TooDeep(name) => write!(
+                f,
+                "`{name}` refers to itself with other type arguments, so it can't be copied for each instantiation"
+            ),
+            Self::NameClash(name) => write!(
+                f,
+                "The copy of a generic type named `{name}` would clash with another type"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for MonomorphizeError {}
+
+struct Monomorphizer<'a> {
+    generic_decls: HashMap<&'a str, &'a TypeDecl>,
+    /// The names of the declarations that aren't generic
+    declared: HashSet<&'a str>,
+    /// The instances by name, as the generic type expressions they were named after
+    instantiated: HashMap<String, TypeExpr>,
+    items: Vec<Item>,
+    depth: usize,
+}
+
+impl<'a> Monomorphizer<'a> {
+    fn instantiate(
+        &mut self,
+        generic_decl: &TypeDecl,
+        args: &[TypeExpr],
+        name: String,
+    ) -> Result<(), MonomorphizeError> {
+        let params = generic_decl.type_params();
+        let args = params
+            .iter()
+            .map(String::as_str)
+            .zip(args)
+            .collect::<HashMap<&str, &TypeExpr>>();
+        let mut type_decl = generic_decl.clone();
+        match &mut type_decl {
+            TypeDecl::Struct(st) => {
+                st.name = name;
+                st.type_params.clear();
+            }
+            TypeDecl::Union(union) => {
+                union.name = name;
+                union.type_params.clear();
+            }
+            TypeDecl::Alias(alias) => {
+                alias.name = name;
+                alias.type_params.clear();
+            }
+            TypeDecl::Enum(_) => unreachable!("enums can't be generic"),
+        }
+        type_decl.for_each_type_expr_mut(&mut |type_expr| type_expr.substitute(&args));
+        self.resolve_type_decl(&mut type_decl)?;
+        self.items.push(Item::TypeDecl(type_decl));
+        Ok(())
+    }
+
+    fn resolve_type_expr(&mut self, type_expr: &mut TypeExpr) -> Result<(), MonomorphizeError> {
+        match &mut type_expr.kind {
+            TypeExprKind::ArrayOf(element) => self.resolve_type_expr(element)?,
+            TypeExprKind::MapOf { key, value } => {
+                self.resolve_type_expr(key)?;
+                self.resolve_type_expr(value)?;
+            }
+            TypeExprKind::Tuple(elements) => {
+                for element in elements {
+                    self.resolve_type_expr(element)?;
+                }
+            }
+            TypeExprKind::Generic { name, args } => {
+                for arg in args.iter_mut() {
+                    self.resolve_type_expr(arg)?;
+                }
+                let generic_decl = match self.generic_decls.get(name.as_str()) {
+                    Some(&generic_decl) if generic_decl.type_params().len() == args.len() => {
+                        generic_decl
+                    }
+                    _ => return Ok(()),
+                };
+                // Leaving the generic in place would refer to a type that isn't declared
+                if self.depth >= MAX_INSTANTIATION_DEPTH {
+                    return Err(MonomorphizeError::TooDeep(name.clone()));
+                }
+                let generic = TypeExpr {
+                    nullable: false,
+                    kind: TypeExprKind::Generic {
+                        name: name.clone(),
+                        args: args.clone(),
+                    },
+                };
+                let instance_name = generic.descriptive_name();
+                // `Page<FooArray>` and `Page<Foo[]>` are both named `PageFooArray`
+                match self.instantiated.get(&instance_name) {
+                    Some(instantiated) if *instantiated == generic => {}
+                    Some(_) => return Err(MonomorphizeError::NameClash(instance_name)),
+                    None if self.declared.contains(instance_name.as_str()) => {
+                        return Err(MonomorphizeError::NameClash(instance_name))
+                    }
+                    None => {
+                        self.instantiated
+                            .insert(instance_name.clone(), generic.clone());
+                        self.depth += 1;
+                        self.instantiate(generic_decl, args, instance_name.clone())?;
+                        self.depth -= 1;
+                    }
+                }
+                type_expr.kind = TypeExprKind::Identifier(instance_name);
+            }
+            TypeExprKind::Identifier(_) | TypeExprKind::Primitive(_) => {}
+        }
+        Ok(())
+    }
+
+    fn resolve_type_decl(&mut self, type_decl: &mut TypeDecl) -> Result<(), MonomorphizeError> {
+        let mut result = Ok(());
+        type_decl.for_each_type_expr_mut(&mut |type_expr| {
+            if result.is_ok() {
+                result = self.resolve_type_expr(type_expr);
+            }
+        });
+        result
+    }
+}
+
+/// Replaces generic declarations with a copy for each instantiation, named after its type
+/// arguments, like `PageUser` for `Page<User>`. For backends whose target language has no generics.
+pub fn monomorphize(file: &File) -> Result<File, MonomorphizeError> {
+    let generic_decls = file
+        .items
+        .iter()
+        .filter_map(|item| match item {
+            Item::TypeDecl(type_decl) if !type_decl.type_params().is_empty() => {
+                Some((type_decl.name(), type_decl))
+            }
+            _ => None,
+        })
+        .collect();
+    let declared = file
+        .type_decls()
+        .filter(|type_decl| type_decl.type_params().is_empty())
+        .map(TypeDecl::name)
+        .collect();
+    let mut monomorphizer = Monomorphizer {
+        generic_decls,
+        declared,
+        instantiated: HashMap::new(),
+        items: vec![],
+        depth: 0,
+    };
+    for item in &file.items {
+        let item = match item {
+            Item::TypeDecl(type_decl) if !type_decl.type_params().is_empty() => continue,
+            Item::TypeDecl(type_decl) => {
+                let mut type_decl = type_decl.clone();
+                monomorphizer.resolve_type_decl(&mut type_decl)?;
+                Item::TypeDecl(type_decl)
+            }
+            Item::Service(service) => {
+                let mut service: Service = service.clone();
+                let mut result = Ok(());
+                for method in &mut service.methods {
+                    method.for_each_type_expr_mut(&mut |type_expr| {
+                        if result.is_ok() {
+                            result = monomorphizer.resolve_type_expr(type_expr);
+                        }
+                    });
+                }
+                result?;
+                Item::Service(service)
+            }
+        };
+        monomorphizer.items.push(item);
+    }
+    Ok(File {
+        items: monomorphizer.items,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::type_decl::struct_::{Field, Struct};
+    use crate::type_expr::primitive::Primitive;
+
+    fn ty(kind: TypeExprKind) -> TypeExpr {
+        TypeExpr {
+            nullable: false,
+            kind,
+        }
+    }
+    fn field(name: &str, ty: TypeExpr) -> Field {
+        Field {
+            name: name.to_string(),
+            wire_name: name.to_string(),
+            ty,
+            optional: false,
+            doc: None,
+        }
+    }
+    fn page_of(arg: TypeExpr) -> TypeExpr {
+        ty(TypeExprKind::Generic {
+            name: "Page".to_string(),
+            args: vec![arg],
+        })
+    }
+
+    fn generic_page() -> Item {
+        Item::TypeDecl(TypeDecl::Struct(Struct {
+            name: "Page".to_string(),
+            type_params: vec!["T".to_string()],
+            extends: vec![],
+            fields: vec![
+                field(
+                    "items",
+                    ty(TypeExprKind::ArrayOf(Box::new(ty(
+                        TypeExprKind::Identifier("T".to_string()),
+                    )))),
+                ),
+                field(
+                    "next",
+                    TypeExpr {
+                        nullable: true,
+                        kind: TypeExprKind::Identifier("T".to_string()),
+                    },
+                ),
+            ],
+            doc: None,
+        }))
+    }
+
+    #[test]
+    fn instantiate_struct() {
+        let file = File {
+            items: vec![
+                generic_page(),
+                Item::TypeDecl(TypeDecl::Struct(Struct {
+                    name: "Response".to_string(),
+                    type_params: vec![],
+                    extends: vec![],
+                    fields: vec![
+                        field(
+                            "users",
+                            page_of(ty(TypeExprKind::Identifier("User".to_string()))),
+                        ),
+                        field(
+                            "more_users",
+                            page_of(ty(TypeExprKind::Identifier("User".to_string()))),
+                        ),
+                        field(
+                            "numbers",
+                            page_of(ty(TypeExprKind::Primitive(Primitive::Number))),
+                        ),
+                    ],
+                    doc: None,
+                })),
+            ],
+        };
+        let monomorphized = monomorphize(&file).unwrap();
+        let names = monomorphized
+            .items
+            .iter()
+            .map(Item::name)
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["PageUser", "PageNumber", "Response"]);
+
+        let page_user = match &monomorphized.items[0] {
+            Item::TypeDecl(TypeDecl::Struct(st)) => st,
+            _ => unreachable!(),
+        };
+        assert!(page_user.type_params.is_empty());
+        assert_eq!(
+            page_user.fields[1].ty,
+            TypeExpr {
+                nullable: true,
+                kind: TypeExprKind::Identifier("User".to_string()),
+            }
+        );
+        let response = match &monomorphized.items[2] {
+            Item::TypeDecl(TypeDecl::Struct(st)) => st,
+            _ => unreachable!(),
+        };
+        assert_eq!(
+            response.fields[0].ty,
+            ty(TypeExprKind::Identifier("PageUser".to_string()))
+        );
+    }
+
+    #[test]
+    fn nested_instantiation() {
+        let file = File {
+            items: vec![
+                generic_page(),
+                Item::TypeDecl(TypeDecl::Struct(Struct {
+                    name: "Response".to_string(),
+                    type_params: vec![],
+                    extends: vec![],
+                    fields: vec![field(
+                        "pages",
+                        page_of(page_of(ty(TypeExprKind::Primitive(Primitive::String)))),
+                    )],
+                    doc: None,
+                })),
+            ],
+        };
+        let names = monomorphize(&file)
+            .unwrap()
+            .items
+            .iter()
+            .map(|item| item.name().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["PageString", "PagePageString", "Response"]);
+    }
+
+    #[test]
+    fn instance_name_clash() {
+        let response = |field_ty| {
+            Item::TypeDecl(TypeDecl::Struct(Struct {
+                name: "Response".to_string(),
+                type_params: vec![],
+                extends: vec![],
+                fields: vec![field("page", field_ty)],
+                doc: None,
+            }))
+        };
+        let user_page = Item::TypeDecl(TypeDecl::Struct(Struct {
+            name: "PageUser".to_string(),
+            type_params: vec![],
+            extends: vec![],
+            fields: vec![],
+            doc: None,
+        }));
+        let file = File {
+            items: vec![
+                generic_page(),
+                user_page,
+                response(page_of(ty(TypeExprKind::Identifier("User".to_string())))),
+            ],
+        };
+        assert_eq!(
+            monomorphize(&file),
+            Err(MonomorphizeError::NameClash("PageUser".to_string()))
+        );
+
+        let file = File {
+            items: vec![
+                generic_page(),
+                response(ty(TypeExprKind::Tuple(vec![
+                    page_of(ty(TypeExprKind::ArrayOf(Box::new(ty(
+                        TypeExprKind::Identifier("User".to_string()),
+                    ))))),
+                    page_of(ty(TypeExprKind::Identifier("UserArray".to_string()))),
+                ]))),
+            ],
+        };
+        assert_eq!(
+            monomorphize(&file),
+            Err(MonomorphizeError::NameClash("PageUserArray".to_string()))
+        );
+    }
+
+    #[test]
+    fn polymorphic_recursion() {
+        let file = File {
+            items: vec![
+                Item::TypeDecl(TypeDecl::Struct(Struct {
+                    name: "Page".to_string(),
+                    type_params: vec!["T".to_string()],
+                    extends: vec![],
+                    fields: vec![field(
+                        "nested",
+                        page_of(ty(TypeExprKind::ArrayOf(Box::new(ty(
+                            TypeExprKind::Identifier("T".to_string()),
+                        ))))),
+                    )],
+                    doc: None,
+                })),
+                Item::TypeDecl(TypeDecl::Struct(Struct {
+                    name: "Response".to_string(),
+                    type_params: vec![],
+                    extends: vec![],
+                    fields: vec![field(
+                        "page",
+                        page_of(ty(TypeExprKind::Primitive(Primitive::String))),
+                    )],
+                    doc: None,
+                })),
+            ],
+        };
+        assert_eq!(
+            monomorphize(&file),
+            Err(MonomorphizeError::TooDeep("Page".to_string()))
+        );
+    }
+}
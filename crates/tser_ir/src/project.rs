@@ -0,0 +1,40 @@
+use crate::{File, Item};
+
+/// Names imported by a module from another one
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Import {
+    pub names: Vec<String>,
+    pub path: String, // Resolved, like `models/user.ts`
+}
+
+/// A source file of a project
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Module {
+    pub path: String,
+    pub imports: Vec<Import>,
+    pub file: File, // Only the module's own declarations
+}
+
+/// A schema split across files. Declaration names are unique across all of them.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Project {
+    pub modules: Vec<Module>, // Imported modules come before the ones importing them
+}
+
+impl Project {
+    pub fn find_module(&self, path: &str) -> Option<&Module> {
+        self.modules.iter().find(|module| module.path == path)
+    }
+
+    /// Puts the declarations of all modules into a single file, in dependency order
+    pub fn merge(&self) -> File {
+        File {
+            items: self
+                .modules
+                .iter()
+                .flat_map(|module| module.file.items.iter())
+                .cloned()
+                .collect::<Vec<Item>>(),
+        }
+    }
+}
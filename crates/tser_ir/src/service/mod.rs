@@ -1,16 +1,46 @@
+use crate::type_decl::struct_::Field;
 use crate::type_decl::TypeDecl;
+use crate::type_expr::TypeExpr;
 
+/// What a method sends or receives. Both are `None` for methods without parameters or results.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct Body {
     pub unary: Option<TypeDecl>,
     pub stream_item: Option<TypeDecl>,
 }
 
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct Method {
+    pub name: String, // As written, which is also the name on the wire
+    /// The parameters as declared. The request body is their type for a single parameter,
+    /// and a struct with a field for each of them otherwise.
+    pub params: Vec<Field>,
     pub request: Body,
     pub response: Body,
+    pub doc: Option<String>,
 }
 
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct Service {
     pub name: String,
     pub methods: Vec<Method>,
+    pub doc: Option<String>,
+}
+
+impl Method {
+    /// The declarations of the request and response bodies
+    pub fn body_decls(&self) -> impl Iterator<Item = &TypeDecl> {
+        [&self.request, &self.response]
+            .into_iter()
+            .flat_map(|body| body.unary.iter().chain(body.stream_item.iter()))
+    }
+
+    pub fn for_each_type_expr_mut(&mut self, f: &mut impl FnMut(&mut TypeExpr)) {
+        self.params.iter_mut().for_each(|param| f(&mut param.ty));
+        for body in [&mut self.request, &mut self.response] {
+            for type_decl in body.unary.iter_mut().chain(body.stream_item.iter_mut()) {
+                type_decl.for_each_type_expr_mut(f);
+            }
+        }
+    }
 }
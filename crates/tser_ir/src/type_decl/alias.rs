@@ -0,0 +1,10 @@
+use crate::type_expr::TypeExpr;
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Alias {
+    pub name: String,
+    pub type_params: Vec<String>,
+    pub ty: TypeExpr,
+    pub newtype: bool, // A distinct type wrapping `ty` instead of another name for it
+    pub doc: Option<String>,
+}
@@ -2,6 +2,7 @@
 pub struct Enum {
     pub name: String,
     pub kind: EnumKind,
+    pub doc: Option<String>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
@@ -14,4 +15,5 @@ pub enum EnumKind {
 pub struct EnumValue<T> {
     pub name: String,
     pub value: T,
+    pub doc: Option<String>,
 }
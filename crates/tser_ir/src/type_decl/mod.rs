@@ -1,15 +1,20 @@
+pub mod alias;
 pub mod enum_;
 pub mod struct_;
 pub mod union;
 
+use crate::type_expr::TypeExpr;
+use alias::Alias;
 use enum_::Enum;
 use struct_::Struct;
-use union::Union;
+use union::{Union, UnionKind};
 
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub enum TypeDecl {
     Enum(Enum),
     Struct(Struct),
     Union(Union),
+    Alias(Alias),
 }
 
 impl TypeDecl {
@@ -18,6 +23,40 @@ impl TypeDecl {
             TypeDecl::Struct(st) => &st.name,
             TypeDecl::Enum(enm) => &enm.name,
             TypeDecl::Union(union) => &union.name,
+            TypeDecl::Alias(alias) => &alias.name,
+        }
+    }
+
+    pub fn type_params(&self) -> &[String] {
+        match self {
+            TypeDecl::Struct(st) => &st.type_params,
+            TypeDecl::Enum(_) => &[],
+            TypeDecl::Union(union) => &union.type_params,
+            TypeDecl::Alias(alias) => &alias.type_params,
+        }
+    }
+
+    /// Calls `f` on every type expression directly referenced by the declaration,
+    /// like the types of struct fields. Nested type expressions are left to `f`.
+    pub fn for_each_type_expr_mut(&mut self, f: &mut impl FnMut(&mut TypeExpr)) {
+        match self {
+            TypeDecl::Struct(st) => {
+                st.extends.iter_mut().for_each(&mut *f);
+                st.fields.iter_mut().for_each(|field| f(&mut field.ty));
+            }
+            TypeDecl::Enum(_) => {}
+            TypeDecl::Union(union) => match &mut union.kind {
+                UnionKind::ExternallyTagged(variants) => {
+                    variants.iter_mut().for_each(|variant| f(&mut variant.ty))
+                }
+                UnionKind::InternallyTagged(internally_tagged) => internally_tagged
+                    .variants
+                    .iter_mut()
+                    .flat_map(|variant| &mut variant.fields)
+                    .for_each(|field| f(&mut field.ty)),
+                UnionKind::Untagged(variants) => variants.iter_mut().for_each(f),
+            },
+            TypeDecl::Alias(alias) => f(&mut alias.ty),
         }
     }
 }
@@ -1,14 +1,289 @@
-use crate::type_expr::TypeExpr;
+use crate::type_decl::TypeDecl;
+use crate::type_expr::{TypeExpr, TypeExprKind};
+use crate::File;
+use std::collections::HashMap;
+use std::fmt::{Display, Formatter};
 
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct Field {
-    pub name: String,
+    pub name: String,      // An identifier, sanitized from `wire_name`
+    pub wire_name: String, // The JSON key
     pub ty: TypeExpr,
     pub optional: bool,
+    pub doc: Option<String>,
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct Struct {
     pub name: String,
-    pub fields: Vec<Field>,
+    pub type_params: Vec<String>,
+    pub extends: Vec<TypeExpr>, // Identifier or Generic referring to other structs
+    pub fields: Vec<Field>,     // Doesn't include inherited fields
+    pub doc: Option<String>,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub enum InheritanceError {
+    UnknownParent(String),
+    Cycle(String),
+    ConflictingField(String),
+}
+
+impl Display for InheritanceError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::UnknownParent(name) => write!(f, "`{name}` is not a known interface"),
+            Self::Cycle(name) => write!(f, "`{name}` inherits from itself"),
+            Self::ConflictingField(name) => {
+                write!(f, "Field `{name}` is inherited with conflicting types")
+            }
+        }
+    }
+}
+
+fn merge_field(fields: &mut Vec<Field>, field: Field) -> Result<(), InheritanceError> {
+    match fields
+        .iter_mut()
+        .find(|existing| existing.name == field.name)
+    {
+        // The same field can be reached through multiple parents, or redeclared with another doc
+        // comment, which replaces the inherited one
+        Some(existing)
+            if existing.ty == field.ty
+                && existing.optional == field.optional
+                && existing.wire_name == field.wire_name =>
+        {
+            if field.doc.is_some() {
+                existing.doc = field.doc;
+            }
+            Ok(())
+        }
+        Some(_) => Err(InheritanceError::ConflictingField(field.name)),
+        None => {
+            fields.push(field);
+            Ok(())
+        }
+    }
+}
+
+impl Struct {
+    fn collect_fields(
+        &self,
+        file: &File,
+        visiting: &mut Vec<String>,
+        fields: &mut Vec<Field>,
+    ) -> Result<(), InheritanceError> {
+        if visiting.contains(&self.name) {
+            return Err(InheritanceError::Cycle(self.name.clone()));
+        }
+        visiting.push(self.name.clone());
+        for parent in &self.extends {
+            let (parent_name, args) = match &parent.kind {
+                TypeExprKind::Identifier(name) => (name, &[][..]),
+                TypeExprKind::Generic { name, args } => (name, args.as_slice()),
+                _ => return Err(InheritanceError::UnknownParent(parent.descriptive_name())),
+            };
+            let parent_struct = match file.find_type_decl(parent_name) {
+                Some(TypeDecl::Struct(st)) if st.type_params.len() == args.len() => st,
+                _ => return Err(InheritanceError::UnknownParent(parent_name.clone())),
+            };
+            let args = parent_struct
+                .type_params
+                .iter()
+                .map(String::as_str)
+                .zip(args)
+                .collect::<HashMap<&str, &TypeExpr>>();
+            let mut parent_fields = vec![];
+            parent_struct.collect_fields(file, visiting, &mut parent_fields)?;
+            for mut field in parent_fields {
+                field.ty.substitute(&args);
+                merge_field(fields, field)?;
+            }
+        }
+        for field in &self.fields {
+            merge_field(fields, field.clone())?;
+        }
+        visiting.pop();
+        Ok(())
+    }
+
+    /// Fields inherited through `extends`, parents first, followed by the struct's own fields
+    pub fn resolve_fields(&self, file: &File) -> Result<Vec<Field>, InheritanceError> {
+        let mut fields = vec![];
+        self.collect_fields(file, &mut vec![], &mut fields)?;
+        Ok(fields)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::type_expr::primitive::Primitive;
+    use crate::Item;
+
+    fn field(name: &str, kind: TypeExprKind) -> Field {
+        Field {
+            name: name.to_string(),
+            wire_name: name.to_string(),
+            ty: TypeExpr {
+                nullable: false,
+                kind,
+            },
+            optional: false,
+            doc: None,
+        }
+    }
+    fn parent(name: &str) -> TypeExpr {
+        TypeExpr {
+            nullable: false,
+            kind: TypeExprKind::Identifier(name.to_string()),
+        }
+    }
+    fn struct_item(name: &str, extends: &[&str], fields: Vec<Field>) -> Item {
+        Item::TypeDecl(TypeDecl::Struct(Struct {
+            name: name.to_string(),
+            type_params: vec![],
+            extends: extends.iter().map(|name| parent(name)).collect(),
+            fields,
+            doc: None,
+        }))
+    }
+    fn resolve(file: &File, name: &str) -> Result<Vec<String>, InheritanceError> {
+        let st = match file.find_type_decl(name) {
+            Some(TypeDecl::Struct(st)) => st,
+            _ => unreachable!(),
+        };
+        Ok(st
+            .resolve_fields(file)?
+            .into_iter()
+            .map(|field| field.name)
+            .collect())
+    }
+
+    #[test]
+    fn multiple_levels() {
+        let string = || TypeExprKind::Primitive(Primitive::String);
+        let file = File {
+            items: vec![
+                struct_item("Base", &[], vec![field("id", string())]),
+                struct_item("Named", &["Base"], vec![field("name", string())]),
+                struct_item("Dated", &["Base"], vec![field("date", string())]),
+                struct_item("Post", &["Named", "Dated"], vec![field("body", string())]),
+            ],
+        };
+        assert_eq!(
+            resolve(&file, "Post").unwrap(),
+            ["id", "name", "date", "body"]
+        );
+    }
+
+    #[test]
+    fn conflicting_field() {
+        let file = File {
+            items: vec![
+                struct_item(
+                    "A",
+                    &[],
+                    vec![field("id", TypeExprKind::Primitive(Primitive::String))],
+                ),
+                struct_item(
+                    "B",
+                    &["A"],
+                    vec![field("id", TypeExprKind::Primitive(Primitive::Number))],
+                ),
+            ],
+        };
+        assert_eq!(
+            resolve(&file, "B"),
+            Err(InheritanceError::ConflictingField("id".to_string()))
+        );
+    }
+
+    #[test]
+    fn redeclared_field_with_doc() {
+        let string = || TypeExprKind::Primitive(Primitive::String);
+        let redeclared = Field {
+            doc: Some("the admin id".to_string()),
+            ..field("id", string())
+        };
+        let file = File {
+            items: vec![
+                struct_item(
+                    "User",
+                    &[],
+                    vec![
+                        Field {
+                            doc: Some("the user id".to_string()),
+                            ..field("id", string())
+                        },
+                        field("name", string()),
+                    ],
+                ),
+                struct_item("Admin", &["User"], vec![redeclared.clone()]),
+            ],
+        };
+        let st = match file.find_type_decl("Admin") {
+            Some(TypeDecl::Struct(st)) => st,
+            _ => unreachable!(),
+        };
+        assert_eq!(
+            st.resolve_fields(&file).unwrap(),
+            [redeclared, field("name", string())]
+        );
+    }
+
+    #[test]
+    fn cycle_and_unknown_parent() {
+        let file = File {
+            items: vec![
+                struct_item("A", &["B"], vec![]),
+                struct_item("B", &["A"], vec![]),
+                struct_item("C", &["D"], vec![]),
+            ],
+        };
+        assert_eq!(
+            resolve(&file, "A"),
+            Err(InheritanceError::Cycle("A".to_string()))
+        );
+        assert_eq!(
+            resolve(&file, "C"),
+            Err(InheritanceError::UnknownParent("D".to_string()))
+        );
+    }
+
+    #[test]
+    fn generic_parent() {
+        let file = File {
+            items: vec![
+                Item::TypeDecl(TypeDecl::Struct(Struct {
+                    name: "Page".to_string(),
+                    type_params: vec!["T".to_string()],
+                    extends: vec![],
+                    fields: vec![field("items", TypeExprKind::Identifier("T".to_string()))],
+                    doc: None,
+                })),
+                Item::TypeDecl(TypeDecl::Struct(Struct {
+                    name: "UserPage".to_string(),
+                    type_params: vec![],
+                    extends: vec![TypeExpr {
+                        nullable: false,
+                        kind: TypeExprKind::Generic {
+                            name: "Page".to_string(),
+                            args: vec![parent("User")],
+                        },
+                    }],
+                    fields: vec![],
+                    doc: None,
+                })),
+            ],
+        };
+        let st = match file.find_type_decl("UserPage") {
+            Some(TypeDecl::Struct(st)) => st,
+            _ => unreachable!(),
+        };
+        assert_eq!(
+            st.resolve_fields(&file).unwrap(),
+            [field("items", TypeExprKind::Identifier("User".to_string()))]
+        );
+    }
 }
@@ -3,8 +3,9 @@ use crate::type_expr::TypeExpr;
 
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct InternallyTaggedUnionBody {
-    pub tag_field: String,
-    pub variants: Vec<Struct>, // Struct::name is the value of tag_field
+    pub tag_field: String,      // The JSON key
+    pub tag_field_name: String, // tag_field sanitized into an identifier
+    pub variants: Vec<Struct>,  // Struct::name is the value of tag_field
 }
 
 fn get_only_field(s: &Struct) -> Option<&Field> {
@@ -16,16 +17,17 @@ fn get_only_field(s: &Struct) -> Option<&Field> {
 }
 impl InternallyTaggedUnionBody {
     pub fn as_adjacently_tagged(&self) -> Option<AdjacentlyTaggedUnionBody> {
-        let data_field = get_only_field(self.variants.first()?)?.name.as_str();
+        let data_field = get_only_field(self.variants.first()?)?;
         let variants = self
             .variants
             .iter()
             .map(|variant| {
                 let only_field = get_only_field(variant)?;
-                if only_field.name == data_field {
+                if only_field.wire_name == data_field.wire_name {
                     Some(AdjacentlyTaggedUnionVariant {
                         optional: only_field.optional,
                         ty: only_field.ty.clone(),
+                        doc: variant.doc.clone(),
                     })
                 } else {
                     None
@@ -34,7 +36,9 @@ impl InternallyTaggedUnionBody {
             .collect::<Option<Vec<AdjacentlyTaggedUnionVariant>>>()?;
         Some(AdjacentlyTaggedUnionBody {
             tag_field: self.tag_field.clone(),
-            data_field: data_field.to_string(),
+            tag_field_name: self.tag_field_name.clone(),
+            data_field: data_field.wire_name.clone(),
+            data_field_name: data_field.name.clone(),
             variants,
         })
     }
@@ -44,12 +48,15 @@ impl InternallyTaggedUnionBody {
 pub struct AdjacentlyTaggedUnionVariant {
     pub optional: bool,
     pub ty: TypeExpr,
+    pub doc: Option<String>, // Of the variant, not of the data field
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct AdjacentlyTaggedUnionBody {
     pub tag_field: String,
-    pub data_field: String,
+    pub tag_field_name: String,
+    pub data_field: String,                          // The JSON key
+    pub data_field_name: String,                     // data_field sanitized into an identifier
     pub variants: Vec<AdjacentlyTaggedUnionVariant>, // AdjacentlyTagged variant can be optional
 }
 
@@ -58,6 +65,7 @@ pub struct AdjacentlyTaggedUnionBody {
 pub struct ExternallyTaggedVariant {
     pub name: String,
     pub ty: TypeExpr,
+    pub doc: Option<String>,
 }
 
 /// https://serde.rs/enum-representations.html
@@ -65,13 +73,15 @@ pub struct ExternallyTaggedVariant {
 pub enum UnionKind {
     ExternallyTagged(Vec<ExternallyTaggedVariant>),
     InternallyTagged(InternallyTaggedUnionBody),
-    // TODO: untagged
+    Untagged(Vec<TypeExpr>),
 }
 
 #[derive(Debug, Clone, Eq, PartialEq, Hash)]
 pub struct Union {
     pub name: String,
+    pub type_params: Vec<String>,
     pub kind: UnionKind,
+    pub doc: Option<String>,
 }
 
 #[cfg(test)]
@@ -80,22 +90,28 @@ mod tests {
     use crate::type_expr::primitive::Primitive;
     use crate::type_expr::TypeExprKind;
 
+    // Field names are given as JSON keys, with `-` sanitized into `_` for the identifiers
     fn generate_struct_variants(names: &[(&str, &[&str])]) -> Vec<Struct> {
         names
             .iter()
             .map(|(struct_name, field_names)| Struct {
                 name: struct_name.to_string(),
+                type_params: vec![],
+                extends: vec![],
                 fields: field_names
                     .iter()
                     .map(|field_name| Field {
-                        name: field_name.to_string(),
+                        name: field_name.replace('-', "_"),
+                        wire_name: field_name.to_string(),
                         ty: TypeExpr {
                             nullable: false,
                             kind: TypeExprKind::Primitive(Primitive::String),
                         },
                         optional: false,
+                        doc: None,
                     })
                     .collect(),
+                doc: None,
             })
             .collect()
     }
@@ -104,6 +120,7 @@ mod tests {
     fn adjacently_tagged_simple() {
         let internally_tagged = InternallyTaggedUnionBody {
             tag_field: "t".to_string(),
+            tag_field_name: "t".to_string(),
             variants: generate_struct_variants(&[("a", &["c"]), ("b", &["c"])]),
         };
         let adjacently_tagged = internally_tagged.as_adjacently_tagged().unwrap();
@@ -111,9 +128,33 @@ mod tests {
         assert_eq!(adjacently_tagged.data_field, "c");
     }
     #[test]
+    fn adjacently_tagged_quoted_keys() {
+        let internally_tagged = InternallyTaggedUnionBody {
+            tag_field: "the-kind".to_string(),
+            tag_field_name: "the_kind".to_string(),
+            variants: generate_struct_variants(&[("a", &["my-data"]), ("b", &["my-data"])]),
+        };
+        let adjacently_tagged = internally_tagged.as_adjacently_tagged().unwrap();
+        assert_eq!(adjacently_tagged.tag_field, "the-kind");
+        assert_eq!(adjacently_tagged.tag_field_name, "the_kind");
+        assert_eq!(adjacently_tagged.data_field, "my-data");
+        assert_eq!(adjacently_tagged.data_field_name, "my_data");
+    }
+    #[test]
+    fn adjacently_tagged_same_identifier_different_keys() {
+        // 'my-data' and 'my_data' are different keys on the wire
+        let internally_tagged = InternallyTaggedUnionBody {
+            tag_field: "t".to_string(),
+            tag_field_name: "t".to_string(),
+            variants: generate_struct_variants(&[("a", &["my-data"]), ("b", &["my_data"])]),
+        };
+        assert_eq!(internally_tagged.as_adjacently_tagged(), None);
+    }
+    #[test]
     fn adjacently_tagged_multiple_fields() {
         let internally_tagged = InternallyTaggedUnionBody {
             tag_field: "t".to_string(),
+            tag_field_name: "t".to_string(),
             variants: generate_struct_variants(&[("a", &["c"]), ("b", &["c", "b"])]),
         };
         assert_eq!(internally_tagged.as_adjacently_tagged(), None);
@@ -122,6 +163,7 @@ mod tests {
     fn adjacently_tagged_different_field_names() {
         let internally_tagged = InternallyTaggedUnionBody {
             tag_field: "t".to_string(),
+            tag_field_name: "t".to_string(),
             variants: generate_struct_variants(&[("a", &["c"]), ("b", &["d"])]),
         };
         assert_eq!(internally_tagged.as_adjacently_tagged(), None);
@@ -1,12 +1,22 @@
 pub mod primitive;
 
 use primitive::Primitive;
+use std::collections::HashMap;
 
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
 pub enum TypeExprKind {
     Identifier(String),
     ArrayOf(Box<TypeExpr>),
+    MapOf {
+        key: Box<TypeExpr>, // Always a string, as JSON object keys are
+        value: Box<TypeExpr>,
+    },
     Primitive(Primitive),
+    Tuple(Vec<TypeExpr>), // Optional elements are nullable
+    Generic {
+        name: String,
+        args: Vec<TypeExpr>,
+    },
 }
 
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
@@ -14,3 +24,73 @@ pub struct TypeExpr {
     pub nullable: bool,
     pub kind: TypeExprKind,
 }
+
+impl TypeExpr {
+    /// Replaces references to type parameters with the type arguments
+    pub fn substitute(&mut self, args: &HashMap<&str, &TypeExpr>) {
+        match &mut self.kind {
+            TypeExprKind::Identifier(id) => {
+                if let Some(&arg) = args.get(id.as_str()) {
+                    let nullable = self.nullable;
+                    *self = arg.clone();
+                    self.nullable |= nullable;
+                }
+            }
+            TypeExprKind::ArrayOf(element) => element.substitute(args),
+            TypeExprKind::MapOf { key, value } => {
+                key.substitute(args);
+                value.substitute(args);
+            }
+            TypeExprKind::Tuple(elements) => elements
+                .iter_mut()
+                .for_each(|element| element.substitute(args)),
+            TypeExprKind::Generic {
+                args: generic_args, ..
+            } => generic_args
+                .iter_mut()
+                .for_each(|generic_arg| generic_arg.substitute(args)),
+            TypeExprKind::Primitive(_) => {}
+        }
+    }
+
+    /// A PascalCase name describing the type, like `NullableStringArray`,
+    /// for places where a name has to be made up for it.
+    pub fn descriptive_name(&self) -> String {
+        let unwrapped = self.kind.descriptive_name();
+        if self.nullable {
+            format!("Nullable{unwrapped}")
+        } else {
+            unwrapped
+        }
+    }
+}
+
+impl TypeExprKind {
+    /// Like [`TypeExpr::descriptive_name`], ignoring nullability
+    pub fn descriptive_name(&self) -> String {
+        match self {
+            TypeExprKind::Primitive(primitive) => match primitive {
+                Primitive::String => "String".to_string(),
+                Primitive::Number => "Number".to_string(),
+                Primitive::Int { bits, signed: true } => format!("Int{bits}"),
+                Primitive::Int {
+                    bits,
+                    signed: false,
+                } => format!("UInt{bits}"),
+                Primitive::Float { bits } => format!("Float{bits}"),
+                Primitive::Bool => "Bool".to_string(),
+            },
+            TypeExprKind::ArrayOf(element) => format!("{}Array", element.descriptive_name()),
+            TypeExprKind::MapOf { value, .. } => format!("{}Map", value.descriptive_name()),
+            TypeExprKind::Identifier(id) => id.clone(),
+            TypeExprKind::Tuple(elements) => elements
+                .iter()
+                .map(TypeExpr::descriptive_name)
+                .chain(["Tuple".to_string()])
+                .collect(),
+            TypeExprKind::Generic { name, args } => std::iter::once(name.clone())
+                .chain(args.iter().map(TypeExpr::descriptive_name))
+                .collect(),
+        }
+    }
+}
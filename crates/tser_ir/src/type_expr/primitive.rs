@@ -1,6 +1,8 @@
 #[derive(Clone, Copy, Debug, Eq, PartialEq, Hash)]
 pub enum Primitive {
     String,
-    Number,
+    Number, // A plain `number`, as a double
+    Int { bits: u8, signed: bool },
+    Float { bits: u8 },
     Bool,
 }
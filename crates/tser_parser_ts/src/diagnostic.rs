@@ -0,0 +1,209 @@
+use crate::error::StructureError;
+use std::collections::BTreeMap;
+use std::fmt::{Display, Formatter};
+use swc_common::Span;
+
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum Severity {
+    Error,
+}
+
+impl Display for Severity {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(match self {
+            Self::Error => "error",
+        })
+    }
+}
+
+/// A position in the source. `line` and `col` are 1-based, and `col` counts characters.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct SourcePos {
+    pub line: u32,
+    pub col: u32,
+    pub offset: u32,
+}
+
+impl SourcePos {
+    /// An offset inside a multi-byte character is moved back to the start of the character
+    pub(crate) fn from_offset(source: &str, offset: u32) -> Self {
+        let mut offset = (offset as usize).min(source.len());
+        while !source.is_char_boundary(offset) {
+            offset -= 1;
+        }
+        let before = &source[..offset];
+        let line_start = before.rfind('\n').map_or(0, |idx| idx + 1);
+        SourcePos {
+            line: before.matches('\n').count() as u32 + 1,
+            col: before[line_start..].chars().count() as u32 + 1,
+            offset: offset as u32,
+        }
+    }
+}
+
+impl Display for SourcePos {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}:{}", self.line, self.col)
+    }
+}
+
+/// A range in the source, with an exclusive `end`
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub struct SourceRange {
+    pub start: SourcePos,
+    pub end: SourcePos,
+}
+
+impl SourceRange {
+    pub(crate) fn from_span(source: &str, span: Span) -> Self {
+        SourceRange {
+            start: SourcePos::from_offset(source, span.lo.0),
+            end: SourcePos::from_offset(source, span.hi.0),
+        }
+    }
+}
+
+/// A secondary range that explains the diagnostic
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Label {
+    pub range: SourceRange,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub message: String,
+    pub range: SourceRange,
+    pub labels: Vec<Label>,
+    pub help: Option<String>,
+}
+
+impl Diagnostic {
+    pub(crate) fn from_structure_error(source: &str, error: StructureError) -> Self {
+        Diagnostic {
+            severity: Severity::Error,
+            message: error.message,
+            range: SourceRange::from_span(source, error.span),
+            labels: error
+                .labels
+                .into_iter()
+                .map(|(span, message)| Label {
+                    range: SourceRange::from_span(source, span),
+                    message,
+                })
+                .collect(),
+            help: error.help,
+        }
+    }
+
+    /// Renders the diagnostic with the lines it points at underlined, in the style of rustc.
+    /// Ranges spanning several lines are only underlined on their first line.
+    pub fn render(&self, source: &str) -> String {
+        self.render_at(None, source)
+    }
+
+    /// Like [`Diagnostic::render`], for a diagnostic in the file at `path`
+    pub fn render_in_file(&self, path: &str, source: &str) -> String {
+        self.render_at(Some(path), source)
+    }
+
+    fn render_at(&self, path: Option<&str>, source: &str) -> String {
+        let mut marks_by_line = BTreeMap::<u32, Vec<(SourceRange, char, &str)>>::new();
+        marks_by_line
+            .entry(self.range.start.line)
+            .or_default()
+            .push((self.range, '^', ""));
+        for label in &self.labels {
+            marks_by_line
+                .entry(label.range.start.line)
+                .or_default()
+                .push((label.range, '-', &label.message));
+        }
+        let last_line = *marks_by_line.keys().next_back().unwrap();
+        let gutter = " ".repeat(last_line.to_string().len());
+
+        let mut out = format!("{}: {}\n", self.severity, self.message);
+        match path {
+            Some(path) => out += &format!("{gutter}--> {path}:{}\n", self.range.start),
+            None => out += &format!("{gutter}--> {}\n", self.range.start),
+        }
+        out += &format!("{gutter} |\n");
+        for (line, mut marks) in marks_by_line {
+            let text = source.lines().nth(line as usize - 1).unwrap_or("");
+            out += &format!("{line:>width$} | {text}\n", width = gutter.len());
+            marks.sort_by_key(|(range, ..)| range.start.col);
+            for (range, mark, message) in marks {
+                let line_len = text.chars().count() as u32 + 1;
+                let end_col = if range.end.line == line {
+                    range.end.col
+                } else {
+                    line_len
+                };
+                let width = end_col.saturating_sub(range.start.col).max(1) as usize;
+                let underline = format!(
+                    "{}{} {message}",
+                    " ".repeat(range.start.col as usize - 1),
+                    mark.to_string().repeat(width)
+                );
+                out += &format!("{gutter} | {}\n", underline.trim_end());
+            }
+        }
+        if let Some(help) = &self.help {
+            out += &format!("{gutter} = help: {help}\n");
+        }
+        out
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use swc_common::BytePos;
+
+    fn span(lo: u32, hi: u32) -> Span {
+        Span::new(BytePos(lo), BytePos(hi), Default::default())
+    }
+
+    #[test]
+    fn test_source_pos() {
+        let source = "ab\ncdé\n\nf";
+        let pos = |offset| {
+            let SourcePos { line, col, .. } = SourcePos::from_offset(source, offset);
+            (line, col)
+        };
+        assert_eq!(pos(0), (1, 1));
+        assert_eq!(pos(2), (1, 3));
+        assert_eq!(pos(3), (2, 1));
+        assert_eq!(pos(7), (2, 4));
+        // Inside the `é`
+        assert_eq!(pos(6), (2, 3));
+        assert_eq!(pos(8), (3, 1));
+        assert_eq!(pos(9), (4, 1));
+    }
+
+    #[test]
+    fn test_render() {
+        let source = "enum E {\n  a = 1,\n  b = 'x',\n}";
+        let error = StructureError::new(span(20, 27), "Enum members must all be of the same type")
+            .with_label(span(11, 16), "This member is an integer")
+            .with_help("Use either integers or strings");
+        let diagnostic = Diagnostic::from_structure_error(source, error);
+        assert_eq!(diagnostic.range.start.line, 3);
+        assert_eq!(diagnostic.range.start.col, 3);
+        assert_eq!(diagnostic.range.end.col, 10);
+        assert_eq!(
+            diagnostic.render(source),
+            "\
+error: Enum members must all be of the same type
+ --> 3:3
+  |
+2 |   a = 1,
+  |   ----- This member is an integer
+3 |   b = 'x',
+  |   ^^^^^^^
+  = help: Use either integers or strings
+"
+        );
+    }
+}
@@ -0,0 +1,132 @@
+use swc_common::comments::{Comment, CommentKind, SingleThreadedComments};
+use swc_common::BytePos;
+
+fn comment_lines(comment: &Comment) -> Option<Vec<&str>> {
+    match comment.kind {
+        CommentKind::Line => {
+            let text = comment.text.trim_start_matches('/');
+            Some(vec![text.strip_prefix(' ').unwrap_or(text).trim_end()])
+        }
+        // Plain block comments are usually commented-out code or license headers
+        CommentKind::Block => Some(
+            comment
+                .text
+                .strip_prefix('*')?
+                .lines()
+                .map(|line| {
+                    let line = line.trim();
+                    let line = line.strip_prefix('*').unwrap_or(line);
+                    line.strip_prefix(' ').unwrap_or(line).trim_end()
+                })
+                .collect(),
+        ),
+    }
+}
+
+/// Collects the JSDoc and line comments in front of the tokens between `from` and `to`, both
+/// inclusive. Most nodes only need `from == to`, but some like union variants can be preceded by
+/// tokens with comments of their own, like the `|`.
+pub fn leading_doc(
+    comments: &SingleThreadedComments,
+    from: BytePos,
+    to: BytePos,
+) -> Option<String> {
+    let (leading, _) = comments.borrow_all();
+    let mut positions = leading
+        .keys()
+        .filter(|pos| (from..=to).contains(*pos))
+        .collect::<Vec<_>>();
+    positions.sort();
+    let lines = positions
+        .into_iter()
+        .flat_map(|pos| &leading[pos])
+        .filter_map(comment_lines)
+        .flatten()
+        .collect::<Vec<_>>();
+    let first = lines.iter().position(|line| !line.is_empty())?;
+    let last = lines.iter().rposition(|line| !line.is_empty())?;
+    Some(lines[first..=last].join("\n"))
+}
+
+/// Removes a JSDoc tag line like `@newtype` from the doc, returning the rest of that line
+pub fn take_tag(doc: &mut Option<String>, tag: &str) -> Option<String> {
+    let text = doc.as_deref()?;
+    let prefix = format!("@{tag}");
+    let mut value = None;
+    let lines = text
+        .lines()
+        .filter(|line| match line.strip_prefix(&prefix) {
+            Some(rest) if value.is_none() && (rest.is_empty() || rest.starts_with(' ')) => {
+                value = Some(rest.trim().to_string());
+                false
+            }
+            _ => true,
+        })
+        .collect::<Vec<_>>();
+    value.as_ref()?;
+    let rest = lines.join("\n");
+    let rest = rest.trim();
+    *doc = (!rest.is_empty()).then(|| rest.to_string());
+    value
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ts_parser_from_source;
+    use swc_common::Spanned;
+
+    fn doc_of_first_item(src: &str) -> Option<String> {
+        let comments = SingleThreadedComments::default();
+        let module = ts_parser_from_source(src, Some(&comments))
+            .parse_module()
+            .unwrap();
+        let lo = module.body[0].span().lo;
+        leading_doc(&comments, lo, lo)
+    }
+
+    #[test]
+    fn test_jsdoc() {
+        let src = r"
+            /**
+             * A user.
+             *
+             * Can log in.
+             */
+            interface User {}
+        ";
+        assert_eq!(doc_of_first_item(src).unwrap(), "A user.\n\nCan log in.");
+        assert_eq!(
+            doc_of_first_item("/** A user. */ interface User {}").unwrap(),
+            "A user."
+        );
+    }
+
+    #[test]
+    fn test_line_comments() {
+        let src = r"
+            // A user.
+            /// Can log in.
+            interface User {}
+        ";
+        assert_eq!(doc_of_first_item(src).unwrap(), "A user.\nCan log in.");
+    }
+
+    #[test]
+    fn test_take_tag() {
+        let mut doc = Some("A user ID.\n@newtype\n@format uuid".to_string());
+        assert_eq!(take_tag(&mut doc, "newtype").unwrap(), "");
+        assert_eq!(take_tag(&mut doc, "newtypes"), None);
+        assert_eq!(take_tag(&mut doc, "format").unwrap(), "uuid");
+        assert_eq!(doc.unwrap(), "A user ID.");
+
+        let mut doc = Some("@newtype".to_string());
+        assert!(take_tag(&mut doc, "newtype").is_some());
+        assert_eq!(doc, None);
+    }
+
+    #[test]
+    fn test_plain_block_comment() {
+        assert_eq!(doc_of_first_item("/* Not a doc */ interface User {}"), None);
+    }
+}
@@ -1,23 +1,74 @@
+use crate::diagnostic::Diagnostic;
+use std::fmt::{Display, Formatter};
 use swc_common::Span;
+use tser_ir::File;
 
 #[derive(Debug)]
 pub struct StructureError {
-    pub message: Option<String>,
+    pub message: String,
     pub span: Span,
+    pub labels: Vec<(Span, String)>,
+    pub help: Option<String>,
 }
 impl StructureError {
     pub fn new(span: Span, message: impl Into<String>) -> Self {
         Self {
             span,
-            message: Some(message.into()),
+            message: message.into(),
+            labels: vec![],
+            help: None,
         }
     }
+    pub fn with_label(mut self, span: Span, message: impl Into<String>) -> Self {
+        self.labels.push((span, message.into()));
+        self
+    }
+    pub fn with_help(mut self, help: impl Into<String>) -> Self {
+        self.help = Some(help.into());
+        self
+    }
 }
-impl From<Span> for StructureError {
-    fn from(span: Span) -> Self {
-        Self {
-            message: None,
-            span,
+
+/// Everything wrong with a file, along with the declarations that could still be parsed
+#[derive(Debug)]
+pub struct ParseError {
+    pub partial_file: File,
+    pub diagnostics: Vec<Diagnostic>,
+    pub(crate) source: String,
+}
+impl Display for ParseError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for (i, diagnostic) in self.diagnostics.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            f.write_str(&diagnostic.render(&self.source))?;
+        }
+        Ok(())
+    }
+}
+impl std::error::Error for ParseError {}
+
+/// Everything wrong with the files of a project
+#[derive(Debug)]
+pub struct ProjectError {
+    pub files: Vec<(String, ParseError)>, // By path, in the order they were loaded
+}
+impl Display for ProjectError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let diagnostics = self.files.iter().flat_map(|(path, error)| {
+            error
+                .diagnostics
+                .iter()
+                .map(move |diagnostic| diagnostic.render_in_file(path, &error.source))
+        });
+        for (i, rendered) in diagnostics.enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            f.write_str(&rendered)?;
         }
+        Ok(())
     }
 }
+impl std::error::Error for ProjectError {}
@@ -0,0 +1,67 @@
+/// Derives an identifier from a string literal. Characters that can't be part of an identifier
+/// are replaced with `_`, a `_` is prepended if the result starts with a digit,
+/// and the empty literal is named `empty`.
+pub fn identifier_from_literal(literal: &str) -> String {
+    let name: String = literal
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    match name.chars().next() {
+        None => "empty".to_string(),
+        Some(first) if first.is_ascii_digit() => format!("_{name}"),
+        Some(_) => name,
+    }
+}
+
+/// Like [`identifier_from_literal`], with a numbered suffix if the identifier is already taken.
+/// Different literals may be sanitized into the same identifier, like 'a-b' and 'a_b'.
+pub fn unique_identifier_from_literal(literal: &str, taken: impl Fn(&str) -> bool) -> String {
+    let base = identifier_from_literal(literal);
+    let mut name = base.clone();
+    let mut suffix = 1;
+    while taken(&name) {
+        name = format!("{base}_{suffix}");
+        suffix += 1;
+    }
+    name
+}
+
+/// Converts an identifier to PascalCase for use in a type name, like `billing_address` and
+/// `billingAddress` to `BillingAddress`
+pub fn pascal_case(identifier: &str) -> String {
+    identifier
+        .split('_')
+        .flat_map(|word| {
+            let mut chars = word.chars();
+            chars
+                .next()
+                .map(|first| first.to_ascii_uppercase())
+                .into_iter()
+                .chain(chars)
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_identifier_from_literal() {
+        assert_eq!(identifier_from_literal("content-type"), "content_type");
+        assert_eq!(identifier_from_literal("2fa_enabled"), "_2fa_enabled");
+        assert_eq!(identifier_from_literal(""), "empty");
+        assert_eq!(
+            unique_identifier_from_literal("a-b", |name| ["a_b", "a_b_1"].contains(&name)),
+            "a_b_2"
+        );
+    }
+
+    #[test]
+    fn test_pascal_case() {
+        assert_eq!(pascal_case("address"), "Address");
+        assert_eq!(pascal_case("billingAddress"), "BillingAddress");
+        assert_eq!(pascal_case("billing_address"), "BillingAddress");
+        assert_eq!(pascal_case("_2fa_enabled"), "2faEnabled");
+    }
+}
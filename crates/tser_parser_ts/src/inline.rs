@@ -0,0 +1,120 @@
+use crate::error::StructureError;
+use crate::prop::{fields_from_props, parse_as_prop, Prop};
+use swc_common::comments::SingleThreadedComments;
+use swc_common::Span;
+use swc_ecma_ast::TsTypeLit;
+use tser_ir::type_decl::struct_::Struct;
+use tser_ir::type_expr::{TypeExpr, TypeExprKind};
+
+/// A struct hoisted out of an inline object type, with the span of the object type
+#[derive(Debug)]
+pub struct InlineStruct {
+    pub st: Struct,
+    pub span: Span,
+}
+
+/// Collects the structs hoisted out of the inline object types of a declaration. They can use the
+/// declaration's type parameters, and take the ones they use as their own.
+pub struct InlineObjects<'a> {
+    type_params: Vec<String>,
+    comments: &'a SingleThreadedComments,
+    pub structs: Vec<InlineStruct>,
+}
+
+impl<'a> InlineObjects<'a> {
+    pub fn new(type_params: Vec<String>, comments: &'a SingleThreadedComments) -> Self {
+        InlineObjects {
+            type_params,
+            comments,
+            structs: vec![],
+        }
+    }
+}
+
+/// A place where inline object types may appear, like the type of a field, naming the structs
+/// hoisted from there. Later ones are numbered, like `OrderAddress` and `OrderAddress2` for
+/// `address: [{ ... }, { ... }]`.
+pub struct InlineSite<'s, 'a> {
+    objects: &'s mut InlineObjects<'a>,
+    name: String,
+    hoisted: usize,
+}
+
+impl<'s, 'a> InlineSite<'s, 'a> {
+    pub fn new(objects: &'s mut InlineObjects<'a>, name: String) -> Self {
+        InlineSite {
+            objects,
+            name,
+            hoisted: 0,
+        }
+    }
+
+    /// How many structs were hoisted from this site
+    pub fn hoisted(&self) -> usize {
+        self.hoisted
+    }
+
+    /// Turns the object type into a struct, returning a reference to it. Fields with object
+    /// types of their own are named after the struct, like `OrderAddressGeo`.
+    pub fn hoist(&mut self, ts_type_lit: &TsTypeLit) -> Result<TypeExprKind, StructureError> {
+        self.hoisted += 1;
+        let name = match self.hoisted {
+            1 => self.name.clone(),
+            n => format!("{}{n}", self.name),
+        };
+        let props = ts_type_lit
+            .members
+            .iter()
+            .map(|member| parse_as_prop(member, self.objects.comments))
+            .collect::<Result<Vec<Prop>, StructureError>>()?;
+        // Inserted in front of the structs hoisted from its own fields
+        let index = self.objects.structs.len();
+        let fields = fields_from_props(&props, &name, self.objects)?;
+        let type_params = self
+            .objects
+            .type_params
+            .iter()
+            .filter(|type_param| fields.iter().any(|field| mentions(&field.ty, type_param)))
+            .cloned()
+            .collect::<Vec<_>>();
+        let kind = if type_params.is_empty() {
+            TypeExprKind::Identifier(name.clone())
+        } else {
+            TypeExprKind::Generic {
+                name: name.clone(),
+                args: type_params
+                    .iter()
+                    .map(|type_param| TypeExpr {
+                        nullable: false,
+                        kind: TypeExprKind::Identifier(type_param.clone()),
+                    })
+                    .collect(),
+            }
+        };
+        self.objects.structs.insert(
+            index,
+            InlineStruct {
+                st: Struct {
+                    name,
+                    type_params,
+                    extends: vec![],
+                    fields,
+                    doc: None,
+                },
+                span: ts_type_lit.span,
+            },
+        );
+        Ok(kind)
+    }
+}
+
+fn mentions(type_expr: &TypeExpr, id: &str) -> bool {
+    match &type_expr.kind {
+        TypeExprKind::Identifier(name) => name == id,
+        TypeExprKind::ArrayOf(element) => mentions(element, id),
+        TypeExprKind::MapOf { key, value } => mentions(key, id) || mentions(value, id),
+        TypeExprKind::Tuple(elements) => elements.iter().any(|element| mentions(element, id)),
+        TypeExprKind::Generic { args, .. } => args.iter().any(|arg| mentions(arg, id)),
+        TypeExprKind::Primitive(_) => false,
+    }
+}
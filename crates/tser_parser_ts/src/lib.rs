@@ -1,101 +1,509 @@
+mod diagnostic;
+mod doc;
 mod error;
+mod ident;
+mod inline;
+mod number;
+mod project;
 mod prop;
+mod service;
 mod type_decl;
 mod type_expr;
 
 #[cfg(test)]
 mod test_utils;
 
+pub use diagnostic::{Diagnostic, Label, Severity, SourcePos, SourceRange};
 use error::StructureError;
-use std::fmt::{Display, Formatter};
+pub use error::{ParseError, ProjectError};
+use inline::InlineStruct;
+pub use project::{parse_project, DiskFileSystem, FileSystem};
+use service::{is_service, parse_service};
+use std::collections::HashSet;
 use swc_common::input::StringInput;
+use tser_ir::type_decl::TypeDecl;
 use tser_ir::{File, Item};
 
-use swc_common::{BytePos, Span, Spanned};
-use swc_ecma_ast::{ExportDecl, Module, ModuleDecl, ModuleItem, Stmt};
+use doc::leading_doc;
+use swc_common::comments::{Comments, SingleThreadedComments};
+use swc_common::{BytePos, Spanned};
+use swc_ecma_ast::{Decl, ExportDecl, Module, ModuleDecl, ModuleItem, Stmt};
 use swc_ecma_parser::lexer::Lexer;
 use swc_ecma_parser::{Parser, Syntax, TsConfig};
-use type_decl::parse_type_decl;
+use type_decl::{check_type_decl, parse_type_decl};
 
-fn parse_module(module: &Module) -> Result<File, StructureError> {
-    let items = module
+/// `imported` has the declarations of the modules this one imports from, directly or not, for
+/// checking inheritance. Imports are rejected if it's `None`.
+fn parse_module(
+    module: &Module,
+    comments: &SingleThreadedComments,
+    imported: Option<&[Item]>,
+) -> (File, Vec<StructureError>) {
+    let mut items = vec![];
+    let mut decls = vec![];
+    let mut errors = vec![];
+    let mut broken_names = HashSet::new();
+    let mut inline_items = vec![];
+    for module_item in &module.body {
+        if imported.is_some() && is_import(module_item) {
+            continue;
+        }
+        let decl = match module_item_decl(module_item) {
+            Ok(decl) => decl,
+            Err(err) => {
+                errors.push(err);
+                continue;
+            }
+        };
+        let doc = leading_doc(comments, module_item.span().lo, decl.span().lo);
+        if let Decl::TsInterface(ts_interface) = decl {
+            if is_service(ts_interface) {
+                match parse_service(ts_interface, doc, comments) {
+                    Ok(service) => items.push(Item::Service(service)),
+                    Err(err) => {
+                        errors.push(err);
+                        broken_names.extend(decl_name(decl));
+                    }
+                }
+                continue;
+            }
+        }
+        match parse_type_decl(decl, doc, comments) {
+            Ok(Some((type_decl, inline_structs))) => {
+                items.push(Item::TypeDecl(type_decl));
+                decls.push(decl);
+                for InlineStruct { st, span } in inline_structs {
+                    inline_items.push((items.len(), span));
+                    items.push(Item::TypeDecl(TypeDecl::Struct(st)));
+                }
+            }
+            Ok(None) => {}
+            Err(err) => {
+                errors.push(err);
+                broken_names.extend(decl_name(decl));
+            }
+        }
+    }
+    // The names of hoisted structs are made up, so they may clash with declared ones
+    let declared_names = module
         .body
         .iter()
-        .map(parse_module_item)
-        .collect::<Result<Vec<Item>, StructureError>>()?;
-    Ok(File { items })
-}
-fn parse_module_item(item: &ModuleItem) -> Result<Item, StructureError> {
-    let decl = match item {
-        ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, .. })) => decl,
-        ModuleItem::Stmt(Stmt::Decl(decl)) => decl,
-        other => return Err(other.span().into()),
+        .filter_map(|module_item| module_item_decl(module_item).ok())
+        .filter_map(decl_name)
+        .collect::<HashSet<_>>();
+    let mut inline_names = HashSet::new();
+    let mut clashing_items = HashSet::new();
+    for (idx, span) in inline_items {
+        let name = items[idx].name();
+        if declared_names.contains(name) || !inline_names.insert(name) {
+            errors.push(
+                StructureError::new(span, format!("`{name}` is already declared"))
+                    .with_help("Name the object type with a `@name` tag on its field"),
+            );
+            clashing_items.insert(idx);
+        }
+    }
+    let items = items
+        .into_iter()
+        .enumerate()
+        .filter(|(idx, _)| !clashing_items.contains(idx))
+        .map(|(_, item)| item);
+    let file = File {
+        items: imported
+            .unwrap_or_default()
+            .iter()
+            .cloned()
+            .chain(items)
+            .collect(),
     };
-    let type_decl = parse_type_decl(decl)?;
-    Ok(Item::TypeDecl(type_decl))
+    for decl in decls {
+        if let Err(err) = check_type_decl(decl, &file, &broken_names) {
+            errors.push(err);
+        }
+    }
+    // Backends expect inheritance to resolve, so structs that failed the checks are left out
+    let items = file.items[imported.map_or(0, <[Item]>::len)..]
+        .iter()
+        .filter(|item| match item {
+            Item::TypeDecl(TypeDecl::Struct(st)) => st.resolve_fields(&file).is_ok(),
+            _ => true,
+        })
+        .cloned()
+        .collect();
+    (File { items }, errors)
 }
-
-#[derive(Debug)]
-pub(crate) struct SourcePos {
-    pub line: u32,
-    pub col: u32,
+fn is_import(item: &ModuleItem) -> bool {
+    matches!(item, ModuleItem::ModuleDecl(ModuleDecl::Import(_)))
 }
-impl SourcePos {
-    fn from_source_span(source: &str, span: Span) -> Self {
-        let idx = span.lo.0;
-        let mut col = idx;
-        let mut line = 0_u32;
-        for line_len in source.lines().map(|s| s.len() as u32) {
-            if col > line_len {
-                col -= line_len;
-                line += 1;
-            } else {
-                break;
-            }
-        }
-        SourcePos { line, col }
+fn module_item_decl(item: &ModuleItem) -> Result<&Decl, StructureError> {
+    match item {
+        ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, .. })) => Ok(decl),
+        ModuleItem::Stmt(Stmt::Decl(decl)) => Ok(decl),
+        ModuleItem::ModuleDecl(ModuleDecl::Import(import)) => Err(StructureError::new(
+            import.span,
+            "Imports are only supported in multi-file projects",
+        )
+        .with_help("Load the files as a project to resolve imports")),
+        other => Err(StructureError::new(
+            other.span(),
+            "Only declarations are supported at the top level",
+        )),
     }
 }
-
-impl Display for SourcePos {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_fmt(format_args!("Line {}, Column {}", self.line, self.col))
+fn decl_name(decl: &Decl) -> Option<&str> {
+    match decl {
+        Decl::TsInterface(ts_interface) => Some(&ts_interface.id.sym),
+        Decl::TsEnum(ts_enum) => Some(&ts_enum.id.sym),
+        Decl::TsTypeAlias(ts_type_alias) => Some(&ts_type_alias.id.sym),
+        _ => None,
     }
 }
 
-fn ts_parser_from_source<'a>(source: &'a str) -> Parser<Lexer<StringInput<'a>>> {
+fn ts_parser_from_source<'a>(
+    source: &'a str,
+    comments: Option<&'a dyn Comments>,
+) -> Parser<Lexer<'a, StringInput<'a>>> {
     Parser::new(
         Syntax::Typescript(TsConfig::default()),
         StringInput::new(source, BytePos(0), BytePos(source.len() as u32)),
-        None,
+        comments,
     )
 }
 
-pub fn parse_file(source: &str) -> anyhow::Result<File> {
-    let mut parser = ts_parser_from_source(source);
+/// Parses every declaration it can, so that all errors are reported at once
+pub fn parse_file(source: &str) -> Result<File, ParseError> {
+    let to_diagnostic = |error| Diagnostic::from_structure_error(source, error);
+    let comments = SingleThreadedComments::default();
+    let mut parser = ts_parser_from_source(source, Some(&comments));
     let module = match parser.parse_module() {
         Ok(ok) => ok,
-        Err(parser_error) => anyhow::bail!(
-            "{} {}",
-            SourcePos::from_source_span(source, parser_error.span()),
-            parser_error.kind().msg()
-        ),
-    };
-    let file = match parse_module(&module) {
-        Ok(ok) => ok,
-        Err(structure_error) => anyhow::bail!(
-            "{} {}",
-            SourcePos::from_source_span(source, structure_error.span),
-            structure_error
-                .message
-                .unwrap_or_else(|| "Unrecognized structure".to_string())
-        ),
+        Err(parser_error) => {
+            let error = StructureError::new(parser_error.span(), parser_error.kind().msg());
+            return Err(ParseError {
+                partial_file: File { items: vec![] },
+                diagnostics: vec![to_diagnostic(error)],
+                source: source.to_string(),
+            });
+        }
     };
-    Ok(file)
+    let (file, errors) = parse_module(&module, &comments, None);
+    if errors.is_empty() {
+        Ok(file)
+    } else {
+        Err(ParseError {
+            partial_file: file,
+            diagnostics: errors.into_iter().map(to_diagnostic).collect(),
+            source: source.to_string(),
+        })
+    }
 }
 
 #[cfg(test)]
 mod tests {
+    use super::*;
+    use assert_matches::assert_matches;
+    use tser_ir::type_decl::enum_::{Enum, EnumKind};
+    use tser_ir::type_decl::union::{Union, UnionKind};
+    use tser_ir::type_expr::{TypeExpr, TypeExprKind};
+
+    #[test]
+    fn test_inheritance() {
+        parse_file(
+            r"
+            interface User { id: string }
+            interface Admin extends User { perms: string[] }
+            interface SuperAdmin extends Admin { id: string }
+            ",
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_inheritance_conflict() {
+        let err = parse_file(
+            r"
+            interface User { id: string }
+            interface Admin extends User { id: number }
+            ",
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.diagnostics[0].message,
+            "Field `id` is inherited with conflicting types"
+        );
+    }
+
     #[test]
-    fn test_add() {}
+    fn test_inheritance_redeclared_doc() {
+        let file = parse_file(
+            r"
+            interface User {
+                /** the user id */
+                id: string
+            }
+            interface Admin extends User {
+                /** the admin id */
+                id: string
+            }
+            ",
+        )
+        .unwrap();
+        let st = match file.find_type_decl("Admin") {
+            Some(TypeDecl::Struct(st)) => st,
+            _ => unreachable!(),
+        };
+        let fields = st.resolve_fields(&file).unwrap();
+        assert_eq!(fields[0].doc.as_deref(), Some("the admin id"));
+    }
+
+    #[test]
+    fn test_intersection() {
+        let file = parse_file(
+            r"
+            interface Base { id: string }
+            type Full = Base & { extra: number };
+            interface Admin extends Full { perms: string[] }
+            ",
+        )
+        .unwrap();
+        let st = match file.find_type_decl("Admin") {
+            Some(TypeDecl::Struct(st)) => st,
+            _ => unreachable!(),
+        };
+        let fields = st.resolve_fields(&file).unwrap();
+        let names = fields.iter().map(|field| &field.name).collect::<Vec<_>>();
+        assert_eq!(names, ["id", "extra", "perms"]);
+    }
+
+    #[test]
+    fn test_intersection_conflicting_field() {
+        let src = "interface A { id: string }\ntype B = A & { id: number };";
+        let err = parse_file(src).unwrap_err();
+        let diagnostic = &err.diagnostics[0];
+        assert_eq!(
+            diagnostic.message,
+            "Field `id` is inherited with conflicting types"
+        );
+        assert_eq!(diagnostic.range.start.line, 2);
+        assert_eq!(diagnostic.range.start.col, 10);
+        assert!(diagnostic.help.is_some());
+        assert!(err.partial_file.find_type_decl("B").is_none());
+    }
+
+    #[test]
+    fn test_diagnostic_after_non_ascii() {
+        let src =
+            "/** Größe in µm */\ninterface Maß { wert: number }\ninterface Ü extends Fehlt {}";
+        let err = parse_file(src).unwrap_err();
+        let range = err.diagnostics[0].range;
+        assert_eq!((range.start.line, range.start.col), (3, 21));
+        assert_eq!((range.end.line, range.end.col), (3, 26));
+        assert!(err.diagnostics[0]
+            .render(src)
+            .contains("3 | interface Ü extends Fehlt {}"));
+    }
+
+    #[test]
+    fn test_inline_objects() {
+        let file = parse_file(
+            r"
+            interface Order<T> {
+                address: { street: string; geo: { lat: number; lng: number } };
+                items: { sku: string; extra: T }[];
+                /** @name Money */
+                total: { amount: number } | null;
+            }
+            type Payment =
+                | { method: 'card'; card: { last4: string } }
+                | { method: 'bank-transfer'; bank: { iban: string } };
+            ",
+        )
+        .unwrap();
+        let names = file.items.iter().map(Item::name).collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            [
+                "Order",
+                "OrderAddress",
+                "OrderAddressGeo",
+                "OrderItems",
+                "Money",
+                "Payment",
+                "PaymentCardCard",
+                "PaymentBankTransferBank",
+            ]
+        );
+        let order = assert_matches!(&file.items[0], Item::TypeDecl(TypeDecl::Struct(st)) => st);
+        assert_eq!(
+            order.fields[1].ty.kind,
+            TypeExprKind::ArrayOf(Box::new(TypeExpr {
+                nullable: false,
+                kind: TypeExprKind::Generic {
+                    name: "OrderItems".to_string(),
+                    args: vec![TypeExpr {
+                        nullable: false,
+                        kind: TypeExprKind::Identifier("T".to_string()),
+                    }],
+                },
+            }))
+        );
+        assert_eq!(order.fields[2].doc, None);
+        let items = assert_matches!(&file.items[3], Item::TypeDecl(TypeDecl::Struct(st)) => st);
+        assert_eq!(items.type_params, ["T"]);
+        let address = assert_matches!(&file.items[1], Item::TypeDecl(TypeDecl::Struct(st)) => st);
+        assert!(address.type_params.is_empty());
+    }
+
+    #[test]
+    fn test_inline_object_name_clash() {
+        let err = parse_file(
+            r"
+            interface Order { address: { street: string } }
+            interface OrderAddress { street: string }
+            ",
+        )
+        .unwrap_err();
+        assert_eq!(
+            err.diagnostics[0].message,
+            "`OrderAddress` is already declared"
+        );
+        assert_eq!(err.diagnostics[0].range.start.line, 2);
+        assert_eq!(err.partial_file.items.len(), 2);
+
+        let err =
+            parse_file("interface Order {\n  /** @name Money */\n  total: number\n}").unwrap_err();
+        assert_eq!(
+            err.diagnostics[0].message,
+            "`@name` only applies to fields with an object type"
+        );
+    }
+
+    #[test]
+    fn test_sized_number_decls() {
+        let file = parse_file(
+            r"
+            type i64 = number;
+            type u32 = number & { readonly __brand: 'u32' };
+            interface Counter {
+                count: u32;
+                /** @format int64 */
+                total: number;
+            }
+            ",
+        )
+        .unwrap();
+        assert_eq!(file.items.len(), 1);
+
+        let err = parse_file("type i32 = string;").unwrap_err();
+        assert_eq!(
+            err.diagnostics[0].message,
+            "`i32` is a built-in number type and must be declared as a `number`"
+        );
+    }
+
+    #[test]
+    fn test_inheritance_unknown_parent() {
+        let err = parse_file("interface Admin extends User { id: number }").unwrap_err();
+        assert_eq!(
+            err.diagnostics[0].message,
+            "`User` is not a known interface"
+        );
+    }
+
+    #[test]
+    fn test_all_errors_reported() {
+        let err = parse_file(
+            r"
+            interface User { id: string }
+            class Foo {}
+            interface Admin extends User { perms: string[] }
+            type Broken = { a: string } | string;
+            interface Nested extends Broken { b: string }
+            interface Orphan extends Nobody { c: string }
+            ",
+        )
+        .unwrap_err();
+        let names = err
+            .partial_file
+            .items
+            .iter()
+            .map(Item::name)
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["User", "Admin"]);
+        let lines = err
+            .diagnostics
+            .iter()
+            .map(|diagnostic| diagnostic.range.start.line)
+            .collect::<Vec<_>>();
+        assert_eq!(lines, [3, 5, 7]);
+        assert_eq!(
+            err.diagnostics[2].message,
+            "`Nobody` is not a known interface"
+        );
+    }
+
+    #[test]
+    fn test_import_in_single_file() {
+        let err = parse_file("import { User } from './user';").unwrap_err();
+        assert_eq!(
+            err.diagnostics[0].message,
+            "Imports are only supported in multi-file projects"
+        );
+    }
+
+    #[test]
+    fn test_rendered_error() {
+        let err = parse_file("interface A {\n  a: string;\n  b: Foo.Bar;\n}").unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "\
+error: Qualified type names aren't supported
+ --> 3:6
+  |
+3 |   b: Foo.Bar;
+  |      ^^^^^^^
+  = help: Import the type by name instead
+"
+        );
+    }
+
+    #[test]
+    fn test_docs() {
+        let file = parse_file(
+            r"
+            /** A user */
+            export interface User {
+                // The primary key
+                id: string;
+                name: string;
+            }
+            type Shape =
+                /** A circle */
+                | { type: 'circle', r: number }
+                | { type: 'square', side: number };
+            enum Level {
+                /** The lowest */
+                Low = 1,
+                High,
+            }
+            ",
+        )
+        .unwrap();
+        let user = assert_matches!(&file.items[0], Item::TypeDecl(TypeDecl::Struct(st)) => st);
+        assert_eq!(user.doc.as_deref(), Some("A user"));
+        assert_eq!(user.fields[0].doc.as_deref(), Some("The primary key"));
+        assert_eq!(user.fields[1].doc, None);
+        let shape = assert_matches!(
+            &file.items[1],
+            Item::TypeDecl(TypeDecl::Union(Union { kind: UnionKind::InternallyTagged(body), .. })) => body
+        );
+        assert_eq!(shape.variants[0].doc.as_deref(), Some("A circle"));
+        assert_eq!(shape.variants[1].doc, None);
+        let level = assert_matches!(
+            &file.items[2],
+            Item::TypeDecl(TypeDecl::Enum(Enum { kind: EnumKind::Integers(values), .. })) => values
+        );
+        assert_eq!(level[0].doc.as_deref(), Some("The lowest"));
+        assert_eq!(level[1].doc, None);
+    }
 }
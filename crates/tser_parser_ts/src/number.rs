@@ -0,0 +1,132 @@
+use crate::doc::take_tag;
+use crate::error::StructureError;
+use swc_common::Span;
+use tser_ir::type_expr::primitive::Primitive;
+use tser_ir::type_expr::{TypeExpr, TypeExprKind};
+
+/// The sized number types that can be referred to by name, declared in TS as `type i64 = number`
+/// or a branded `type i64 = number & { __brand: 'i64' }`
+pub fn sized_number(name: &str) -> Option<Primitive> {
+    let (bits, signed) = match name {
+        "i8" => (8, true),
+        "i16" => (16, true),
+        "i32" => (32, true),
+        "i64" => (64, true),
+        "u8" => (8, false),
+        "u16" => (16, false),
+        "u32" => (32, false),
+        "u64" => (64, false),
+        "f32" => return Some(Primitive::Float { bits: 32 }),
+        "f64" => return Some(Primitive::Float { bits: 64 }),
+        _ => return None,
+    };
+    Some(Primitive::Int { bits, signed })
+}
+
+/// Formats like in OpenAPI, for `@format int64`
+fn number_format(format: &str) -> Option<Primitive> {
+    let (bits, signed) = match format {
+        "int8" => (8, true),
+        "int16" => (16, true),
+        "int32" => (32, true),
+        "int64" => (64, true),
+        "uint8" => (8, false),
+        "uint16" => (16, false),
+        "uint32" => (32, false),
+        "uint64" => (64, false),
+        "float" => return Some(Primitive::Float { bits: 32 }),
+        "double" => return Some(Primitive::Float { bits: 64 }),
+        _ => return None,
+    };
+    Some(Primitive::Int { bits, signed })
+}
+
+fn replace_number(type_expr: &mut TypeExpr, primitive: Primitive) -> bool {
+    match &mut type_expr.kind {
+        TypeExprKind::Primitive(Primitive::Number) => {
+            type_expr.kind = TypeExprKind::Primitive(primitive);
+            true
+        }
+        TypeExprKind::ArrayOf(element) => replace_number(element, primitive),
+        TypeExprKind::MapOf { value, .. } => replace_number(value, primitive),
+        TypeExprKind::Tuple(elements) | TypeExprKind::Generic { args: elements, .. } => {
+            elements.iter_mut().fold(false, |replaced, element| {
+                replace_number(element, primitive) | replaced
+            })
+        }
+        TypeExprKind::Primitive(_) | TypeExprKind::Identifier(_) => false,
+    }
+}
+
+/// Applies a `@format` tag in the doc to the `number`s in the type, like the elements of
+/// `number[]`. Formats that aren't about numbers are left in the doc for other tools.
+pub fn apply_number_format(
+    doc: &mut Option<String>,
+    type_expr: &mut TypeExpr,
+    span: Span,
+) -> Result<(), StructureError> {
+    let mut rest = doc.clone();
+    let primitive = match take_tag(&mut rest, "format")
+        .as_deref()
+        .and_then(number_format)
+    {
+        Some(primitive) => primitive,
+        None => return Ok(()),
+    };
+    if !replace_number(type_expr, primitive) {
+        return Err(
+            StructureError::new(span, "Number formats only apply to `number` types")
+                .with_help("Remove the `@format` tag or change the type to `number`"),
+        );
+    }
+    *doc = rest;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ty(kind: TypeExprKind) -> TypeExpr {
+        TypeExpr {
+            nullable: false,
+            kind,
+        }
+    }
+
+    #[test]
+    fn test_apply_number_format() {
+        let mut doc = Some("Milliseconds.\n@format int64".to_string());
+        let mut type_expr = ty(TypeExprKind::ArrayOf(Box::new(ty(
+            TypeExprKind::Primitive(Primitive::Number),
+        ))));
+        apply_number_format(&mut doc, &mut type_expr, Span::default()).unwrap();
+        assert_eq!(doc.unwrap(), "Milliseconds.");
+        let int64 = Primitive::Int {
+            bits: 64,
+            signed: true,
+        };
+        assert_eq!(
+            type_expr,
+            ty(TypeExprKind::ArrayOf(Box::new(ty(
+                TypeExprKind::Primitive(int64)
+            ))))
+        );
+    }
+
+    #[test]
+    fn test_other_format() {
+        let mut doc = Some("@format date-time".to_string());
+        let mut type_expr = ty(TypeExprKind::Primitive(Primitive::String));
+        apply_number_format(&mut doc, &mut type_expr, Span::default()).unwrap();
+        assert_eq!(doc.unwrap(), "@format date-time");
+    }
+
+    #[test]
+    fn test_format_without_number() {
+        let mut doc = Some("@format int32".to_string());
+        let mut type_expr = ty(TypeExprKind::Primitive(Primitive::String));
+        let err = apply_number_format(&mut doc, &mut type_expr, Span::default()).unwrap_err();
+        assert_eq!(err.message, "Number formats only apply to `number` types");
+    }
+}
@@ -0,0 +1,549 @@
+use crate::diagnostic::Diagnostic;
+use crate::error::{ParseError, ProjectError, StructureError};
+use crate::{decl_name, parse_module, ts_parser_from_source};
+use std::collections::HashMap;
+use std::io;
+use std::path::PathBuf;
+use swc_common::comments::SingleThreadedComments;
+use swc_common::{Span, Spanned, DUMMY_SP};
+use swc_ecma_ast::{
+    Decl, ExportDecl, ImportDecl, ImportSpecifier, Module, ModuleDecl, ModuleExportName,
+    ModuleItem, Stmt,
+};
+use tser_ir::project::{Import, Module as IrModule, Project};
+use tser_ir::{File, Item};
+
+/// Where the files of a project are read from. Paths are relative to the root of the project and
+/// separated by `/`, so that the files don't have to be on disk.
+pub trait FileSystem {
+    fn read(&self, path: &str) -> io::Result<String>;
+}
+
+/// Reads the files from the `root` directory
+pub struct DiskFileSystem {
+    pub root: PathBuf,
+}
+
+impl FileSystem for DiskFileSystem {
+    fn read(&self, path: &str) -> io::Result<String> {
+        std::fs::read_to_string(self.root.join(path))
+    }
+}
+
+/// Files kept in memory by their paths, like ones passed in from JavaScript
+impl FileSystem for HashMap<String, String> {
+    fn read(&self, path: &str) -> io::Result<String> {
+        self.get(path)
+            .cloned()
+            .ok_or_else(|| io::Error::from(io::ErrorKind::NotFound))
+    }
+}
+
+/// Resolves a relative path against the directory of the file at `from`, which is empty for the
+/// root. Returns `None` if the path leaves the root.
+fn join(from: &str, relative: &str) -> Option<String> {
+    let mut parts = from.split('/').collect::<Vec<_>>();
+    parts.pop();
+    for part in relative.split('/') {
+        match part {
+            "" | "." => {}
+            ".." => {
+                parts.pop()?;
+            }
+            part => parts.push(part),
+        }
+    }
+    Some(parts.join("/"))
+}
+
+/// The files an import like `./user` may refer to, in order of preference
+fn candidate_paths(path: &str) -> Vec<String> {
+    if path.ends_with(".ts") {
+        vec![path.to_string()]
+    } else if let Some(stem) = path.strip_suffix(".js") {
+        vec![format!("{stem}.ts")]
+    } else {
+        vec![format!("{path}.ts"), format!("{path}/index.ts")]
+    }
+}
+
+struct ResolvedImport {
+    names: Vec<(String, Span)>,
+    target: usize,
+}
+
+struct SourceFile {
+    path: String,
+    source: String,
+    comments: SingleThreadedComments,
+    module: Option<Module>, // `None` if it has syntax errors
+    imports: Vec<ResolvedImport>,
+    errors: Vec<StructureError>,
+    file: File,
+}
+
+struct Loader<'a> {
+    file_system: &'a dyn FileSystem,
+    files: Vec<SourceFile>,
+    stack: Vec<usize>, // The files whose imports are being loaded, for finding cycles
+    order: Vec<usize>, // Each file comes after the ones it imports
+}
+
+impl<'a> Loader<'a> {
+    fn find(&self, path: &str) -> Option<usize> {
+        self.files.iter().position(|file| file.path == path)
+    }
+
+    /// Parses the file and loads its imports, depth-first
+    fn load(&mut self, path: String, source: String) -> usize {
+        let comments = SingleThreadedComments::default();
+        let mut errors = vec![];
+        let module = match ts_parser_from_source(&source, Some(&comments)).parse_module() {
+            Ok(module) => Some(module),
+            Err(parser_error) => {
+                errors.push(StructureError::new(
+                    parser_error.span(),
+                    parser_error.kind().msg(),
+                ));
+                None
+            }
+        };
+        let import_decls = module
+            .iter()
+            .flat_map(|module| &module.body)
+            .filter_map(|module_item| match module_item {
+                ModuleItem::ModuleDecl(ModuleDecl::Import(import_decl)) => {
+                    Some(import_decl.clone())
+                }
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        let idx = self.files.len();
+        self.files.push(SourceFile {
+            path,
+            source,
+            comments,
+            module,
+            imports: vec![],
+            errors,
+            file: File { items: vec![] },
+        });
+        self.stack.push(idx);
+        for import_decl in import_decls {
+            match self.load_import(idx, &import_decl) {
+                Ok(import) => self.files[idx].imports.push(import),
+                Err(err) => self.files[idx].errors.push(err),
+            }
+        }
+        self.stack.pop();
+        self.order.push(idx);
+        idx
+    }
+
+    fn load_import(
+        &mut self,
+        importer: usize,
+        import_decl: &ImportDecl,
+    ) -> Result<ResolvedImport, StructureError> {
+        let names = import_decl
+            .specifiers
+            .iter()
+            .map(|specifier| match specifier {
+                ImportSpecifier::Named(named) => match &named.imported {
+                    Some(ModuleExportName::Ident(imported)) if imported.sym != named.local.sym => {
+                        Err(StructureError::new(named.span, "Imports can't be renamed")
+                            .with_help("Declarations are generated with their original names"))
+                    }
+                    Some(ModuleExportName::Str(imported)) => Err(StructureError::new(
+                        imported.span,
+                        "Imported names must be identifiers",
+                    )),
+                    _ => Ok((named.local.sym.to_string(), named.span)),
+                },
+                other => Err(StructureError::new(
+                    other.span(),
+                    "Only named imports like `import { User } from './user'` are supported",
+                )),
+            })
+            .collect::<Result<Vec<_>, StructureError>>()?;
+
+        let specifier = &*import_decl.src.value;
+        if !specifier.starts_with("./") && !specifier.starts_with("../") {
+            return Err(StructureError::new(
+                import_decl.src.span,
+                "Only relative imports are supported",
+            ));
+        }
+        let path = join(&self.files[importer].path, specifier).ok_or_else(|| {
+            StructureError::new(
+                import_decl.src.span,
+                format!("`{specifier}` is outside of the project"),
+            )
+        })?;
+        let candidates = candidate_paths(&path);
+
+        let target = match candidates.iter().find_map(|candidate| self.find(candidate)) {
+            Some(target) => target,
+            None => {
+                let (candidate, source) = candidates
+                    .iter()
+                    .find_map(|candidate| {
+                        let source = self.file_system.read(candidate).ok()?;
+                        Some((candidate.clone(), source))
+                    })
+                    .ok_or_else(|| {
+                        StructureError::new(
+                            import_decl.src.span,
+                            format!("Can't find `{specifier}`"),
+                        )
+                        .with_help(format!("Looked for `{}`", candidates.join("` and `")))
+                    })?;
+                self.load(candidate, source)
+            }
+        };
+        if let Some(start) = self.stack.iter().position(|&idx| idx == target) {
+            let cycle = self.stack[start..]
+                .iter()
+                .chain([&target])
+                .map(|&idx| self.files[idx].path.as_str())
+                .collect::<Vec<_>>();
+            return Err(StructureError::new(
+                import_decl.span,
+                format!("Import cycle: {}", cycle.join(" -> ")),
+            )
+            .with_help("Move the declarations that depend on each other into one file"));
+        }
+        Ok(ResolvedImport { names, target })
+    }
+}
+
+/// The declarations of a module, and whether they are exported
+fn module_decls(module: &Module) -> impl Iterator<Item = (&Decl, bool)> {
+    module
+        .body
+        .iter()
+        .filter_map(|module_item| match module_item {
+            ModuleItem::ModuleDecl(ModuleDecl::ExportDecl(ExportDecl { decl, .. })) => {
+                Some((decl, true))
+            }
+            ModuleItem::Stmt(Stmt::Decl(decl)) => Some((decl, false)),
+            _ => None,
+        })
+}
+
+fn check_import(name: &str, span: Span, target: &SourceFile) -> Result<(), StructureError> {
+    let module = match &target.module {
+        Some(module) => module,
+        None => return Ok(()), // Its syntax errors are reported already
+    };
+    match module_decls(module).find(|(decl, _)| decl_name(decl) == Some(name)) {
+        Some((_, true)) => Ok(()),
+        Some((_, false)) => Err(StructureError::new(
+            span,
+            format!("`{name}` isn't exported from `{}`", target.path),
+        )
+        .with_help("Add `export` in front of its declaration")),
+        None => Err(StructureError::new(
+            span,
+            format!("`{name}` isn't declared in `{}`", target.path),
+        )),
+    }
+}
+
+/// Where a declaration of the file is, or the whole file for the structs hoisted out of them
+fn decl_span(file: &SourceFile, name: &str) -> Span {
+    match &file.module {
+        Some(module) => module_decls(module)
+            .find(|(decl, _)| decl_name(decl) == Some(name))
+            .map_or(module.span, |(decl, _)| decl.span()),
+        None => DUMMY_SP,
+    }
+}
+
+/// Loads the `roots` and the files they import, directly or not. Imports are relative, like
+/// `import { User } from './user'`, which refers to `user.ts` or `user/index.ts`. Declarations
+/// are generated into a single file, so their names must be unique across the project.
+pub fn parse_project(
+    roots: &[&str],
+    file_system: &dyn FileSystem,
+) -> Result<Project, ProjectError> {
+    let mut loader = Loader {
+        file_system,
+        files: vec![],
+        stack: vec![],
+        order: vec![],
+    };
+    let mut unreadable = vec![];
+    for root in roots {
+        let path = join("", root).unwrap_or_else(|| root.to_string());
+        if loader.find(&path).is_some() {
+            continue;
+        }
+        match file_system.read(&path) {
+            Ok(source) => {
+                loader.load(path, source);
+            }
+            Err(err) => {
+                let error = StructureError::new(DUMMY_SP, format!("Can't read `{path}`: {err}"));
+                unreadable.push((
+                    path,
+                    ParseError {
+                        partial_file: File { items: vec![] },
+                        diagnostics: vec![Diagnostic::from_structure_error("", error)],
+                        source: String::new(),
+                    },
+                ));
+            }
+        }
+    }
+    let Loader {
+        mut files, order, ..
+    } = loader;
+
+    // Parsed in dependency order, so that inheritance can be checked across files
+    let mut dependencies: Vec<Vec<usize>> = vec![vec![]; files.len()];
+    for &idx in &order {
+        let mut file_dependencies = vec![];
+        for import in &files[idx].imports {
+            for &dependency in dependencies[import.target].iter().chain([&import.target]) {
+                if !file_dependencies.contains(&dependency) {
+                    file_dependencies.push(dependency);
+                }
+            }
+        }
+        let imported = file_dependencies
+            .iter()
+            .flat_map(|&dependency| files[dependency].file.items.iter().cloned())
+            .collect::<Vec<Item>>();
+        dependencies[idx] = file_dependencies;
+        let source_file = &files[idx];
+        if let Some(module) = &source_file.module {
+            let (file, errors) = parse_module(module, &source_file.comments, Some(&imported));
+            files[idx].file = file;
+            files[idx].errors.extend(errors);
+        }
+    }
+
+    let mut errors = vec![];
+    let mut declared_in = HashMap::new();
+    for &idx in &order {
+        for import in &files[idx].imports {
+            for (name, span) in &import.names {
+                if let Err(err) = check_import(name, *span, &files[import.target]) {
+                    errors.push((idx, err));
+                }
+            }
+        }
+        for item in &files[idx].file.items {
+            match declared_in.get(item.name()) {
+                Some(&other) if other != idx => {
+                    let other: &SourceFile = &files[other];
+                    errors.push((
+                        idx,
+                        StructureError::new(
+                            decl_span(&files[idx], item.name()),
+                            format!("`{}` is already declared in `{}`", item.name(), other.path),
+                        )
+                        .with_help(
+                            "Declarations are generated into one file, so names must be unique",
+                        ),
+                    ));
+                }
+                Some(_) => {}
+                None => {
+                    declared_in.insert(item.name().to_string(), idx);
+                }
+            }
+        }
+    }
+    for (idx, err) in errors {
+        files[idx].errors.push(err);
+    }
+
+    let failed = files.iter().any(|file| !file.errors.is_empty());
+    if failed || !unreadable.is_empty() {
+        let files = files
+            .into_iter()
+            .filter(|file| !file.errors.is_empty())
+            .map(|file| {
+                let diagnostics = file
+                    .errors
+                    .into_iter()
+                    .map(|error| Diagnostic::from_structure_error(&file.source, error))
+                    .collect();
+                (
+                    file.path,
+                    ParseError {
+                        partial_file: file.file,
+                        diagnostics,
+                        source: file.source,
+                    },
+                )
+            });
+        return Err(ProjectError {
+            files: unreadable.into_iter().chain(files).collect(),
+        });
+    }
+
+    let paths = files
+        .iter()
+        .map(|file| file.path.clone())
+        .collect::<Vec<_>>();
+    let mut modules = files.into_iter().map(Some).collect::<Vec<_>>();
+    Ok(Project {
+        modules: order
+            .into_iter()
+            .map(|idx| {
+                let file = modules[idx].take().expect("files are loaded once");
+                IrModule {
+                    path: file.path,
+                    imports: file
+                        .imports
+                        .into_iter()
+                        .map(|import| Import {
+                            names: import.names.into_iter().map(|(name, _)| name).collect(),
+                            path: paths[import.target].clone(),
+                        })
+                        .collect(),
+                    file: file.file,
+                }
+            })
+            .collect(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn file_system(files: &[(&str, &str)]) -> HashMap<String, String> {
+        files
+            .iter()
+            .map(|(path, source)| (path.to_string(), source.to_string()))
+            .collect()
+    }
+
+    fn messages(err: &ProjectError) -> Vec<(&str, &str)> {
+        err.files
+            .iter()
+            .flat_map(|(path, error)| {
+                error
+                    .diagnostics
+                    .iter()
+                    .map(move |diagnostic| (path.as_str(), diagnostic.message.as_str()))
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_join() {
+        assert_eq!(join("a/b.ts", "./c").unwrap(), "a/c");
+        assert_eq!(join("a/b.ts", "../c/d").unwrap(), "c/d");
+        assert_eq!(join("", "./a.ts").unwrap(), "a.ts");
+        assert_eq!(join("a.ts", "../b"), None);
+    }
+
+    #[test]
+    fn test_project() {
+        let files = file_system(&[
+            (
+                "main.ts",
+                "import { User } from './models/user';\n\
+                 import { Id } from './id.js';\n\
+                 export interface Admin extends User { perms: string[]; by: Id }",
+            ),
+            (
+                "models/user/index.ts",
+                "import type { Id } from '../../id';\nexport interface User { id: Id }",
+            ),
+            ("id.ts", "export type Id = string;"),
+        ]);
+        let project = parse_project(&["main.ts"], &files).unwrap();
+        let paths = project
+            .modules
+            .iter()
+            .map(|module| module.path.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(paths, ["id.ts", "models/user/index.ts", "main.ts"]);
+        let main = project.find_module("main.ts").unwrap();
+        assert_eq!(
+            main.imports,
+            [
+                Import {
+                    names: vec!["User".to_string()],
+                    path: "models/user/index.ts".to_string(),
+                },
+                Import {
+                    names: vec!["Id".to_string()],
+                    path: "id.ts".to_string(),
+                },
+            ]
+        );
+        let merged = project.merge();
+        let names = merged.items.iter().map(Item::name).collect::<Vec<_>>();
+        assert_eq!(names, ["Id", "User", "Admin"]);
+    }
+
+    #[test]
+    fn test_cycle() {
+        let files = file_system(&[
+            (
+                "a.ts",
+                "import { B } from './b';\nexport interface A { b: B }",
+            ),
+            (
+                "b.ts",
+                "import { A } from './a';\nexport interface B { a?: A }",
+            ),
+        ]);
+        let err = parse_project(&["a.ts"], &files).unwrap_err();
+        assert_eq!(
+            messages(&err),
+            [("b.ts", "Import cycle: a.ts -> b.ts -> a.ts")]
+        );
+        assert!(err.to_string().contains("--> b.ts:1:1"));
+    }
+
+    #[test]
+    fn test_name_clash() {
+        let files = file_system(&[
+            ("a.ts", "export interface User { id: string }"),
+            ("b.ts", "export interface User { name: string }"),
+        ]);
+        let err = parse_project(&["a.ts", "./b.ts"], &files).unwrap_err();
+        assert_eq!(
+            messages(&err),
+            [("b.ts", "`User` is already declared in `a.ts`")]
+        );
+    }
+
+    #[test]
+    fn test_bad_imports() {
+        let files = file_system(&[
+            (
+                "main.ts",
+                "import { User } from './user';\n\
+                 import { Admin } from './user';\n\
+                 import { Post } from './post';\n\
+                 import * as all from './user';\n\
+                 import { z } from 'zod';",
+            ),
+            ("user.ts", "interface User { id: string }"),
+        ]);
+        let err = parse_project(&["main.ts", "missing.ts"], &files).unwrap_err();
+        assert_eq!(
+            messages(&err),
+            [
+                ("missing.ts", "Can't read `missing.ts`: entity not found"),
+                ("main.ts", "Can't find `./post`"),
+                (
+                    "main.ts",
+                    "Only named imports like `import { User } from './user'` are supported"
+                ),
+                ("main.ts", "Only relative imports are supported"),
+                ("main.ts", "`User` isn't exported from `user.ts`"),
+                ("main.ts", "`Admin` isn't declared in `user.ts`"),
+            ]
+        );
+    }
+}
@@ -1,29 +1,72 @@
 use crate::error::StructureError;
 
-use crate::type_expr::parse_to_type_expr;
+use crate::doc::{leading_doc, take_tag};
+use crate::ident::{pascal_case, unique_identifier_from_literal};
+use crate::inline::{InlineObjects, InlineSite};
+use crate::number::apply_number_format;
+use crate::type_expr::parse_to_type_expr_hoisting;
+use swc_common::comments::SingleThreadedComments;
 use swc_common::Spanned;
-use swc_ecma_ast::{Expr, TsPropertySignature, TsType, TsTypeElement};
+use swc_ecma_ast::{Expr, Lit, Str, TsPropertySignature, TsType, TsTypeElement};
 use tser_ir::type_decl::struct_::Field;
 
 pub struct Prop<'a> {
-    pub name: String,
+    pub name: String, // As written, which is also the JSON key
     pub optional: bool,
     pub ts_type: &'a TsType,
     pub ts_type_element: &'a TsTypeElement,
+    pub doc: Option<String>,
 }
 
-impl<'a> TryFrom<&'a Prop<'a>> for Field {
-    type Error = StructureError;
-    fn try_from(prop: &Prop) -> Result<Self, Self::Error> {
-        Ok(Field {
-            name: prop.name.clone(),
+/// Converts the props of an object type to fields, making sure that their identifiers are unique.
+/// Object types in a field's type are hoisted into structs named after `parent` and the field,
+/// like `OrderAddress`, or after the field's `@name` tag.
+pub fn fields_from_props(
+    props: &[Prop],
+    parent: &str,
+    inline_objects: &mut InlineObjects,
+) -> Result<Vec<Field>, StructureError> {
+    let mut fields: Vec<Field> = vec![];
+    for prop in props {
+        let name = unique_identifier_from_literal(&prop.name, |name| {
+            fields.iter().any(|field| field.name == name)
+        });
+        let mut doc = prop.doc.clone();
+        let inline_name = take_tag(&mut doc, "name");
+        let site_name = match &inline_name {
+            Some(inline_name) if inline_name.is_empty() => {
+                return Err(StructureError::new(
+                    prop.ts_type_element.span(),
+                    "`@name` must be followed by a type name",
+                ))
+            }
+            Some(inline_name) => inline_name.clone(),
+            None => format!("{parent}{}", pascal_case(&name)),
+        };
+        let mut site = InlineSite::new(inline_objects, site_name);
+        let mut ty = parse_to_type_expr_hoisting(prop.ts_type, &mut site)?;
+        if inline_name.is_some() && site.hoisted() == 0 {
+            return Err(StructureError::new(
+                prop.ts_type_element.span(),
+                "`@name` only applies to fields with an object type",
+            ));
+        }
+        apply_number_format(&mut doc, &mut ty, prop.ts_type.span())?;
+        fields.push(Field {
+            name,
+            wire_name: prop.name.clone(),
             optional: prop.optional,
-            ty: parse_to_type_expr(prop.ts_type)?,
-        })
+            ty,
+            doc,
+        });
     }
+    Ok(fields)
 }
 
-pub fn parse_as_prop(ts_type_element: &TsTypeElement) -> Result<Prop, StructureError> {
+pub fn parse_as_prop<'a>(
+    ts_type_element: &'a TsTypeElement,
+    comments: &SingleThreadedComments,
+) -> Result<Prop<'a>, StructureError> {
     match ts_type_element {
         TsTypeElement::TsPropertySignature(prop_sig) => match prop_sig {
             TsPropertySignature {
@@ -37,17 +80,35 @@ pub fn parse_as_prop(ts_type_element: &TsTypeElement) -> Result<Prop, StructureE
             } if params.is_empty() => {
                 let name = match key.as_ref() {
                     Expr::Ident(ident) => ident.sym.to_string(),
-                    other => return Err(other.span().into()),
+                    Expr::Lit(Lit::Str(Str { value, .. })) => value.to_string(),
+                    other => {
+                        return Err(StructureError::new(
+                            other.span(),
+                            "Property names must be identifiers or string literals",
+                        ))
+                    }
                 };
                 Ok(Prop {
                     name,
                     optional: *optional,
                     ts_type: type_ann.type_ann.as_ref(),
                     ts_type_element,
+                    doc: leading_doc(comments, prop_sig.span.lo, prop_sig.span.lo),
                 })
             }
-            other => Err(other.span.into()),
+            other => Err(StructureError::new(
+                other.span,
+                "Properties must have a type annotation and can't be computed",
+            )),
         },
-        other => Err(other.span().into()),
+        TsTypeElement::TsIndexSignature(index_signature) => Err(StructureError::new(
+            index_signature.span,
+            "Index signatures are only supported as field types",
+        )
+        .with_help("Add a field of type `Record<string, T>` instead")),
+        other => Err(StructureError::new(
+            other.span(),
+            "Only property signatures are supported",
+        )),
     }
 }
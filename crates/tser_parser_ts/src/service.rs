@@ -0,0 +1,366 @@
+use crate::doc::leading_doc;
+use crate::error::StructureError;
+use crate::ident::pascal_case;
+use crate::type_expr::parse_to_type_expr;
+use swc_common::comments::SingleThreadedComments;
+use swc_common::Spanned;
+use swc_ecma_ast::{
+    BindingIdent, Expr, TsEntityName, TsFnParam, TsInterfaceDecl, TsKeywordType, TsKeywordTypeKind,
+    TsMethodSignature, TsType, TsTypeElement, TsTypeRef,
+};
+use tser_ir::service::{Body, Method, Service};
+use tser_ir::type_decl::alias::Alias;
+use tser_ir::type_decl::struct_::{Field, Struct};
+use tser_ir::type_decl::TypeDecl;
+
+/// Whether the interface declares methods rather than properties, making it a service
+pub fn is_service(ts_interface: &TsInterfaceDecl) -> bool {
+    ts_interface
+        .body
+        .body
+        .iter()
+        .any(|member| matches!(member, TsTypeElement::TsMethodSignature(_)))
+}
+
+/// Returns `T` for `Promise<T>`, `AsyncIterable<T>` and the like, if `ts_type` is one
+fn type_arg_of<'a>(ts_type: &'a TsType, names: &[&str]) -> Option<&'a TsType> {
+    match ts_type {
+        TsType::TsTypeRef(TsTypeRef {
+            type_name: TsEntityName::Ident(ident),
+            type_params: Some(type_params),
+            ..
+        }) if names.contains(&&*ident.sym) => match type_params.params.as_slice() {
+            [param] => Some(param.as_ref()),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+fn stream_item(ts_type: &TsType) -> Option<&TsType> {
+    type_arg_of(ts_type, &["AsyncIterable", "AsyncIterator"])
+}
+
+fn is_void(ts_type: &TsType) -> bool {
+    matches!(
+        ts_type,
+        TsType::TsKeywordType(TsKeywordType {
+            kind: TsKeywordTypeKind::TsVoidKeyword | TsKeywordTypeKind::TsUndefinedKeyword,
+            ..
+        })
+    )
+}
+
+fn alias_body(name: String, ts_type: &TsType, stream: bool) -> Result<Body, StructureError> {
+    let alias = TypeDecl::Alias(Alias {
+        name,
+        type_params: vec![],
+        ty: parse_to_type_expr(ts_type)?,
+        newtype: false,
+        doc: None,
+    });
+    Ok(if stream {
+        Body {
+            unary: None,
+            stream_item: Some(alias),
+        }
+    } else {
+        Body {
+            unary: Some(alias),
+            stream_item: None,
+        }
+    })
+}
+
+fn parse_param(param: &TsFnParam) -> Result<(Field, &TsType), StructureError> {
+    match param {
+        TsFnParam::Ident(BindingIdent {
+            id,
+            type_ann: Some(type_ann),
+        }) => {
+            let ts_type = type_ann.type_ann.as_ref();
+            let field = Field {
+                name: id.sym.to_string(),
+                wire_name: id.sym.to_string(),
+                optional: id.optional,
+                ty: parse_to_type_expr(stream_item(ts_type).unwrap_or(ts_type))?,
+                doc: None,
+            };
+            Ok((field, ts_type))
+        }
+        other => Err(StructureError::new(
+            other.span(),
+            "Parameters must be plain names with a type annotation",
+        )),
+    }
+}
+
+/// The request is the type of a single parameter, or a struct of the parameters otherwise
+fn request_body(
+    name: String,
+    params: &[(Field, &TsType)],
+    method_sig: &TsMethodSignature,
+) -> Result<Body, StructureError> {
+    match params {
+        [] => Ok(Body {
+            unary: None,
+            stream_item: None,
+        }),
+        [(field, ts_type)] if !field.optional => match stream_item(ts_type) {
+            Some(item) => alias_body(name, item, true),
+            None => alias_body(name, ts_type, false),
+        },
+        _ => {
+            if let Some((_, ts_type)) = params
+                .iter()
+                .find(|(_, ts_type)| stream_item(ts_type).is_some())
+            {
+                return Err(StructureError::new(
+                    ts_type.span(),
+                    "Streaming requests must be the only parameter",
+                )
+                .with_label(method_sig.key.span(), "This method has several parameters"));
+            }
+            Ok(Body {
+                unary: Some(TypeDecl::Struct(Struct {
+                    name,
+                    type_params: vec![],
+                    extends: vec![],
+                    fields: params.iter().map(|(field, _)| field.clone()).collect(),
+                    doc: None,
+                })),
+                stream_item: None,
+            })
+        }
+    }
+}
+
+fn response_body(name: String, method_sig: &TsMethodSignature) -> Result<Body, StructureError> {
+    let ts_type = match &method_sig.type_ann {
+        Some(type_ann) => type_ann.type_ann.as_ref(),
+        None => {
+            return Err(
+                StructureError::new(method_sig.span, "Methods must declare a return type")
+                    .with_help("Return `Promise<void>` for methods without a result"),
+            )
+        }
+    };
+    let ts_type = type_arg_of(ts_type, &["Promise"]).unwrap_or(ts_type);
+    if is_void(ts_type) {
+        Ok(Body {
+            unary: None,
+            stream_item: None,
+        })
+    } else if let Some(item) = stream_item(ts_type) {
+        alias_body(name, item, true)
+    } else {
+        alias_body(name, ts_type, false)
+    }
+}
+
+fn parse_method(
+    service_name: &str,
+    method_sig: &TsMethodSignature,
+    comments: &SingleThreadedComments,
+) -> Result<Method, StructureError> {
+    let name = match method_sig.key.as_ref() {
+        Expr::Ident(ident) if !method_sig.computed => ident.sym.to_string(),
+        other => {
+            return Err(StructureError::new(
+                other.span(),
+                "Method names must be identifiers",
+            ))
+        }
+    };
+    if let Some(type_params) = &method_sig.type_params {
+        return Err(StructureError::new(
+            type_params.span,
+            "Methods can't be generic",
+        ));
+    }
+    if method_sig.optional {
+        return Err(StructureError::new(
+            method_sig.span,
+            "Methods can't be optional",
+        ));
+    }
+    let params = method_sig
+        .params
+        .iter()
+        .map(parse_param)
+        .collect::<Result<Vec<_>, StructureError>>()?;
+    let prefix = format!("{service_name}{}", pascal_case(&name));
+    Ok(Method {
+        request: request_body(format!("{prefix}Request"), &params, method_sig)?,
+        response: response_body(format!("{prefix}Response"), method_sig)?,
+        params: params.into_iter().map(|(field, _)| field).collect(),
+        doc: leading_doc(comments, method_sig.span.lo, method_sig.span.lo),
+        name,
+    })
+}
+
+/// Parses an interface of methods like `getUser(req: GetUserReq): Promise<User>`. The request
+/// and response bodies are declared as types named after the service and method, like
+/// `UserServiceGetUserRequest`. Returning `AsyncIterable<T>` streams the response, and so does
+/// taking one as the only parameter for the request.
+pub fn parse_service(
+    ts_interface: &TsInterfaceDecl,
+    doc: Option<String>,
+    comments: &SingleThreadedComments,
+) -> Result<Service, StructureError> {
+    let name = ts_interface.id.sym.to_string();
+    if let Some(type_params) = &ts_interface.type_params {
+        return Err(StructureError::new(
+            type_params.span,
+            "Services can't be generic",
+        ));
+    }
+    if let Some(extend) = ts_interface.extends.first() {
+        return Err(StructureError::new(
+            extend.span,
+            "Services can't extend others",
+        ));
+    }
+    let methods = ts_interface
+        .body
+        .body
+        .iter()
+        .map(|member| match member {
+            TsTypeElement::TsMethodSignature(method_sig) => {
+                parse_method(&name, method_sig, comments)
+            }
+            other => Err(
+                StructureError::new(other.span(), "Services can only declare methods")
+                    .with_help("Declare properties in a separate interface"),
+            ),
+        })
+        .collect::<Result<Vec<Method>, StructureError>>()?;
+    Ok(Service { name, methods, doc })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::parse_src_as_decl;
+    use assert_matches::assert_matches;
+    use swc_ecma_ast::Decl;
+    use tser_ir::type_expr::primitive::Primitive;
+    use tser_ir::type_expr::{TypeExpr, TypeExprKind};
+
+    fn parse_src_as_service(src: &str) -> Result<Service, StructureError> {
+        let decl = parse_src_as_decl(src);
+        let ts_interface =
+            assert_matches!(&decl, Decl::TsInterface(ts_interface) => ts_interface.as_ref());
+        assert!(is_service(ts_interface));
+        parse_service(ts_interface, None, &SingleThreadedComments::default())
+    }
+
+    fn alias(name: &str, kind: TypeExprKind) -> TypeDecl {
+        TypeDecl::Alias(Alias {
+            name: name.to_string(),
+            type_params: vec![],
+            ty: TypeExpr {
+                nullable: false,
+                kind,
+            },
+            newtype: false,
+            doc: None,
+        })
+    }
+
+    #[test]
+    fn test_service() {
+        let service = parse_src_as_service(
+            r"interface UserService {
+                getUser(req: GetUserReq): Promise<User>;
+                watch(req: WatchReq): AsyncIterable<Event>;
+                upload(chunks: AsyncIterable<string>): Promise<void>;
+                ping(): void;
+            }",
+        )
+        .unwrap();
+        let names = service
+            .methods
+            .iter()
+            .map(|method| method.name.as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["getUser", "watch", "upload", "ping"]);
+
+        let get_user = &service.methods[0];
+        assert_eq!(get_user.params[0].name, "req");
+        assert_eq!(
+            get_user.request.unary,
+            Some(alias(
+                "UserServiceGetUserRequest",
+                TypeExprKind::Identifier("GetUserReq".to_string())
+            ))
+        );
+        assert_eq!(
+            get_user.response.unary,
+            Some(alias(
+                "UserServiceGetUserResponse",
+                TypeExprKind::Identifier("User".to_string())
+            ))
+        );
+
+        let watch = &service.methods[1];
+        assert_eq!(watch.response.unary, None);
+        assert_eq!(
+            watch.response.stream_item,
+            Some(alias(
+                "UserServiceWatchResponse",
+                TypeExprKind::Identifier("Event".to_string())
+            ))
+        );
+
+        let upload = &service.methods[2];
+        assert_eq!(
+            upload.request.stream_item,
+            Some(alias(
+                "UserServiceUploadRequest",
+                TypeExprKind::Primitive(Primitive::String)
+            ))
+        );
+        assert_eq!(upload.response.unary, None);
+        assert_eq!(upload.response.stream_item, None);
+
+        let ping = &service.methods[3];
+        assert!(ping.params.is_empty());
+        assert_eq!(ping.request.unary, None);
+    }
+
+    #[test]
+    fn test_several_params() {
+        let service = parse_src_as_service(
+            "interface Search { find(query: string, limit?: number): Promise<string[]> }",
+        )
+        .unwrap();
+        let request = assert_matches!(
+            &service.methods[0].request.unary,
+            Some(TypeDecl::Struct(st)) => st
+        );
+        assert_eq!(request.name, "SearchFindRequest");
+        let fields = request
+            .fields
+            .iter()
+            .map(|field| (field.name.as_str(), field.optional))
+            .collect::<Vec<_>>();
+        assert_eq!(fields, [("query", false), ("limit", true)]);
+    }
+
+    #[test]
+    fn test_service_errors() {
+        let err =
+            parse_src_as_service("interface S { id: string; get(): Promise<void> }").unwrap_err();
+        assert_eq!(err.message, "Services can only declare methods");
+
+        let err = parse_src_as_service("interface S { get() }").unwrap_err();
+        assert_eq!(err.message, "Methods must declare a return type");
+
+        let err = parse_src_as_service(
+            "interface S { put(id: string, chunks: AsyncIterable<string>): void }",
+        )
+        .unwrap_err();
+        assert_eq!(err.message, "Streaming requests must be the only parameter");
+    }
+}
@@ -0,0 +1,142 @@
+use crate::doc::take_tag;
+use crate::error::StructureError;
+use crate::number::{apply_number_format, sized_number};
+use crate::type_decl::intersection::collect_ts_intersection_members;
+use crate::type_decl::parse_type_params;
+use crate::type_decl::union::collect_ts_union_variants;
+use crate::type_expr::parse_to_type_expr;
+use swc_common::Spanned;
+use swc_ecma_ast::{TsKeywordType, TsKeywordTypeKind, TsType, TsTypeAliasDecl, TsTypeElement};
+use tser_ir::type_decl::alias::Alias;
+
+/// Returns `None` if the alias is a union of several types, or of object types, which are
+/// declared as unions instead. `T | null` is a plain alias of a nullable type.
+pub fn parse_alias(
+    type_alias_decl: &TsTypeAliasDecl,
+    mut doc: Option<String>,
+) -> Result<Option<Alias>, StructureError> {
+    let mut ts_types: Vec<&TsType> = vec![];
+    collect_ts_union_variants(type_alias_decl.type_ann.as_ref(), &mut ts_types);
+    ts_types.retain(|ts_type| {
+        !matches!(
+            ts_type,
+            TsType::TsKeywordType(TsKeywordType {
+                kind: TsKeywordTypeKind::TsNullKeyword,
+                ..
+            })
+        )
+    });
+    match ts_types.as_slice() {
+        // Index signatures are maps rather than object types
+        [TsType::TsTypeLit(ts_type_lit)]
+            if !matches!(
+                ts_type_lit.members.as_slice(),
+                [TsTypeElement::TsIndexSignature(_)]
+            ) =>
+        {
+            return Ok(None)
+        }
+        [_] => {}
+        _ => return Ok(None),
+    }
+    let newtype = take_tag(&mut doc, "newtype").is_some();
+    let mut ty = parse_to_type_expr(type_alias_decl.type_ann.as_ref())?;
+    apply_number_format(&mut doc, &mut ty, type_alias_decl.type_ann.span())?;
+    Ok(Some(Alias {
+        name: type_alias_decl.id.sym.to_string(),
+        type_params: parse_type_params(type_alias_decl.type_params.as_deref())?,
+        ty,
+        newtype,
+        doc,
+    }))
+}
+
+/// Whether the alias declares one of the sized number types, like `type i64 = number` or a
+/// branded `type i64 = number & { __brand: 'i64' }`. These are built in, so they aren't generated.
+pub fn is_sized_number_decl(type_alias_decl: &TsTypeAliasDecl) -> Result<bool, StructureError> {
+    let name = &type_alias_decl.id.sym;
+    if sized_number(name).is_none() {
+        return Ok(false);
+    }
+    let mut members: Vec<&TsType> = vec![];
+    collect_ts_intersection_members(type_alias_decl.type_ann.as_ref(), &mut members);
+    let is_number = |ts_type: &TsType| {
+        matches!(
+            ts_type,
+            TsType::TsKeywordType(TsKeywordType {
+                kind: TsKeywordTypeKind::TsNumberKeyword | TsKeywordTypeKind::TsBigIntKeyword,
+                ..
+            })
+        )
+    };
+    let is_brand = |ts_type: &TsType| matches!(ts_type, TsType::TsTypeLit(_));
+    if members.iter().filter(|member| is_number(member)).count() == 1
+        && members
+            .iter()
+            .all(|member| is_number(member) || is_brand(member))
+    {
+        Ok(true)
+    } else {
+        Err(StructureError::new(
+            type_alias_decl.type_ann.span(),
+            format!("`{name}` is a built-in number type and must be declared as a `number`"),
+        )
+        .with_help("Declare it as `number` or `bigint`, optionally branded with an object type"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ts_parser_from_source;
+    use swc_ecma_ast::{Decl, ModuleItem, Stmt};
+    use tser_ir::type_expr::primitive::Primitive;
+    use tser_ir::type_expr::{TypeExpr, TypeExprKind};
+
+    fn parse(src: &str, doc: Option<&str>) -> Option<Alias> {
+        let module = ts_parser_from_source(src, None).parse_module().unwrap();
+        match &module.body[0] {
+            ModuleItem::Stmt(Stmt::Decl(Decl::TsTypeAlias(alias))) => {
+                parse_alias(alias, doc.map(str::to_string)).unwrap()
+            }
+            _ => panic!("expected a type alias"),
+        }
+    }
+
+    #[test]
+    fn test_alias() {
+        let alias = parse("type UserId = string", Some("A user.")).unwrap();
+        assert_eq!(alias.name, "UserId");
+        assert_eq!(alias.ty.kind, TypeExprKind::Primitive(Primitive::String));
+        assert!(!alias.newtype);
+        assert_eq!(alias.doc.unwrap(), "A user.");
+
+        let alias = parse("type Page<T> = T[] | null", None).unwrap();
+        assert_eq!(alias.type_params, vec!["T"]);
+        assert_eq!(
+            alias.ty,
+            TypeExpr {
+                nullable: true,
+                kind: TypeExprKind::ArrayOf(Box::new(TypeExpr {
+                    nullable: false,
+                    kind: TypeExprKind::Identifier("T".to_string())
+                }))
+            }
+        );
+        assert!(parse("type Scores = { [name: string]: number }", None).is_some());
+    }
+
+    #[test]
+    fn test_newtype() {
+        let alias = parse("type UserId = string", Some("A user.\n@newtype")).unwrap();
+        assert!(alias.newtype);
+        assert_eq!(alias.doc.unwrap(), "A user.");
+    }
+
+    #[test]
+    fn test_unions_are_not_aliases() {
+        assert!(parse("type A = string | number", None).is_none());
+        assert!(parse("type A = { a: string }", None).is_none());
+        assert!(parse("type A = { a: string } | null", None).is_none());
+    }
+}
@@ -1,88 +1,288 @@
+use crate::doc::leading_doc;
 use crate::error::StructureError;
-use swc_common::Spanned;
-use swc_ecma_ast::{Expr, Lit, Number, Str, TsEnumDecl, TsEnumMember, TsEnumMemberId};
+use crate::ident::unique_identifier_from_literal;
+use crate::type_decl::union::collect_ts_union_variants;
+use swc_common::comments::SingleThreadedComments;
+use swc_common::{Span, Spanned};
+use swc_ecma_ast::{
+    BinExpr, BinaryOp, Expr, Lit, MemberExpr, MemberProp, Number, Str, TsEnumDecl, TsEnumMember,
+    TsEnumMemberId, TsLit, TsLitType, TsType, TsTypeAliasDecl, UnaryExpr, UnaryOp,
+};
 use tser_ir::type_decl::enum_::{Enum, EnumKind, EnumValue};
 
+#[derive(Clone)]
 enum AnyEnumValue {
     String(String),
     Int(i64),
 }
 
+fn not_an_integer(span: Span) -> StructureError {
+    StructureError::new(span, "Enum values must be integers")
+}
+
+fn eval_int_bin_op(op: BinaryOp, left: i64, right: i64, span: Span) -> Result<i64, StructureError> {
+    let (left32, right32) = (left as i32, right as i32);
+    // JavaScript only uses the lowest 5 bits of the shift count
+    let shift = (right as u32) & 31;
+    Ok(match op {
+        BinaryOp::BitOr => (left32 | right32) as i64,
+        BinaryOp::BitAnd => (left32 & right32) as i64,
+        BinaryOp::BitXor => (left32 ^ right32) as i64,
+        BinaryOp::LShift => left32.wrapping_shl(shift) as i64,
+        BinaryOp::RShift => (left32 >> shift) as i64,
+        BinaryOp::ZeroFillRShift => ((left32 as u32) >> shift) as i64,
+        BinaryOp::Add => left.checked_add(right).ok_or_else(|| overflow(span))?,
+        BinaryOp::Sub => left.checked_sub(right).ok_or_else(|| overflow(span))?,
+        BinaryOp::Mul => left.checked_mul(right).ok_or_else(|| overflow(span))?,
+        BinaryOp::Div | BinaryOp::Mod if right == 0 => return Err(not_an_integer(span)),
+        // `i64::MIN / -1` doesn't fit, and neither does its remainder in Rust
+        BinaryOp::Div => match left.checked_rem(right) {
+            Some(0) => left.checked_div(right).ok_or_else(|| overflow(span))?,
+            Some(_) => return Err(not_an_integer(span)),
+            None => return Err(overflow(span)),
+        },
+        BinaryOp::Mod => left.checked_rem(right).ok_or_else(|| overflow(span))?,
+        BinaryOp::Exp => u32::try_from(right)
+            .ok()
+            .and_then(|right| left.checked_pow(right))
+            .ok_or_else(|| not_an_integer(span))?,
+        _ => return Err(unsupported_enum_expr(span)),
+    })
+}
+
+/// Evaluates the constant expressions TypeScript allows as enum initializers. Bitwise operators
+/// work on 32-bit integers like in JavaScript. `earlier` are the members declared before this one.
+fn eval_enum_expr(
+    expr: &Expr,
+    enum_name: &str,
+    earlier: &[EnumValue<AnyEnumValue>],
+) -> Result<AnyEnumValue, StructureError> {
+    let find_member = |name: &str, span: Span| {
+        earlier
+            .iter()
+            .find(|member| member.name == name)
+            .map(|member| member.value.clone())
+            .ok_or_else(|| {
+                StructureError::new(
+                    span,
+                    format!("`{name}` is not an earlier member of this enum"),
+                )
+            })
+    };
+    Ok(match expr {
+        Expr::Lit(Lit::Str(Str { value, .. })) => AnyEnumValue::String(value.to_string()),
+        Expr::Lit(Lit::Num(Number { value, span, .. })) => {
+            // Casting saturates, so 2^63 would silently become i64::MAX
+            if *value >= i64::MAX as f64 {
+                return Err(overflow(*span));
+            }
+            let int_value = *value as i64;
+            if int_value as f64 != *value {
+                return Err(not_an_integer(*span));
+            }
+            AnyEnumValue::Int(int_value)
+        }
+        Expr::Paren(paren) => eval_enum_expr(&paren.expr, enum_name, earlier)?,
+        Expr::Ident(ident) => find_member(&ident.sym, ident.span)?,
+        Expr::Member(MemberExpr {
+            obj,
+            prop: MemberProp::Ident(prop),
+            ..
+        }) if matches!(obj.as_ref(), Expr::Ident(obj) if &*obj.sym == enum_name) => {
+            find_member(&prop.sym, prop.span)?
+        }
+        Expr::Unary(UnaryExpr { op, arg, span }) => {
+            let arg = match eval_enum_expr(arg, enum_name, earlier)? {
+                AnyEnumValue::Int(arg) => arg,
+                AnyEnumValue::String(_) => return Err(mixed_operands(*span)),
+            };
+            AnyEnumValue::Int(match op {
+                UnaryOp::Minus => arg.checked_neg().ok_or_else(|| overflow(*span))?,
+                UnaryOp::Plus => arg,
+                UnaryOp::Tilde => !(arg as i32) as i64,
+                _ => return Err(unsupported_enum_expr(*span)),
+            })
+        }
+        Expr::Bin(BinExpr {
+            op,
+            left,
+            right,
+            span,
+        }) => match (
+            eval_enum_expr(left, enum_name, earlier)?,
+            eval_enum_expr(right, enum_name, earlier)?,
+        ) {
+            (AnyEnumValue::String(left), AnyEnumValue::String(right)) if *op == BinaryOp::Add => {
+                AnyEnumValue::String(left + &right)
+            }
+            (AnyEnumValue::Int(left), AnyEnumValue::Int(right)) => {
+                AnyEnumValue::Int(eval_int_bin_op(*op, left, right, *span)?)
+            }
+            _ => return Err(mixed_operands(*span)),
+        },
+        other => return Err(unsupported_enum_expr(other.span())),
+    })
+}
+
+fn mixed_operands(span: Span) -> StructureError {
+    StructureError::new(
+        span,
+        "Strings can only be concatenated, and arithmetic only works on integers",
+    )
+}
+
+fn overflow(span: Span) -> StructureError {
+    StructureError::new(span, "Enum value is out of range")
+}
+
+fn unsupported_enum_expr(span: Span) -> StructureError {
+    StructureError::new(span, "Enum values must be constant expressions").with_help(
+        "Use literals, arithmetic or bitwise operators, and references to earlier members",
+    )
+}
+
 fn parse_enum_member(
     ts_enum_member: &TsEnumMember,
-) -> Result<EnumValue<Option<AnyEnumValue>>, StructureError> {
+    enum_name: &str,
+    earlier: &[EnumValue<AnyEnumValue>],
+    comments: &SingleThreadedComments,
+) -> Result<EnumValue<AnyEnumValue>, StructureError> {
     let name = match &ts_enum_member.id {
         TsEnumMemberId::Str(Str { value, .. }) => value.to_string(),
         TsEnumMemberId::Ident(ident) => ident.sym.to_string(),
     };
     let value = match ts_enum_member.init.as_ref() {
-        Some(expr) => match expr.as_ref() {
-            Expr::Lit(Lit::Str(Str { value, .. })) => Some(AnyEnumValue::String(value.to_string())),
-            Expr::Lit(Lit::Num(Number { value, .. })) => {
-                let value = *value;
-                let int_value = value as i64;
-                if int_value as f64 != value {
-                    return Err(expr.span().into());
-                }
-                Some(AnyEnumValue::Int(int_value))
+        Some(expr) => eval_enum_expr(expr, enum_name, earlier)?,
+        // Numbered automatically, continuing after the previous member
+        None => match earlier.last() {
+            None => AnyEnumValue::Int(0),
+            Some(EnumValue {
+                value: AnyEnumValue::Int(prev),
+                ..
+            }) => AnyEnumValue::Int(
+                prev.checked_add(1)
+                    .ok_or_else(|| overflow(ts_enum_member.span))?,
+            ),
+            Some(EnumValue {
+                value: AnyEnumValue::String(_),
+                ..
+            }) => {
+                return Err(StructureError::new(
+                    ts_enum_member.span,
+                    "Enum members after a string member must be initialized",
+                ))
             }
-            // TODO: support automatic int values
-            other => return Err(other.span().into()),
         },
-        None => None,
     };
-    Ok(EnumValue { name, value })
+    Ok(EnumValue {
+        name,
+        value,
+        doc: leading_doc(comments, ts_enum_member.span.lo, ts_enum_member.span.lo),
+    })
 }
 
-pub fn parse_enum(ts_enum: &TsEnumDecl) -> Result<Enum, StructureError> {
+pub fn parse_enum(
+    ts_enum: &TsEnumDecl,
+    doc: Option<String>,
+    comments: &SingleThreadedComments,
+) -> Result<Enum, StructureError> {
     let name = ts_enum.id.sym.to_string();
-    let mut member_iter = ts_enum.members.as_slice().iter();
-    let mut enum_kind = if let Some(first_member) = member_iter.next() {
-        let EnumValue { name, value } = parse_enum_member(first_member)?;
-        match value {
-            Some(AnyEnumValue::String(string_value)) => {
-                EnumKind::Strings(vec![EnumValue::<String> {
-                    name,
-                    value: string_value,
-                }])
-            }
-            Some(AnyEnumValue::Int(int_value)) => EnumKind::Integers(vec![EnumValue::<i64> {
-                name,
-                value: int_value,
-            }]),
-            None => EnumKind::Integers(vec![EnumValue::<i64> { name, value: 0 }]),
+    let mut members: Vec<EnumValue<AnyEnumValue>> = vec![];
+    for member in &ts_enum.members {
+        let member = parse_enum_member(member, &name, &members, comments)?;
+        members.push(member);
+    }
+    let mut enum_kind = match members.first() {
+        Some(EnumValue {
+            value: AnyEnumValue::String(_),
+            ..
+        }) => EnumKind::Strings(vec![]),
+        Some(EnumValue {
+            value: AnyEnumValue::Int(_),
+            ..
+        }) => EnumKind::Integers(vec![]),
+        None => {
+            return Err(StructureError::new(
+                ts_enum.span(),
+                "Enums must have at least one member",
+            ))
         }
-    } else {
-        return Err(ts_enum.span().into());
     };
-    for member in member_iter {
-        let EnumValue { name, value } = parse_enum_member(member)?;
+    for (member, ts_member) in members.into_iter().zip(&ts_enum.members) {
+        let EnumValue { name, value, doc } = member;
         match (value, &mut enum_kind) {
-            (Some(AnyEnumValue::Int(int_value)), EnumKind::Integers(cases)) => {
-                cases.push(EnumValue {
-                    name,
-                    value: int_value,
-                })
+            (AnyEnumValue::Int(value), EnumKind::Integers(cases)) => {
+                cases.push(EnumValue { name, value, doc })
             }
-            (None, EnumKind::Integers(cases)) => cases.push(EnumValue {
-                name,
-                value: cases.last().unwrap().value + 1,
-            }),
-            (Some(AnyEnumValue::String(string_value)), EnumKind::Strings(cases)) => {
-                cases.push(EnumValue {
-                    name,
-                    value: string_value,
-                })
+            (AnyEnumValue::String(value), EnumKind::Strings(cases)) => {
+                cases.push(EnumValue { name, value, doc })
+            }
+            _ => {
+                return Err(StructureError::new(
+                    ts_member.span,
+                    "Enum members must be all strings or all integers",
+                )
+                .with_label(
+                    ts_enum.members[0].span,
+                    "The first member decides the type of the enum",
+                ))
             }
-            _ => return Err(member.span.into()),
         }
     }
     Ok(Enum {
         name,
         kind: enum_kind,
+        doc,
     })
 }
 
+/// Parses `type Foo = 'a' | 'b'` as a string enum.
+/// Returns `None` if the alias isn't a union of string literals.
+pub fn parse_string_literal_union(
+    type_alias_decl: &TsTypeAliasDecl,
+    doc: Option<String>,
+    comments: &SingleThreadedComments,
+) -> Result<Option<Enum>, StructureError> {
+    if type_alias_decl.type_params.is_some() {
+        return Ok(None);
+    }
+    let mut ts_types: Vec<&TsType> = vec![];
+    collect_ts_union_variants(type_alias_decl.type_ann.as_ref(), &mut ts_types);
+
+    let mut cases: Vec<EnumValue<String>> = vec![];
+    let mut prev_hi = type_alias_decl.id.span.hi;
+    for ts_type in ts_types {
+        let case_doc = leading_doc(comments, prev_hi, ts_type.span().lo);
+        prev_hi = ts_type.span().hi;
+        let value = match ts_type {
+            TsType::TsLitType(TsLitType {
+                lit: TsLit::Str(Str { value, .. }),
+                ..
+            }) => value.to_string(),
+            _ => return Ok(None),
+        };
+        if cases.iter().any(|case| case.value == value) {
+            return Err(StructureError::new(
+                ts_type.span(),
+                "Duplicated string literal in union",
+            ));
+        }
+        let name = unique_identifier_from_literal(&value, |name| {
+            cases.iter().any(|case| case.name == name)
+        });
+        cases.push(EnumValue {
+            name,
+            value,
+            doc: case_doc,
+        });
+    }
+    Ok(Some(Enum {
+        name: type_alias_decl.id.sym.to_string(),
+        kind: EnumKind::Strings(cases),
+        doc,
+    }))
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::super::test_utils::parse_src_as_decl;
@@ -93,7 +293,7 @@ mod tests {
     fn parse_src_as_enum(src: &str) -> Result<Enum, StructureError> {
         let decl = parse_src_as_decl(src);
         let ts_enum = assert_matches!(&decl, Decl::TsEnum(ts_enum) => ts_enum.as_ref());
-        parse_enum(ts_enum)
+        parse_enum(ts_enum, None, &SingleThreadedComments::default())
     }
 
     #[test]
@@ -106,12 +306,15 @@ mod tests {
                     EnumValue {
                         name: 'X'.to_string(),
                         value: 'A'.to_string(),
+                        doc: None,
                     },
                     EnumValue {
                         name: 'Y'.to_string(),
                         value: 'B'.to_string(),
+                        doc: None,
                     },
-                ])
+                ]),
+                doc: None
             }
         );
     }
@@ -126,12 +329,15 @@ mod tests {
                     EnumValue {
                         name: 'X'.to_string(),
                         value: 0,
+                        doc: None,
                     },
                     EnumValue {
                         name: 'Y'.to_string(),
                         value: 1,
+                        doc: None,
                     },
-                ])
+                ]),
+                doc: None
             }
         );
     }
@@ -146,12 +352,15 @@ mod tests {
                     EnumValue {
                         name: 'X'.to_string(),
                         value: 4,
+                        doc: None,
                     },
                     EnumValue {
                         name: 'Y'.to_string(),
                         value: 2,
+                        doc: None,
                     },
-                ])
+                ]),
+                doc: None
             }
         );
     }
@@ -164,7 +373,9 @@ mod tests {
                 kind: EnumKind::Integers(vec![EnumValue {
                     name: 'X'.to_string(),
                     value: 0,
-                }])
+                    doc: None,
+                }]),
+                doc: None
             }
         );
     }
@@ -176,4 +387,154 @@ mod tests {
     fn test_float_number() {
         assert_matches!(parse_src_as_enum(r"enum Foo { X = 1.1 }"), Err(_));
     }
+
+    fn int_values(src: &str) -> Vec<i64> {
+        let enm = parse_src_as_enum(src).unwrap();
+        assert_matches!(enm.kind, EnumKind::Integers(cases) => cases)
+            .into_iter()
+            .map(|case| case.value)
+            .collect()
+    }
+
+    #[test]
+    fn test_computed_values() {
+        assert_eq!(
+            int_values("enum Flags { None, A = 1 << 0, B = 1 << 1, AB = A | B, C = Flags.B << 1 }"),
+            [0, 1, 2, 3, 4]
+        );
+        assert_eq!(
+            int_values("enum Foo { A = -1, B, C = (B + 3) * 2, D = ~0, E = 1 << 31, F = 7 % 4 }"),
+            [-1, 0, 6, -1, -2147483648, 3]
+        );
+    }
+
+    #[test]
+    fn test_extreme_values() {
+        assert_eq!(
+            int_values("enum Foo { A = -(2 ** 62) * 2, B = 9223372036854774784 }"),
+            [i64::MIN, 9223372036854774784]
+        );
+    }
+
+    #[test]
+    fn test_auto_increment_after_value() {
+        assert_eq!(int_values("enum Foo { A, B = 10, C, D }"), [0, 10, 11, 12]);
+    }
+
+    #[test]
+    fn test_const_enum() {
+        assert_eq!(int_values("const enum Foo { A = 2, B }"), [2, 3]);
+    }
+
+    #[test]
+    fn test_computed_string() {
+        let enm = parse_src_as_enum("enum Foo { A = 'a', B = A + '-b' }").unwrap();
+        let values = assert_matches!(enm.kind, EnumKind::Strings(cases) => cases)
+            .into_iter()
+            .map(|case| case.value)
+            .collect::<Vec<_>>();
+        assert_eq!(values, ["a", "a-b"]);
+    }
+
+    #[test]
+    fn test_computed_errors() {
+        let message = |src| parse_src_as_enum(src).unwrap_err().message;
+        assert_eq!(
+            message("enum Foo { A = B, B = 1 }"),
+            "`B` is not an earlier member of this enum"
+        );
+        assert_eq!(
+            message("enum Foo { A = 3 / 2 }"),
+            "Enum values must be integers"
+        );
+        assert_eq!(
+            message("enum Foo { A = 9223372036854775808 }"),
+            "Enum value is out of range"
+        );
+        assert_eq!(
+            message("enum Foo { A = -(2 ** 62) * 2, B = A / -1 }"),
+            "Enum value is out of range"
+        );
+        assert_eq!(
+            message("enum Foo { A = -(2 ** 62) * 2, B = A % -1 }"),
+            "Enum value is out of range"
+        );
+        assert_eq!(
+            message("enum Foo { A = 'a', B }"),
+            "Enum members after a string member must be initialized"
+        );
+        assert_eq!(
+            message("enum Foo { A = 'a', B = A - 1 }"),
+            "Strings can only be concatenated, and arithmetic only works on integers"
+        );
+        assert_eq!(
+            message("enum Foo { A = 'a'.length }"),
+            "Enum values must be constant expressions"
+        );
+    }
+
+    fn parse_src_as_string_literal_union(src: &str) -> Result<Option<Enum>, StructureError> {
+        let decl = parse_src_as_decl(src);
+        let ts_type_alias =
+            assert_matches!(&decl, Decl::TsTypeAlias(ts_type_alias) => ts_type_alias.as_ref());
+        parse_string_literal_union(ts_type_alias, None, &SingleThreadedComments::default())
+    }
+
+    #[test]
+    fn test_string_literal_union() {
+        assert_eq!(
+            parse_src_as_string_literal_union(r"type Foo = 'active' | 'in-progress' | '2fa' | ''")
+                .unwrap()
+                .unwrap(),
+            Enum {
+                name: "Foo".to_string(),
+                kind: EnumKind::Strings(vec![
+                    EnumValue {
+                        name: "active".to_string(),
+                        value: "active".to_string(),
+                        doc: None,
+                    },
+                    EnumValue {
+                        name: "in_progress".to_string(),
+                        value: "in-progress".to_string(),
+                        doc: None,
+                    },
+                    EnumValue {
+                        name: "_2fa".to_string(),
+                        value: "2fa".to_string(),
+                        doc: None,
+                    },
+                    EnumValue {
+                        name: "empty".to_string(),
+                        value: "".to_string(),
+                        doc: None,
+                    },
+                ]),
+                doc: None
+            }
+        );
+    }
+    #[test]
+    fn test_string_literal_union_name_clash() {
+        let enm = parse_src_as_string_literal_union(r"type Foo = 'a_b' | 'a-b' | 'a b'")
+            .unwrap()
+            .unwrap();
+        let names = assert_matches!(enm.kind, EnumKind::Strings(cases) => cases)
+            .into_iter()
+            .map(|case| case.name)
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["a_b", "a_b_1", "a_b_2"]);
+    }
+    #[test]
+    fn test_string_literal_union_duplicated() {
+        let err = parse_src_as_string_literal_union(r"type Foo = 'a' | 'a'").unwrap_err();
+        assert_eq!(err.message, "Duplicated string literal in union");
+    }
+    #[test]
+    fn test_not_string_literal_union() {
+        assert_matches!(
+            parse_src_as_string_literal_union(r"type Foo = 'a' | number"),
+            Ok(None)
+        );
+    }
 }
@@ -0,0 +1,134 @@
+use crate::error::StructureError;
+use crate::inline::InlineObjects;
+use crate::prop::{fields_from_props, parse_as_prop};
+use crate::type_decl::parse_type_params;
+use crate::type_expr::parse_to_type_expr;
+use swc_common::comments::SingleThreadedComments;
+use swc_common::Spanned;
+use swc_ecma_ast::{TsType, TsTypeAliasDecl, TsUnionOrIntersectionType};
+use tser_ir::type_decl::struct_::Struct;
+use tser_ir::type_expr::{TypeExpr, TypeExprKind};
+
+pub(super) fn collect_ts_intersection_members<'a>(
+    ts_type: &'a TsType,
+    members: &mut Vec<&'a TsType>,
+) {
+    match ts_type {
+        TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsIntersectionType(
+            ts_intersection_type,
+        )) => {
+            for child in ts_intersection_type.types.as_slice() {
+                collect_ts_intersection_members(child.as_ref(), members)
+            }
+        }
+        TsType::TsParenthesizedType(parenthesized_type) => {
+            collect_ts_intersection_members(parenthesized_type.type_ann.as_ref(), members)
+        }
+        other => members.push(other),
+    }
+}
+
+/// Merges an intersection like `Base & { extra: number }` into a struct, with the named members
+/// as parents and the object type members as its own fields. Returns `None` if the alias isn't
+/// an intersection.
+pub fn parse_intersection(
+    type_alias_decl: &TsTypeAliasDecl,
+    doc: Option<String>,
+    comments: &SingleThreadedComments,
+    inline_objects: &mut InlineObjects,
+) -> Result<Option<Struct>, StructureError> {
+    let mut members: Vec<&TsType> = vec![];
+    collect_ts_intersection_members(type_alias_decl.type_ann.as_ref(), &mut members);
+    if members.len() < 2 {
+        return Ok(None);
+    }
+    let mut extends = vec![];
+    let mut props = vec![];
+    for member in members {
+        match member {
+            TsType::TsTypeLit(ts_type_lit) => {
+                for ts_type_element in &ts_type_lit.members {
+                    props.push(parse_as_prop(ts_type_element, comments)?);
+                }
+            }
+            other => match parse_to_type_expr(other)? {
+                parent @ TypeExpr {
+                    nullable: false,
+                    kind: TypeExprKind::Identifier(_) | TypeExprKind::Generic { .. },
+                } => extends.push(parent),
+                _ => {
+                    return Err(StructureError::new(
+                        other.span(),
+                        "Only interfaces and object types can be intersected",
+                    ))
+                }
+            },
+        }
+    }
+    let name = type_alias_decl.id.sym.to_string();
+    Ok(Some(Struct {
+        type_params: parse_type_params(type_alias_decl.type_params.as_deref())?,
+        extends,
+        fields: fields_from_props(&props, &name, inline_objects)?,
+        name,
+        doc,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::super::test_utils::parse_src_as_decl;
+    use super::*;
+    use assert_matches::assert_matches;
+    use swc_ecma_ast::Decl;
+
+    fn parse_src_as_intersection(src: &str) -> Result<Option<Struct>, StructureError> {
+        let decl = parse_src_as_decl(src);
+        let alias = assert_matches!(&decl, Decl::TsTypeAlias(alias) => alias.as_ref());
+        let comments = SingleThreadedComments::default();
+        let mut inline_objects = InlineObjects::new(vec![], &comments);
+        parse_intersection(alias, None, &comments, &mut inline_objects)
+    }
+
+    #[test]
+    fn test_intersection() {
+        let st = parse_src_as_intersection(
+            "type Full = Base & (Named & Page<string>) & { extra: number } & { more?: string }",
+        )
+        .unwrap()
+        .unwrap();
+        assert_eq!(st.name, "Full");
+        let parents = st
+            .extends
+            .iter()
+            .map(TypeExpr::descriptive_name)
+            .collect::<Vec<_>>();
+        assert_eq!(parents, ["Base", "Named", "PageString"]);
+        let fields = st
+            .fields
+            .iter()
+            .map(|field| &field.name)
+            .collect::<Vec<_>>();
+        assert_eq!(fields, ["extra", "more"]);
+        assert!(st.fields[1].optional);
+    }
+
+    #[test]
+    fn test_not_an_intersection() {
+        assert!(parse_src_as_intersection("type A = Base")
+            .unwrap()
+            .is_none());
+        assert!(parse_src_as_intersection("type A = B | C")
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn test_intersection_of_non_object() {
+        let err = parse_src_as_intersection("type A = Base & string[]").unwrap_err();
+        assert_eq!(
+            err.message,
+            "Only interfaces and object types can be intersected"
+        );
+    }
+}
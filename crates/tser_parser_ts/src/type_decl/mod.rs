@@ -1,20 +1,124 @@
+mod alias;
 mod enum_;
+mod intersection;
 mod struct_;
 mod union;
 
 use crate::error::StructureError;
-use crate::type_decl::enum_::parse_enum;
-use crate::type_decl::struct_::parse_struct;
+use crate::inline::{InlineObjects, InlineStruct};
+use crate::type_decl::alias::{is_sized_number_decl, parse_alias};
+use crate::type_decl::enum_::{parse_enum, parse_string_literal_union};
+use crate::type_decl::intersection::parse_intersection;
+use crate::type_decl::struct_::{check_inheritance, parse_struct};
 use crate::type_decl::union::parse_union;
+use std::collections::HashSet;
+use swc_common::comments::SingleThreadedComments;
 use swc_common::Spanned;
-use swc_ecma_ast::Decl;
+use swc_ecma_ast::{Decl, TsTypeParamDecl};
 use tser_ir::type_decl::TypeDecl;
+use tser_ir::File;
 
-pub fn parse_type_decl(decl: &Decl) -> Result<TypeDecl, StructureError> {
-    Ok(match decl {
-        Decl::TsInterface(ts_interface) => TypeDecl::Struct(parse_struct(ts_interface)?),
-        Decl::TsEnum(ts_enum) => TypeDecl::Enum(parse_enum(ts_enum)?),
-        Decl::TsTypeAlias(ts_type_alias) => TypeDecl::Union(parse_union(ts_type_alias)?),
-        other => return Err(other.span().into()),
-    })
+fn parse_type_params(type_params: Option<&TsTypeParamDecl>) -> Result<Vec<String>, StructureError> {
+    let type_params = match type_params {
+        Some(type_params) => type_params,
+        None => return Ok(vec![]),
+    };
+    type_params
+        .params
+        .iter()
+        .map(|type_param| {
+            if type_param.constraint.is_some() || type_param.default.is_some() {
+                return Err(StructureError::new(
+                    type_param.span,
+                    "Type parameters can't have constraints or defaults",
+                ));
+            }
+            Ok(type_param.name.sym.to_string())
+        })
+        .collect()
+}
+
+/// `doc` is passed in because an `export` in front of the declaration takes its comments.
+/// Returns the declaration along with the structs hoisted out of its inline object types, or
+/// `None` for declarations of built-in types like `type i64 = number`.
+pub fn parse_type_decl(
+    decl: &Decl,
+    doc: Option<String>,
+    comments: &SingleThreadedComments,
+) -> Result<Option<(TypeDecl, Vec<InlineStruct>)>, StructureError> {
+    let type_params = match decl {
+        Decl::TsInterface(ts_interface) => ts_interface.type_params.as_deref(),
+        Decl::TsTypeAlias(ts_type_alias) => ts_type_alias.type_params.as_deref(),
+        _ => None,
+    };
+    let mut inline_objects = InlineObjects::new(parse_type_params(type_params)?, comments);
+    let type_decl = match decl {
+        Decl::TsInterface(ts_interface) => TypeDecl::Struct(parse_struct(
+            ts_interface,
+            doc,
+            comments,
+            &mut inline_objects,
+        )?),
+        Decl::TsEnum(ts_enum) => TypeDecl::Enum(parse_enum(ts_enum, doc, comments)?),
+        Decl::TsTypeAlias(ts_type_alias) => {
+            if is_sized_number_decl(ts_type_alias)? {
+                return Ok(None);
+            } else if let Some(st) =
+                parse_intersection(ts_type_alias, doc.clone(), comments, &mut inline_objects)?
+            {
+                TypeDecl::Struct(st)
+            } else if let Some(enm) =
+                parse_string_literal_union(ts_type_alias, doc.clone(), comments)?
+            {
+                TypeDecl::Enum(enm)
+            } else if let Some(alias) = parse_alias(ts_type_alias, doc.clone())? {
+                TypeDecl::Alias(alias)
+            } else {
+                TypeDecl::Union(parse_union(
+                    ts_type_alias,
+                    doc,
+                    comments,
+                    &mut inline_objects,
+                )?)
+            }
+        }
+        other => {
+            return Err(StructureError::new(
+                other.span(),
+                "Only interfaces, enums and type aliases are supported",
+            ))
+        }
+    };
+    Ok(Some((type_decl, inline_objects.structs)))
+}
+
+/// Checks the parts of a declaration that depend on the rest of the file. Declarations named in
+/// `broken_names` failed to parse and have already been reported, so they aren't reported again.
+pub fn check_type_decl(
+    decl: &Decl,
+    file: &File,
+    broken_names: &HashSet<&str>,
+) -> Result<(), StructureError> {
+    match decl {
+        Decl::TsInterface(ts_interface) => {
+            match (
+                file.find_type_decl(&ts_interface.id.sym),
+                ts_interface.extends.first(),
+                ts_interface.extends.last(),
+            ) {
+                (Some(TypeDecl::Struct(st)), Some(first), Some(last)) => {
+                    check_inheritance(st, first.span.to(last.span), file, broken_names)
+                }
+                _ => Ok(()),
+            }
+        }
+        // Intersections are parsed into structs with parents
+        Decl::TsTypeAlias(ts_type_alias) => match file.find_type_decl(&ts_type_alias.id.sym) {
+            Some(TypeDecl::Struct(st)) => {
+                check_inheritance(st, ts_type_alias.type_ann.span(), file, broken_names)
+            }
+            _ => Ok(()),
+        },
+        _ => Ok(()),
+    }
 }
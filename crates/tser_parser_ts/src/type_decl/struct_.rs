@@ -1,34 +1,101 @@
 use crate::error::StructureError;
 
-use crate::prop::parse_as_prop;
-use swc_ecma_ast::TsInterfaceDecl;
-use tser_ir::type_decl::struct_::{Field, Struct};
+use crate::inline::InlineObjects;
+use crate::prop::{fields_from_props, parse_as_prop, Prop};
+use crate::type_decl::parse_type_params;
+use crate::type_expr::parse_to_type_expr;
+use std::collections::HashSet;
+use swc_common::comments::SingleThreadedComments;
+use swc_common::{Span, Spanned};
+use swc_ecma_ast::{Expr, TsExprWithTypeArgs, TsInterfaceDecl};
+use tser_ir::type_decl::struct_::{InheritanceError, Struct};
+use tser_ir::type_expr::{TypeExpr, TypeExprKind};
+use tser_ir::File;
 
-pub fn parse_struct(ts_interface: &TsInterfaceDecl) -> Result<Struct, StructureError> {
-    if let Some(type_params) = &ts_interface.type_params {
-        return Err(type_params.span.into());
-    }
-    if let Some(extend) = ts_interface.extends.first() {
-        return Err(extend.span.into());
-    }
+fn parse_extends(extend: &TsExprWithTypeArgs) -> Result<TypeExpr, StructureError> {
+    let name = match extend.expr.as_ref() {
+        Expr::Ident(ident) => ident.sym.to_string(),
+        other => {
+            return Err(StructureError::new(
+                other.span(),
+                "Only interfaces can be extended by name",
+            ))
+        }
+    };
+    let kind = match &extend.type_args {
+        None => TypeExprKind::Identifier(name),
+        Some(type_args) => TypeExprKind::Generic {
+            name,
+            args: type_args
+                .params
+                .iter()
+                .map(|param| parse_to_type_expr(param))
+                .collect::<Result<Vec<TypeExpr>, StructureError>>()?,
+        },
+    };
+    Ok(TypeExpr {
+        nullable: false,
+        kind,
+    })
+}
+
+pub fn parse_struct(
+    ts_interface: &TsInterfaceDecl,
+    doc: Option<String>,
+    comments: &SingleThreadedComments,
+    inline_objects: &mut InlineObjects,
+) -> Result<Struct, StructureError> {
+    let name = ts_interface.id.sym.to_string();
     Ok(Struct {
-        name: ts_interface.id.sym.to_string(),
-        fields: ts_interface
-            .body
-            .body
+        type_params: parse_type_params(ts_interface.type_params.as_deref())?,
+        extends: ts_interface
+            .extends
             .iter()
-            .map(parse_as_prop)
-            .map(|prop_result| Field::try_from(&prop_result?))
-            .collect::<Result<Vec<Field>, StructureError>>()?,
+            .map(parse_extends)
+            .collect::<Result<Vec<TypeExpr>, StructureError>>()?,
+        fields: fields_from_props(
+            &ts_interface
+                .body
+                .body
+                .iter()
+                .map(|ts_type_element| parse_as_prop(ts_type_element, comments))
+                .collect::<Result<Vec<Prop>, StructureError>>()?,
+            &name,
+            inline_objects,
+        )?,
+        name,
+        doc,
     })
 }
 
+/// Checks that the parents exist in the file and that the inherited fields don't conflict.
+/// Errors point at `parents_span`.
+pub fn check_inheritance(
+    st: &Struct,
+    parents_span: Span,
+    file: &File,
+    broken_names: &HashSet<&str>,
+) -> Result<(), StructureError> {
+    match st.resolve_fields(file) {
+        Err(InheritanceError::UnknownParent(name)) if broken_names.contains(name.as_str()) => {
+            Ok(())
+        }
+        Err(err @ InheritanceError::ConflictingField(_)) => {
+            Err(StructureError::new(parents_span, err.to_string())
+                .with_help("Parents and own fields must agree on the types of shared fields"))
+        }
+        Err(err) => Err(StructureError::new(parents_span, err.to_string())),
+        Ok(_) => Ok(()),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::super::super::test_utils::parse_src_as_decl;
     use super::*;
     use assert_matches::assert_matches;
     use swc_ecma_ast::Decl;
+    use tser_ir::type_decl::struct_::Field;
     use tser_ir::type_expr::primitive::Primitive;
     use tser_ir::type_expr::{TypeExpr, TypeExprKind};
 
@@ -36,7 +103,9 @@ mod tests {
         let decl = parse_src_as_decl(src);
         let ts_interface =
             assert_matches!(&decl, Decl::TsInterface(ts_interface) => ts_interface.as_ref());
-        parse_struct(ts_interface)
+        let comments = SingleThreadedComments::default();
+        let mut inline_objects = InlineObjects::new(vec![], &comments);
+        parse_struct(ts_interface, None, &comments, &mut inline_objects)
     }
 
     #[test]
@@ -45,28 +114,105 @@ mod tests {
             parse_src_as_struct("interface Hello { foo: string }").unwrap(),
             Struct {
                 name: "Hello".to_string(),
+                type_params: vec![],
+                extends: vec![],
                 fields: vec![Field {
                     name: "foo".to_string(),
+                    wire_name: "foo".to_string(),
                     ty: TypeExpr {
                         nullable: false,
                         kind: TypeExprKind::Primitive(Primitive::String),
                     },
-                    optional: false
-                }]
+                    optional: false,
+                    doc: None
+                }],
+                doc: None
             }
         );
     }
 
     #[test]
     fn test_ts_interface_struct_optional() {
-        assert_eq!(
+        assert!(
             parse_src_as_struct(r"interface Hello { foo?: string }")
                 .unwrap()
                 .fields
                 .first()
                 .unwrap()
-                .optional,
-            true
+                .optional
+        );
+    }
+
+    #[test]
+    fn test_ts_interface_quoted_names() {
+        let st = parse_src_as_struct(
+            r#"interface Headers { 'content-type': string; "2fa_enabled": boolean; content_type: string }"#,
+        )
+        .unwrap();
+        let names = st
+            .fields
+            .iter()
+            .map(|field| (field.name.as_str(), field.wire_name.as_str()))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            names,
+            [
+                ("content_type", "content-type"),
+                ("_2fa_enabled", "2fa_enabled"),
+                ("content_type_1", "content_type")
+            ]
+        );
+    }
+
+    #[test]
+    fn test_ts_interface_generic() {
+        let st = parse_src_as_struct("interface Page<T> { items: T[]; next?: string }").unwrap();
+        assert_eq!(st.type_params, ["T"]);
+        assert_eq!(
+            st.fields[0].ty,
+            TypeExpr {
+                nullable: false,
+                kind: TypeExprKind::ArrayOf(Box::new(TypeExpr {
+                    nullable: false,
+                    kind: TypeExprKind::Identifier("T".to_string()),
+                })),
+            }
+        );
+    }
+
+    #[test]
+    fn test_ts_interface_type_param_constraint() {
+        let err = parse_src_as_struct("interface Page<T extends Item> { items: T[] }").unwrap_err();
+        assert_eq!(
+            err.message,
+            "Type parameters can't have constraints or defaults"
+        );
+    }
+
+    #[test]
+    fn test_ts_interface_extends() {
+        let st =
+            parse_src_as_struct("interface Admin extends User, Page<string> { perms: string[] }")
+                .unwrap();
+        assert_eq!(
+            st.extends,
+            [
+                TypeExpr {
+                    nullable: false,
+                    kind: TypeExprKind::Identifier("User".to_string()),
+                },
+                TypeExpr {
+                    nullable: false,
+                    kind: TypeExprKind::Generic {
+                        name: "Page".to_string(),
+                        args: vec![TypeExpr {
+                            nullable: false,
+                            kind: TypeExprKind::Primitive(Primitive::String),
+                        }]
+                    },
+                }
+            ]
         );
+        assert_eq!(st.fields.len(), 1);
     }
 }
@@ -1,37 +1,52 @@
 use super::super::error::StructureError;
-use crate::prop::{parse_as_prop, Prop};
-use crate::type_expr::parse_to_type_expr;
+use crate::ident::{identifier_from_literal, pascal_case};
+use crate::inline::{InlineObjects, InlineSite};
+use crate::prop::{fields_from_props, parse_as_prop, Prop};
+use crate::type_decl::parse_type_params;
+use crate::type_expr::{parse_to_type_expr, parse_to_type_expr_hoisting};
 
+use crate::doc::leading_doc;
+use swc_common::comments::SingleThreadedComments;
 use swc_common::{Span, Spanned};
 use tser_ir::type_decl::{
-    struct_::Field,
     struct_::Struct,
     union::{Union, UnionKind},
 };
 
-use swc_ecma_ast::{TsLit, TsLitType, TsType, TsTypeAliasDecl, TsUnionOrIntersectionType};
+use swc_ecma_ast::{
+    TsKeywordType, TsKeywordTypeKind, TsLit, TsLitType, TsType, TsTypeAliasDecl,
+    TsUnionOrIntersectionType,
+};
 use tser_ir::type_decl::union::{ExternallyTaggedVariant, InternallyTaggedUnionBody};
+use tser_ir::type_expr::TypeExpr;
 
-fn traverse_ts_union_variants<E>(
-    ts_type: &TsType,
-    cb: &mut impl FnMut(&TsType) -> Result<(), E>,
-) -> Result<(), E> {
+pub(super) fn collect_ts_union_variants<'a>(ts_type: &'a TsType, variants: &mut Vec<&'a TsType>) {
     match ts_type {
         TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsUnionType(
             ts_union_type,
         )) => {
             for child in ts_union_type.types.as_slice() {
-                traverse_ts_union_variants(child.as_ref(), cb)?
+                collect_ts_union_variants(child.as_ref(), variants)
             }
-            Ok(())
         }
-        other => cb(other),
+        other => variants.push(other),
     }
 }
 
+/// Object types in variants are named after the union and the variant, like `ShapeCircle`
+fn variant_struct_name(union_name: &str, variant_name: &str) -> String {
+    format!(
+        "{union_name}{}",
+        pascal_case(&identifier_from_literal(variant_name))
+    )
+}
+
 fn try_get_externally_tagged_variant(
     members: &[Prop],
     span: Span,
+    doc: Option<String>,
+    union_name: &str,
+    inline_objects: &mut InlineObjects,
 ) -> Result<ExternallyTaggedVariant, StructureError> {
     if members.len() != 1 {
         return Err(StructureError::new(
@@ -46,14 +61,19 @@ fn try_get_externally_tagged_variant(
             "Field of externally tagged union variant must not be optional",
         ));
     }
+    let mut site = InlineSite::new(inline_objects, variant_struct_name(union_name, &prop.name));
     Ok(ExternallyTaggedVariant {
         name: prop.name.to_string(),
-        ty: parse_to_type_expr(prop.ts_type)?,
+        ty: parse_to_type_expr_hoisting(prop.ts_type, &mut site)?,
+        doc: doc.or_else(|| prop.doc.clone()),
     })
 }
 
 fn try_get_internally_tagged_variant(
     members: &mut Vec<Prop>,
+    doc: Option<String>,
+    union_name: &str,
+    inline_objects: &mut InlineObjects,
 ) -> Result<Option<(String, Struct)>, StructureError> {
     struct DiscriminatorField {
         name: String,
@@ -87,15 +107,19 @@ fn try_get_internally_tagged_variant(
     match find_and_remove_discriminator_field(members)? {
         Some(discriminator_field) => {
             // internally tagged, like { type: "...", ... } | { type: "...", ... }
-            let fields = members
-                .iter()
-                .map(Field::try_from)
-                .collect::<Result<Vec<Field>, StructureError>>()?;
+            let fields = fields_from_props(
+                members,
+                &variant_struct_name(union_name, &discriminator_field.value),
+                inline_objects,
+            )?;
             Ok(Some((
                 discriminator_field.name,
                 Struct {
                     name: discriminator_field.value,
+                    type_params: vec![],
+                    extends: vec![],
                     fields,
+                    doc,
                 },
             )))
         }
@@ -103,50 +127,124 @@ fn try_get_internally_tagged_variant(
     }
 }
 
-fn detect_union_kind(mut members: Vec<Prop>, span: Span) -> Result<UnionKind, StructureError> {
+fn detect_union_kind(
+    mut members: Vec<Prop>,
+    span: Span,
+    doc: Option<String>,
+    union_name: &str,
+    inline_objects: &mut InlineObjects,
+) -> Result<UnionKind, StructureError> {
     // Try finding discriminator field
-    match try_get_internally_tagged_variant(&mut members)? {
+    match try_get_internally_tagged_variant(&mut members, doc.clone(), union_name, inline_objects)?
+    {
         Some((tag_field, variant)) => {
             // internally tagged, like { type: "...", ... } | { type: "...", ... }
             Ok(UnionKind::InternallyTagged(InternallyTaggedUnionBody {
+                tag_field_name: identifier_from_literal(&tag_field),
                 tag_field,
                 variants: vec![variant],
             }))
         }
         None => {
             // externally tagged, like { "foo": string } | { "bar": number }
-            let variant = try_get_externally_tagged_variant(&members, span)?;
+            let variant =
+                try_get_externally_tagged_variant(&members, span, doc, union_name, inline_objects)?;
             Ok(UnionKind::ExternallyTagged(vec![variant]))
         }
     }
 }
 
-pub fn parse_union(type_alias_decl: &TsTypeAliasDecl) -> Result<Union, StructureError> {
-    if let Some(type_params) = &type_alias_decl.type_params {
-        return Err(type_params.span.into());
+pub fn parse_union(
+    type_alias_decl: &TsTypeAliasDecl,
+    doc: Option<String>,
+    comments: &SingleThreadedComments,
+    inline_objects: &mut InlineObjects,
+) -> Result<Union, StructureError> {
+    let name = type_alias_decl.id.sym.to_string();
+    let type_params = parse_type_params(type_alias_decl.type_params.as_deref())?;
+
+    let mut ts_types: Vec<&TsType> = vec![];
+    collect_ts_union_variants(type_alias_decl.type_ann.as_ref(), &mut ts_types);
+
+    // A union declaration can't be nullable, only the places using it
+    if let Some(null) = ts_types.iter().find(|ts_type| {
+        matches!(
+            ts_type,
+            TsType::TsKeywordType(TsKeywordType {
+                kind: TsKeywordTypeKind::TsNullKeyword | TsKeywordTypeKind::TsUndefinedKeyword,
+                ..
+            })
+        )
+    }) {
+        return Err(
+            StructureError::new(null.span(), "Unions can't have `null` as a variant").with_help(
+                format!("Leave out `null`, and use `{name} | null` where the union is used"),
+            ),
+        );
     }
 
-    let name = type_alias_decl.id.sym.to_string();
+    if !ts_types
+        .iter()
+        .all(|ts_type| matches!(ts_type, TsType::TsTypeLit(_)))
+    {
+        if ts_types.len() < 2 {
+            return Err(StructureError::new(
+                type_alias_decl.type_ann.span(),
+                "Type aliases are only supported for unions",
+            ));
+        }
+        // untagged, like string | number
+        let variants = ts_types
+            .into_iter()
+            .map(|ts_type| match ts_type {
+                TsType::TsTypeLit(ts_type_lit) => Err(StructureError::new(
+                    ts_type_lit.span,
+                    "Object types in an untagged union must be declared separately",
+                )),
+                other => parse_to_type_expr(other),
+            })
+            .collect::<Result<Vec<TypeExpr>, StructureError>>()?;
+        return Ok(Union {
+            name,
+            type_params,
+            kind: UnionKind::Untagged(variants),
+            doc,
+        });
+    }
 
     let mut kind: Option<UnionKind> = None;
+    let mut prev_hi = type_alias_decl.id.span.hi;
 
-    traverse_ts_union_variants(type_alias_decl.type_ann.as_ref(), &mut |ts_type| {
+    for ts_type in ts_types {
         let ts_type_lit = match ts_type {
             TsType::TsTypeLit(ts_type_lit) => ts_type_lit,
-            other => return Err(StructureError::from(other.span())),
+            other => return Err(StructureError::new(other.span(), "Expected an object type")),
         };
+        let variant_doc = leading_doc(comments, prev_hi, ts_type_lit.span.lo);
+        prev_hi = ts_type_lit.span.hi;
         let mut members = ts_type_lit
             .members
             .iter()
-            .map(parse_as_prop)
+            .map(|member| parse_as_prop(member, comments))
             .collect::<Result<Vec<Prop>, StructureError>>()?;
         match &mut kind {
             None => {
                 // Using the first union member to detect union kind
-                kind = Some(detect_union_kind(members, ts_type_lit.span)?)
+                kind = Some(detect_union_kind(
+                    members,
+                    ts_type_lit.span,
+                    variant_doc,
+                    &name,
+                    inline_objects,
+                )?)
             }
             Some(UnionKind::InternallyTagged(internally_tagged)) => {
-                let (tag_field, variant) = match try_get_internally_tagged_variant(&mut members)? {
+                let (tag_field, variant) = match try_get_internally_tagged_variant(
+                    &mut members,
+                    variant_doc,
+                    &name,
+                    inline_objects,
+                )? {
                     Some(some) => some,
                     None => {
                         return Err(StructureError::new(
@@ -164,18 +262,29 @@ pub fn parse_union(type_alias_decl: &TsTypeAliasDecl) -> Result<Union, Structure
                 internally_tagged.variants.push(variant)
             }
             Some(UnionKind::ExternallyTagged(externally_tagged_variants)) => {
-                let variant = try_get_externally_tagged_variant(&members, ts_type_lit.span)?;
+                let variant = try_get_externally_tagged_variant(
+                    &members,
+                    ts_type_lit.span,
+                    variant_doc,
+                    &name,
+                    inline_objects,
+                )?;
                 externally_tagged_variants.push(variant);
             }
+            Some(UnionKind::Untagged(_)) => unreachable!(),
         };
-        Ok(())
-    })?;
+    }
     match kind {
         None => Err(StructureError::new(
             type_alias_decl.type_ann.span(),
             "Union must have at least one variant",
         )),
-        Some(kind) => Ok(Union { name, kind }),
+        Some(kind) => Ok(Union {
+            name,
+            type_params,
+            kind,
+            doc,
+        }),
     }
 }
 
@@ -185,6 +294,7 @@ mod tests {
     use crate::test_utils::parse_src_as_decl;
     use assert_matches::assert_matches;
     use swc_ecma_ast::Decl;
+    use tser_ir::type_decl::struct_::Field;
     use tser_ir::type_expr::primitive::Primitive;
     use tser_ir::type_expr::{TypeExpr, TypeExprKind};
 
@@ -192,7 +302,9 @@ mod tests {
         let decl = parse_src_as_decl(src);
         let ts_typealias_decl =
             assert_matches!(&decl, Decl::TsTypeAlias(ts_typealias) => ts_typealias.as_ref());
-        parse_union(ts_typealias_decl)
+        let comments = SingleThreadedComments::default();
+        let mut inline_objects = InlineObjects::new(vec![], &comments);
+        parse_union(ts_typealias_decl, None, &comments, &mut inline_objects)
     }
 
     #[test]
@@ -206,48 +318,81 @@ mod tests {
             .unwrap(),
             Union {
                 name: "Bar".to_string(),
+                type_params: vec![],
                 kind: UnionKind::InternallyTagged(InternallyTaggedUnionBody {
                     tag_field: "type".to_string(),
+                    tag_field_name: "type".to_string(),
                     variants: vec![
                         Struct {
                             name: "hello".to_string(),
+                            type_params: vec![],
+                            extends: vec![],
                             fields: vec![Field {
                                 name: "val".to_string(),
+                                wire_name: "val".to_string(),
                                 ty: TypeExpr {
                                     nullable: false,
                                     kind: TypeExprKind::Primitive(Primitive::Number),
                                 },
-                                optional: false
+                                optional: false,
+                                doc: None
                             }],
+                            doc: None,
                         },
                         Struct {
                             name: "empty".to_string(),
+                            type_params: vec![],
+                            extends: vec![],
                             fields: vec![],
+                            doc: None,
                         },
                         Struct {
                             name: "maybe_a_string".to_string(),
+                            type_params: vec![],
+                            extends: vec![],
                             fields: vec![Field {
                                 name: "val".to_string(),
+                                wire_name: "val".to_string(),
                                 ty: TypeExpr {
                                     nullable: false,
                                     kind: TypeExprKind::Primitive(Primitive::String),
                                 },
-                                optional: true
+                                optional: true,
+                                doc: None
                             }],
+                            doc: None,
                         },
                     ],
-                })
+                }),
+                doc: None
             },
         );
     }
 
+    #[test]
+    fn adjacently_tagged_quoted_keys() {
+        let union = parse_src_as_union(
+            r"type Bar = { 'the-kind': 'a', 'my-data': number } | { 'the-kind': 'b', 'my-data': string }",
+        )
+        .unwrap();
+        let internally_tagged = assert_matches!(
+            union.kind,
+            UnionKind::InternallyTagged(internally_tagged) => internally_tagged
+        );
+        assert_eq!(internally_tagged.tag_field, "the-kind");
+        assert_eq!(internally_tagged.tag_field_name, "the_kind");
+        let adjacently_tagged = internally_tagged.as_adjacently_tagged().unwrap();
+        assert_eq!(adjacently_tagged.data_field, "my-data");
+        assert_eq!(adjacently_tagged.data_field_name, "my_data");
+    }
+
     #[test]
     fn internally_tagged_different_tag_name() {
         let error = parse_src_as_union(r"type Bar = { a: 'hello', val: number } | { b: 'empty' }")
             .err()
             .unwrap();
         assert_eq!(
-            error.message.unwrap(),
+            error.message,
             "The discriminator field has a different name from the previous one"
         );
         assert_eq!(error.span.lo.0, 41);
@@ -259,7 +404,7 @@ mod tests {
         let error = parse_src_as_union(r"type Bar = { a: 'hello', val: number } | { val: string }")
             .err()
             .unwrap();
-        assert_eq!(error.message.unwrap(), "The discriminator field is missing");
+        assert_eq!(error.message, "The discriminator field is missing");
         assert_eq!(error.span.lo.0, 41);
         assert_eq!(error.span.hi.0, 56);
     }
@@ -270,22 +415,26 @@ mod tests {
             parse_src_as_union("type Foo = { string: string } | { number: number }").unwrap(),
             Union {
                 name: "Foo".to_string(),
+                type_params: vec![],
                 kind: UnionKind::ExternallyTagged(vec![
                     ExternallyTaggedVariant {
                         name: "string".to_string(),
                         ty: TypeExpr {
                             nullable: false,
                             kind: TypeExprKind::Primitive(Primitive::String)
-                        }
+                        },
+                        doc: None
                     },
                     ExternallyTaggedVariant {
                         name: "number".to_string(),
                         ty: TypeExpr {
                             nullable: false,
                             kind: TypeExprKind::Primitive(Primitive::Number)
-                        }
+                        },
+                        doc: None
                     },
                 ]),
+                doc: None,
             },
         );
     }
@@ -294,7 +443,7 @@ mod tests {
         let err =
             parse_src_as_union("type Foo = { string: string } | { number?: number }").unwrap_err();
         assert_eq!(
-            err.message.unwrap(),
+            err.message,
             "Field of externally tagged union variant must not be optional"
         );
         assert_eq!(err.span.lo.0, 34);
@@ -307,7 +456,7 @@ mod tests {
                 .err()
                 .unwrap();
         assert_eq!(
-            err.message.unwrap(),
+            err.message,
             "Externally tagged union variant must contain exactly one field"
         );
         assert_eq!(err.span.lo.0, 32);
@@ -319,23 +468,74 @@ mod tests {
             .err()
             .unwrap();
         assert_eq!(
-            err.message.unwrap(),
+            err.message,
             "Externally tagged union variant must contain exactly one field"
         );
         assert_eq!(err.span.lo.0, 32);
         assert_eq!(err.span.hi.0, 35);
     }
 
+    #[test]
+    fn nullable_untagged() {
+        let err = parse_src_as_union("type Id = string | number | null").unwrap_err();
+        assert_eq!(err.message, "Unions can't have `null` as a variant");
+        assert_eq!(
+            err.help.as_deref(),
+            Some("Leave out `null`, and use `Id | null` where the union is used")
+        );
+        assert_eq!(err.span.lo.0, 28);
+        assert_eq!(err.span.hi.0, 32);
+    }
+
     #[test]
     fn invalid_union_kind() {
         let err = parse_src_as_union("type Foo = { foo: string, bar: number }")
             .err()
             .unwrap();
         assert_eq!(
-            err.message.unwrap(),
+            err.message,
             "Externally tagged union variant must contain exactly one field"
         );
         assert_eq!(err.span.lo.0, 11);
         assert_eq!(err.span.hi.0, 39);
     }
+
+    #[test]
+    fn untagged() {
+        assert_eq!(
+            parse_src_as_union("type Foo = string | Bar | number[]").unwrap(),
+            Union {
+                name: "Foo".to_string(),
+                type_params: vec![],
+                kind: UnionKind::Untagged(vec![
+                    TypeExpr {
+                        nullable: false,
+                        kind: TypeExprKind::Primitive(Primitive::String)
+                    },
+                    TypeExpr {
+                        nullable: false,
+                        kind: TypeExprKind::Identifier("Bar".to_string())
+                    },
+                    TypeExpr {
+                        nullable: false,
+                        kind: TypeExprKind::ArrayOf(Box::new(TypeExpr {
+                            nullable: false,
+                            kind: TypeExprKind::Primitive(Primitive::Number)
+                        }))
+                    },
+                ]),
+                doc: None,
+            },
+        );
+    }
+    #[test]
+    fn untagged_with_object_type() {
+        let err = parse_src_as_union("type Foo = string | { foo: string }").unwrap_err();
+        assert_eq!(
+            err.message,
+            "Object types in an untagged union must be declared separately"
+        );
+        assert_eq!(err.span.lo.0, 20);
+        assert_eq!(err.span.hi.0, 35);
+    }
 }
@@ -1,40 +1,193 @@
 use crate::error::StructureError;
-use swc_common::Spanned;
+use crate::inline::InlineSite;
+use crate::number::sized_number;
+use swc_common::{Span, Spanned};
 use swc_ecma_ast::{
-    TsEntityName, TsKeywordType, TsKeywordTypeKind, TsType, TsUnionOrIntersectionType,
+    BindingIdent, TsEntityName, TsFnParam, TsIndexSignature, TsKeywordType, TsKeywordTypeKind,
+    TsType, TsTypeElement, TsUnionOrIntersectionType,
 };
 use tser_ir::type_expr::primitive::Primitive;
 use tser_ir::type_expr::{TypeExpr, TypeExprKind};
 
-fn parse_ts_type_to_type_expr_kind(ts_type: &TsType) -> Result<TypeExprKind, StructureError> {
+fn parse_ts_type_to_type_expr_kind(
+    ts_type: &TsType,
+    mut site: Option<&mut InlineSite>,
+) -> Result<TypeExprKind, StructureError> {
     Ok(match ts_type {
         TsType::TsKeywordType(keyword_type) => TypeExprKind::Primitive(match keyword_type.kind {
             TsKeywordTypeKind::TsBooleanKeyword => Primitive::Bool,
             TsKeywordTypeKind::TsNumberKeyword => Primitive::Number,
             TsKeywordTypeKind::TsStringKeyword => Primitive::String,
-            _ => return Err(keyword_type.span().into()),
+            TsKeywordTypeKind::TsBigIntKeyword => Primitive::Int {
+                bits: 64,
+                signed: true,
+            },
+            _ => {
+                return Err(
+                    StructureError::new(keyword_type.span(), "Unsupported primitive type")
+                        .with_help("Use `string`, `number`, `bigint` or `boolean`"),
+                )
+            }
         }),
         TsType::TsArrayType(ts_array_type) => {
-            let elem_type_expr = parse_to_type_expr(&ts_array_type.elem_type)?;
+            let elem_type_expr = parse_type(&ts_array_type.elem_type, site)?;
             TypeExprKind::ArrayOf(Box::new(elem_type_expr))
         }
         TsType::TsTypeRef(type_ref) => {
-            if let Some(type_params) = &type_ref.type_params {
-                return Err(type_params.span.into());
-            }
-            TypeExprKind::Identifier(match &type_ref.type_name {
+            let name = match &type_ref.type_name {
                 TsEntityName::Ident(ident) => ident.sym.to_string(),
-                other => return Err(other.span().into()),
-            })
+                other => {
+                    return Err(StructureError::new(
+                        other.span(),
+                        "Qualified type names aren't supported",
+                    )
+                    .with_help("Import the type by name instead"))
+                }
+            };
+            match &type_ref.type_params {
+                None => match sized_number(&name) {
+                    Some(primitive) => TypeExprKind::Primitive(primitive),
+                    None => TypeExprKind::Identifier(name),
+                },
+                Some(type_params) => {
+                    let mut args = type_params
+                        .params
+                        .iter()
+                        .map(|param| parse_type(param, site.as_deref_mut()))
+                        .collect::<Result<Vec<TypeExpr>, StructureError>>()?;
+                    if name == "Array" && args.len() == 1 {
+                        TypeExprKind::ArrayOf(Box::new(args.remove(0)))
+                    } else if name == "Record" && args.len() == 2 {
+                        let value = args.remove(1);
+                        map_of(args.remove(0), type_params.params[0].span(), value)?
+                    } else {
+                        TypeExprKind::Generic { name, args }
+                    }
+                }
+            }
         }
-        other => {
-            dbg!(other);
-            return Err(other.span().into());
+        TsType::TsTupleType(ts_tuple_type) => TypeExprKind::Tuple(
+            ts_tuple_type
+                .elem_types
+                .iter()
+                .map(|element| parse_tuple_element(&element.ty, site.as_deref_mut()))
+                .collect::<Result<Vec<TypeExpr>, StructureError>>()?,
+        ),
+        TsType::TsTypeLit(ts_type_lit) => match (ts_type_lit.members.as_slice(), site) {
+            ([TsTypeElement::TsIndexSignature(index_signature)], site) => {
+                parse_index_signature(index_signature, site)?
+            }
+            (_, Some(site)) => site.hoist(ts_type_lit)?,
+            (_, None) => {
+                return Err(StructureError::new(
+                    ts_type.span(),
+                    "Object types must be declared separately",
+                )
+                .with_help("Declare an interface and refer to it by name"))
+            }
+        },
+        TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsIntersectionType(
+            intersection_type,
+        )) => {
+            return Err(StructureError::new(
+                intersection_type.span,
+                "Intersections must be declared separately",
+            )
+            .with_help("Declare the intersection as a type alias and refer to it by name"))
+        }
+        other => return Err(StructureError::new(other.span(), "Unsupported type")),
+    })
+}
+
+fn parse_tuple_element(
+    ts_type: &TsType,
+    site: Option<&mut InlineSite>,
+) -> Result<TypeExpr, StructureError> {
+    match ts_type {
+        // Like optional fields, `[string, number?]` may be missing the value or have it as null
+        TsType::TsOptionalType(ts_optional_type) => {
+            let element = parse_type(&ts_optional_type.type_ann, site)?;
+            Ok(TypeExpr {
+                nullable: true,
+                kind: element.kind,
+            })
         }
+        TsType::TsRestType(ts_rest_type) => Err(StructureError::new(
+            ts_rest_type.span,
+            "Rest elements in tuples aren't supported",
+        )
+        .with_help("Use an array instead")),
+        other => parse_type(other, site),
+    }
+}
+
+fn map_of(key: TypeExpr, key_span: Span, value: TypeExpr) -> Result<TypeExprKind, StructureError> {
+    let string_key = TypeExpr {
+        nullable: false,
+        kind: TypeExprKind::Primitive(Primitive::String),
+    };
+    if key != string_key {
+        return Err(StructureError::new(key_span, "Map keys must be `string`")
+            .with_help("JSON object keys are always strings"));
+    }
+    Ok(TypeExprKind::MapOf {
+        key: Box::new(key),
+        value: Box::new(value),
     })
 }
 
+/// Parses `{ [key: string]: T }`
+fn parse_index_signature(
+    index_signature: &TsIndexSignature,
+    site: Option<&mut InlineSite>,
+) -> Result<TypeExprKind, StructureError> {
+    let key_type_ann = match index_signature.params.as_slice() {
+        [TsFnParam::Ident(BindingIdent {
+            type_ann: Some(type_ann),
+            ..
+        })] => type_ann,
+        _ => {
+            return Err(StructureError::new(
+                index_signature.span,
+                "Index signatures must have a single key with a type",
+            ))
+        }
+    };
+    let value_type_ann = index_signature.type_ann.as_ref().ok_or_else(|| {
+        StructureError::new(
+            index_signature.span,
+            "Index signatures must have a value type",
+        )
+    })?;
+    map_of(
+        parse_to_type_expr(&key_type_ann.type_ann)?,
+        key_type_ann.type_ann.span(),
+        parse_type(&value_type_ann.type_ann, site)?,
+    )
+}
+
+fn nested_union_error(span: Span) -> StructureError {
+    StructureError::new(
+        span,
+        "Unions other than `T | null` must be declared separately",
+    )
+    .with_help("Declare the union as a type alias and refer to it by name")
+}
+
+/// Object types in `ts_type` are rejected, as there's no field to name them after
 pub fn parse_to_type_expr(ts_type: &TsType) -> Result<TypeExpr, StructureError> {
+    parse_type(ts_type, None)
+}
+
+/// Like [`parse_to_type_expr`], hoisting object types into structs named by `site`
+pub fn parse_to_type_expr_hoisting(
+    ts_type: &TsType,
+    site: &mut InlineSite,
+) -> Result<TypeExpr, StructureError> {
+    parse_type(ts_type, Some(site))
+}
+
+fn parse_type(ts_type: &TsType, site: Option<&mut InlineSite>) -> Result<TypeExpr, StructureError> {
     fn is_null(ts_type: &TsType) -> bool {
         matches!(
             ts_type,
@@ -47,7 +200,7 @@ pub fn parse_to_type_expr(ts_type: &TsType) -> Result<TypeExpr, StructureError>
     match ts_type {
         TsType::TsUnionOrIntersectionType(TsUnionOrIntersectionType::TsUnionType(union_type)) => {
             // Parse "... | null"
-            return match union_type.types.as_slice() {
+            match union_type.types.as_slice() {
                 [type1, type2] => {
                     let type1 = type1.as_ref();
                     let type2 = type2.as_ref();
@@ -56,22 +209,22 @@ pub fn parse_to_type_expr(ts_type: &TsType) -> Result<TypeExpr, StructureError>
                     } else if is_null(type2) {
                         type1
                     } else {
-                        return Err(union_type.span.into());
+                        return Err(nested_union_error(union_type.span));
                     };
-                    let kind = parse_ts_type_to_type_expr_kind(ty)?;
+                    let kind = parse_ts_type_to_type_expr_kind(ty, site)?;
                     Ok(TypeExpr {
                         nullable: true,
                         kind,
                     })
                 }
-                _ => Err(union_type.span.into()),
-            };
+                _ => Err(nested_union_error(union_type.span)),
+            }
         }
         TsType::TsParenthesizedType(parenthesized_type) => {
-            parse_to_type_expr(parenthesized_type.type_ann.as_ref())
+            parse_type(parenthesized_type.type_ann.as_ref(), site)
         }
         other => {
-            let kind = parse_ts_type_to_type_expr_kind(other)?;
+            let kind = parse_ts_type_to_type_expr_kind(other, site)?;
             Ok(TypeExpr {
                 nullable: false,
                 kind,
@@ -85,13 +238,13 @@ mod tests {
     use super::*;
 
     fn parse_type_expr(src: &str) -> TypeExpr {
-        let mut parser = ts_parser_from_source(src);
+        let mut parser = ts_parser_from_source(src, None);
         let ts_type = parser.parse_type().unwrap();
         parse_to_type_expr(ts_type.as_ref()).unwrap()
     }
     fn parse_type_expr_kind(src: &str) -> TypeExprKind {
         let expr = parse_type_expr(src);
-        assert_eq!(expr.nullable, false);
+        assert!(!expr.nullable);
         expr.kind
     }
 
@@ -111,6 +264,27 @@ mod tests {
         );
     }
     #[test]
+    fn test_sized_number() {
+        assert_eq!(
+            parse_type_expr_kind("bigint"),
+            TypeExprKind::Primitive(Primitive::Int {
+                bits: 64,
+                signed: true
+            })
+        );
+        assert_eq!(
+            parse_type_expr_kind("u8"),
+            TypeExprKind::Primitive(Primitive::Int {
+                bits: 8,
+                signed: false
+            })
+        );
+        assert_eq!(
+            parse_type_expr_kind("f32"),
+            TypeExprKind::Primitive(Primitive::Float { bits: 32 })
+        );
+    }
+    #[test]
     fn test_identifier() {
         assert_eq!(
             parse_type_expr_kind("foo"),
@@ -160,4 +334,91 @@ mod tests {
             }
         );
     }
+
+    #[test]
+    fn test_generic() {
+        assert_eq!(
+            parse_type_expr_kind("Page<User, string | null>"),
+            TypeExprKind::Generic {
+                name: "Page".to_string(),
+                args: vec![
+                    TypeExpr {
+                        nullable: false,
+                        kind: TypeExprKind::Identifier("User".to_string())
+                    },
+                    TypeExpr {
+                        nullable: true,
+                        kind: TypeExprKind::Primitive(Primitive::String)
+                    },
+                ]
+            }
+        );
+    }
+
+    #[test]
+    fn test_array_generic() {
+        assert_eq!(
+            parse_type_expr_kind("Array<number>"),
+            parse_type_expr_kind("number[]")
+        );
+    }
+
+    #[test]
+    fn test_map() {
+        assert_eq!(
+            parse_type_expr_kind("Record<string, number[]>"),
+            TypeExprKind::MapOf {
+                key: Box::new(TypeExpr {
+                    nullable: false,
+                    kind: TypeExprKind::Primitive(Primitive::String)
+                }),
+                value: Box::new(TypeExpr {
+                    nullable: false,
+                    kind: TypeExprKind::ArrayOf(Box::new(TypeExpr {
+                        nullable: false,
+                        kind: TypeExprKind::Primitive(Primitive::Number)
+                    }))
+                }),
+            }
+        );
+        assert_eq!(
+            parse_type_expr_kind("{ [key: string]: number[] }"),
+            parse_type_expr_kind("Record<string, number[]>")
+        );
+    }
+
+    #[test]
+    fn test_map_key() {
+        let mut parser = ts_parser_from_source("{ [id: number]: string }", None);
+        let ts_type = parser.parse_type().unwrap();
+        let err = parse_to_type_expr(ts_type.as_ref()).unwrap_err();
+        assert_eq!(err.message, "Map keys must be `string`");
+        assert_eq!(err.span.lo.0, 7);
+        assert_eq!(err.span.hi.0, 13);
+    }
+
+    #[test]
+    fn test_tuple() {
+        assert_eq!(
+            parse_type_expr_kind("[string, User, boolean?]"),
+            TypeExprKind::Tuple(vec![
+                TypeExpr {
+                    nullable: false,
+                    kind: TypeExprKind::Primitive(Primitive::String)
+                },
+                TypeExpr {
+                    nullable: false,
+                    kind: TypeExprKind::Identifier("User".to_string())
+                },
+                TypeExpr {
+                    nullable: true,
+                    kind: TypeExprKind::Primitive(Primitive::Bool)
+                },
+            ])
+        );
+        assert_eq!(
+            parse_type_expr_kind("[x: number, y: number]"),
+            parse_type_expr_kind("[number, number]")
+        );
+    }
 }
@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use wasm_bindgen::prelude::*;
 
 #[wasm_bindgen]
@@ -5,6 +6,12 @@ use wasm_bindgen::prelude::*;
 pub enum Language {
     Rust = "rust",
     Swift = "swift",
+    Kotlin = "kotlin",
+    Go = "go",
+    Python = "python",
+    CSharp = "csharp",
+    Java = "java",
+    JavaPojo = "java-pojo",
 }
 
 impl TryFrom<Language> for tser::Language {
@@ -13,6 +20,12 @@ impl TryFrom<Language> for tser::Language {
         Ok(match value {
             Language::Swift => tser::Language::Swift,
             Language::Rust => tser::Language::Rust,
+            Language::Kotlin => tser::Language::Kotlin,
+            Language::Go => tser::Language::Go,
+            Language::Python => tser::Language::Python,
+            Language::CSharp => tser::Language::CSharp,
+            Language::Java => tser::Language::Java,
+            Language::JavaPojo => tser::Language::JavaPojo,
             other => return Err(format!("Invalid language: {}", other.to_str())),
         })
     }
@@ -23,3 +36,28 @@ pub fn generate_from_ts(ts_src: &str, lang: Language) -> Result<String, String>
     console_error_panic_hook::set_once();
     tser::generate_from_ts(ts_src, lang.try_into()?).map_err(|err| err.to_string())
 }
+
+/// Generates a single file from `files`, a `Map` from paths like `models/user.ts` to sources,
+/// resolving the imports between them
+#[wasm_bindgen]
+pub fn generate_from_ts_files(files: &js_sys::Map, lang: Language) -> Result<String, String> {
+    console_error_panic_hook::set_once();
+    let mut paths = vec![];
+    let mut sources = HashMap::new();
+    let mut invalid = false;
+    files.for_each(
+        &mut |source, path| match (path.as_string(), source.as_string()) {
+            (Some(path), Some(source)) => {
+                paths.push(path.clone());
+                sources.insert(path, source);
+            }
+            _ => invalid = true,
+        },
+    );
+    if invalid {
+        return Err("Files must be a Map from paths to sources".to_string());
+    }
+    let roots = paths.iter().map(String::as_str).collect::<Vec<_>>();
+    tser::generate_from_ts_project(&roots, &sources, lang.try_into()?)
+        .map_err(|err| err.to_string())
+}
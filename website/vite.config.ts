@@ -10,7 +10,7 @@ export default defineConfig({
     svelte(),
   ],
   optimizeDeps: {
-    exclude: ["codemirror", "@codemirror/language", "@codemirror/legacy-modes/mode/swift", "@codemirror/state"]
+    exclude: ["codemirror", "@codemirror/language", "@codemirror/legacy-modes/mode/swift", "@codemirror/legacy-modes/mode/clike", "@codemirror/legacy-modes/mode/go", "@codemirror/legacy-modes/mode/python", "@codemirror/state"]
   },
   build: {
     target: "es2022"